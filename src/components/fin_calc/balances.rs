@@ -0,0 +1,252 @@
+//! Balances panel: reconcile accounts, per-account trends and allocation
+//! drift.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::finCalc::balances::{self, ValuePoint};
+use crate::models::finCalc::finances::{AssetKind, FinancesFile};
+
+use super::charts::{build_pie, LineChart, PieChart};
+use super::component::{fmt_short_date, format_amount, parse_iso_date, MoneyInput};
+
+/// One account for the balances pickers.
+#[derive(Clone, PartialEq)]
+struct Account {
+    id: Uuid,
+    name: String,
+    liability: bool,
+    code: String,
+    current: i64,
+    points: Vec<ValuePoint>,
+}
+
+fn accounts(file: &FinancesFile) -> Vec<Account> {
+    let assets = file.assets.iter().map(|a| Account {
+        id: a.id,
+        name: a.name.clone(),
+        liability: false,
+        code: file.currencies.resolve(a.currency.as_deref()).to_string(),
+        current: a.value,
+        points: a.balances.clone(),
+    });
+    let debts = file.liabilities.iter().map(|l| Account {
+        id: l.id,
+        name: l.name.clone(),
+        liability: true,
+        code: file.currencies.resolve(l.currency.as_deref()).to_string(),
+        current: l.balance,
+        points: l.balances.clone(),
+    });
+    assets.chain(debts).collect()
+}
+
+#[component]
+pub(super) fn BalancesSection(mut fin_state: Signal<FinancesFile>, on_change: EventHandler<()>) -> Element {
+    let mut reconciling = use_signal(|| false);
+    // Typed balances while reconciling, by account.
+    let mut draft = use_signal(Vec::<(Uuid, i64)>::new);
+    let mut reconcile_date = use_signal(String::new);
+    let mut selected = use_signal(|| None::<Uuid>);
+    let mut drift_kind = use_signal(|| AssetKind::Investment);
+
+    let today = time::OffsetDateTime::now_utc().date();
+    let base = fin_state.read().currencies.base.clone();
+    let list = accounts(&fin_state.read());
+    let account = selected
+        .read()
+        .and_then(|id| list.iter().find(|a| a.id == id).cloned())
+        .or_else(|| list.first().cloned());
+
+    let trend: Vec<(String, f64)> = match &account {
+        Some(a) if a.points.is_empty() => vec![("now".to_string(), a.current as f64)],
+        Some(a) => a
+            .points
+            .iter()
+            .map(|p| (fmt_short_date(p.date), p.value as f64))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let allocation = fin_state.read().allocation_history(today);
+    let kind = *drift_kind.read();
+    let drift: Vec<(String, f64)> = allocation
+        .iter()
+        .map(|p| (fmt_short_date(p.date), p.share(kind)))
+        .collect();
+    let mix = |p: &balances::AllocationPoint| {
+        let items = AssetKind::ALL
+            .iter()
+            .map(|k| (k.label().to_string(), p.value(*k)))
+            .collect();
+        build_pie(items, &base).0
+    };
+    // First recorded mix next to today's.
+    let then = allocation
+        .first()
+        .filter(|_| allocation.len() > 1)
+        .map(|p| (p.date, mix(p)));
+    let now_slices = allocation.last().map(mix).unwrap_or_default();
+
+    rsx! {
+        section { class: "border rounded p-3 space-y-2",
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-lg font-semibold", "Balances" }
+                button {
+                    class: "px-3 py-1 border rounded text-xs",
+                    onclick: move |_| {
+                        let open = !*reconciling.peek();
+                        if open {
+                            let st = fin_state.read();
+                            let current = st.assets.iter().map(|a| (a.id, a.value));
+                            let owed = st.liabilities.iter().map(|l| (l.id, l.balance));
+                            draft.set(current.chain(owed).collect());
+                            reconcile_date.set(today.to_string());
+                        }
+                        reconciling.set(open);
+                    },
+                    if *reconciling.read() { "Cancel" } else { "Reconcile balances" }
+                }
+            }
+            if *reconciling.read() {
+                div { class: "border rounded p-2 space-y-2",
+                    div { class: "flex items-center gap-2 text-xs",
+                        span { class: "opacity-60", "Balances as of" }
+                        input {
+                            r#type: "date",
+                            class: "border px-1 py-1 bg-transparent",
+                            value: "{reconcile_date}",
+                            oninput: move |e| reconcile_date.set(e.value()),
+                        }
+                    }
+                    table { class: "w-full text-sm border-collapse",
+                        tbody {
+                            for a in list.iter().cloned() {
+                                tr { key: "{a.id}",
+                                    td { class: "py-1 pr-2", "{a.name}" }
+                                    td { class: "py-1 pr-2 text-xs opacity-60", if a.liability { "owed" } else { "" } }
+                                    td { class: "py-1 pr-2 text-right",
+                                        MoneyInput {
+                                            cents: draft.read().iter().find(|(id, _)| *id == a.id).map_or(a.current, |(_, c)| *c),
+                                            on_commit: move |c: i64| {
+                                                let mut d = draft.write();
+                                                match d.iter_mut().find(|(id, _)| *id == a.id) {
+                                                    Some(entry) => entry.1 = c,
+                                                    None => d.push((a.id, c)),
+                                                }
+                                            }
+                                        }
+                                    }
+                                    td { class: "py-1 text-xs opacity-60", "{a.code}" }
+                                }
+                            }
+                        }
+                    }
+                    button {
+                        class: "px-3 py-1 border rounded text-xs",
+                        onclick: move |_| {
+                            let Some(date) = parse_iso_date(&reconcile_date.peek()) else { return; };
+                            let values = draft.peek().clone();
+                            {
+                                let mut st = fin_state.write();
+                                st.reconcile_balances(date, &values);
+                                if date == today {
+                                    st.record_snapshot();
+                                }
+                            }
+                            reconciling.set(false);
+                            on_change.call(());
+                        },
+                        "Save all balances"
+                    }
+                }
+            }
+            if list.is_empty() {
+                div { class: "text-xs opacity-60", "Add an asset or debt to track its balance." }
+            } else {
+                div { class: "grid md:grid-cols-2 gap-4",
+                    div { class: "space-y-1",
+                        div { class: "flex items-center justify-between text-xs",
+                            span { class: "opacity-60", "Account trend" }
+                            select {
+                                class: "border px-1 py-0.5 bg-transparent",
+                                value: "{account.as_ref().map(|a| a.id.to_string()).unwrap_or_default()}",
+                                onchange: move |e| selected.set(Uuid::parse_str(&e.value()).ok()),
+                                for a in list.iter() {
+                                    option { key: "{a.id}", value: "{a.id}", "{a.name}" }
+                                }
+                            }
+                        }
+                        if let Some(a) = account.clone() {
+                            LineChart {
+                                points: trend,
+                                color: if a.liability { "#f87171".to_string() } else { "#60a5fa".to_string() },
+                                currency: a.code.clone(),
+                            }
+                            if !a.points.is_empty() {
+                                div { class: "max-h-40 overflow-y-auto",
+                                    table { class: "w-full text-xs border-collapse",
+                                        tbody {
+                                            for p in a.points.iter().rev().copied() {
+                                                tr { key: "{p.date}",
+                                                    td { class: "py-0.5 pr-2", "{p.date}" }
+                                                    td { class: "py-0.5 pr-2 text-right", "{format_amount(p.value, &a.code)}" }
+                                                    td { class: "py-0.5 text-center w-8",
+                                                        button {
+                                                            class: "px-2 text-red-400",
+                                                            onclick: move |_| {
+                                                                {
+                                                                    let mut st = fin_state.write();
+                                                                    if let Some(x) = st.assets.iter_mut().find(|x| x.id == a.id) {
+                                                                        balances::remove_point(&mut x.balances, p.date);
+                                                                    } else if let Some(x) = st.liabilities.iter_mut().find(|x| x.id == a.id) {
+                                                                        balances::remove_point(&mut x.balances, p.date);
+                                                                    }
+                                                                }
+                                                                on_change.call(());
+                                                            },
+                                                            "✕"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div { class: "space-y-1",
+                        div { class: "flex items-center justify-between text-xs",
+                            span { class: "opacity-60", "Allocation drift" }
+                            select {
+                                class: "border px-1 py-0.5 bg-transparent",
+                                value: "{kind.label()}",
+                                onchange: move |e| drift_kind.set(AssetKind::from_label(&e.value())),
+                                for k in AssetKind::ALL { option { value: "{k.label()}", "{k.label()}" } }
+                            }
+                        }
+                        LineChart { points: drift, color: "#34d399".to_string(), currency: base.clone(), percent: true }
+                        div { class: "grid grid-cols-2 gap-2",
+                            if let Some((date, slices)) = then {
+                                div {
+                                    div { class: "text-xs opacity-60 text-center", "{date}" }
+                                    PieChart { slices }
+                                }
+                            }
+                            if !now_slices.is_empty() {
+                                div {
+                                    div { class: "text-xs opacity-60 text-center", "Today" }
+                                    PieChart { slices: now_slices }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "text-xs opacity-60",
+                "Every edit to a value records a dated point for that account. Balances are in each account's currency; the allocation mix is converted to {base} at today's rates."
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Hand-rendered SVG charts (no JS chart deps in a desktop webview), shared
+//! by the projection and balances panels.
+
+use dioxus::prelude::*;
+
+use super::component::format_amount;
+
+#[derive(Clone, PartialEq)]
+pub(super) struct PieSlice {
+    d: String,
+    color: String,
+    label: String,
+    note: String,
+    full: bool,
+}
+
+/// Turn (label, cents) pairs into renderable pie slices. Returns the slices and
+/// the positive total (0 ⇒ nothing to draw).
+pub(super) fn build_pie(items: Vec<(String, i64)>, code: &str) -> (Vec<PieSlice>, i64) {
+    const PALETTE: [&str; 8] = [
+        "#60a5fa", "#f87171", "#34d399", "#fbbf24", "#a78bfa", "#fb923c", "#22d3ee", "#f472b6",
+    ];
+    let total: i64 = items.iter().map(|(_, v)| *v).filter(|v| *v > 0).sum();
+    let mut slices = Vec::new();
+    if total <= 0 {
+        return (slices, 0);
+    }
+    let (cx, cy, r) = (100.0_f64, 100.0_f64, 90.0_f64);
+    let mut a0 = -std::f64::consts::FRAC_PI_2; // start at 12 o'clock
+    let mut idx = 0usize;
+    for (label, value) in items {
+        if value <= 0 {
+            continue;
+        }
+        let frac = value as f64 / total as f64;
+        let a1 = a0 + frac * std::f64::consts::TAU;
+        let color = PALETTE[idx % PALETTE.len()].to_string();
+        idx += 1;
+        let full = frac >= 0.999;
+        let d = if full {
+            String::new()
+        } else {
+            let (x1, y1) = (cx + r * a0.cos(), cy + r * a0.sin());
+            let (x2, y2) = (cx + r * a1.cos(), cy + r * a1.sin());
+            let large = if (a1 - a0) > std::f64::consts::PI {
+                1
+            } else {
+                0
+            };
+            format!(
+                "M {cx:.2} {cy:.2} L {x1:.2} {y1:.2} A {r:.2} {r:.2} 0 {large} 1 {x2:.2} {y2:.2} Z"
+            )
+        };
+        let note = format!("{} · {:.0}%", format_amount(value, code), frac * 100.0);
+        slices.push(PieSlice {
+            d,
+            color,
+            label,
+            note,
+            full,
+        });
+        a0 = a1;
+    }
+    (slices, total)
+}
+
+#[component]
+pub(super) fn PieChart(slices: Vec<PieSlice>) -> Element {
+    rsx! {
+        div { class: "flex gap-3 items-center flex-wrap",
+            svg { width: "150", height: "150", view_box: "0 0 200 200",
+                for (i, s) in slices.iter().enumerate() {
+                    if s.full {
+                        circle { key: "{i}", cx: "100", cy: "100", r: "90", fill: "{s.color}" }
+                    } else {
+                        path { key: "{i}", d: "{s.d}", fill: "{s.color}", stroke: "#0a0a0a", stroke_width: "1" }
+                    }
+                }
+            }
+            div { class: "flex flex-col gap-1",
+                for (i, s) in slices.iter().enumerate() {
+                    div { key: "{i}", class: "flex items-center gap-2 text-xs",
+                        span { style: "width:10px;height:10px;border-radius:2px;display:inline-block;background:{s.color}" }
+                        span { "{s.label}" }
+                        span { class: "opacity-60", "{s.note}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Dot {
+    cx: String,
+    cy: String,
+}
+
+/// `band` (low, high) and `dashed` are optional extra series, one value per
+/// point: a shaded range (e.g. a Monte Carlo p10–p90) and a secondary line.
+/// `percent` labels the axis as percentages instead of money.
+#[component]
+pub(super) fn LineChart(
+    points: Vec<(String, f64)>,
+    color: String,
+    currency: String,
+    #[props(default)] band: Vec<(f64, f64)>,
+    #[props(default)] dashed: Vec<f64>,
+    #[props(default)] percent: bool,
+) -> Element {
+    if points.is_empty() {
+        return rsx! {
+            div { class: "text-xs opacity-60 p-6 text-center",
+                "No history yet — edit a value or hit “Record point”, then check back over the coming days."
+            }
+        };
+    }
+
+    let (left, right, top, bottom) = (46.0_f64, 312.0_f64, 12.0_f64, 128.0_f64);
+    let n = points.len();
+    let mut mn = f64::INFINITY;
+    let mut mx = f64::NEG_INFINITY;
+    let extras = band
+        .iter()
+        .flat_map(|(lo, hi)| [*lo, *hi])
+        .chain(dashed.iter().copied());
+    for v in points.iter().map(|(_, v)| *v).chain(extras) {
+        mn = mn.min(v);
+        mx = mx.max(v);
+    }
+    if (mx - mn).abs() < 1e-9 {
+        mn -= 1.0;
+        mx += 1.0;
+    }
+    let x_at = |i: usize| {
+        if n == 1 {
+            (left + right) / 2.0
+        } else {
+            left + i as f64 * (right - left) / (n as f64 - 1.0)
+        }
+    };
+    let y_at = |v: f64| bottom - (v - mn) / (mx - mn) * (bottom - top);
+
+    let poly: String = points
+        .iter()
+        .enumerate()
+        .map(|(i, (_, v))| format!("{:.1},{:.1}", x_at(i), y_at(*v)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let band_poly: String = band
+        .iter()
+        .enumerate()
+        .map(|(i, (_, hi))| (i, *hi))
+        .chain(band.iter().enumerate().rev().map(|(i, (lo, _))| (i, *lo)))
+        .map(|(i, v)| format!("{:.1},{:.1}", x_at(i), y_at(v)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let dashed_poly: String = dashed
+        .iter()
+        .enumerate()
+        .map(|(i, v)| format!("{:.1},{:.1}", x_at(i), y_at(*v)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let dots: Vec<Dot> = points
+        .iter()
+        .enumerate()
+        .map(|(i, (_, v))| Dot {
+            cx: format!("{:.1}", x_at(i)),
+            cy: format!("{:.1}", y_at(*v)),
+        })
+        .collect();
+    let (y_top, y_bot) = if percent {
+        (format!("{mx:.0}%"), format!("{mn:.0}%"))
+    } else {
+        (
+            format_amount(mx as i64, &currency),
+            format_amount(mn as i64, &currency),
+        )
+    };
+    let x_first = points.first().unwrap().0.clone();
+    let x_last = points.last().unwrap().0.clone();
+
+    rsx! {
+        svg { width: "100%", height: "150", view_box: "0 0 320 160",
+            line { x1: "46", y1: "12", x2: "46", y2: "128", stroke: "#444", stroke_width: "1" }
+            line { x1: "46", y1: "128", x2: "312", y2: "128", stroke: "#444", stroke_width: "1" }
+            if !band.is_empty() {
+                polygon { points: "{band_poly}", fill: "{color}", fill_opacity: "0.15", stroke: "none" }
+            }
+            if !dashed.is_empty() {
+                polyline { points: "{dashed_poly}", fill: "none", stroke: "{color}", stroke_width: "1", stroke_dasharray: "3 2", opacity: "0.8" }
+            }
+            polyline { points: "{poly}", fill: "none", stroke: "{color}", stroke_width: "2" }
+            for (i, dot) in dots.iter().enumerate() {
+                circle { key: "{i}", cx: "{dot.cx}", cy: "{dot.cy}", r: "2.5", fill: "{color}" }
+            }
+            text { x: "4", y: "16", fill: "#888", font_size: "9", "{y_top}" }
+            text { x: "4", y: "128", fill: "#888", font_size: "9", "{y_bot}" }
+            text { x: "46", y: "142", fill: "#888", font_size: "9", "{x_first}" }
+            text { x: "268", y: "142", fill: "#888", font_size: "9", "{x_last}" }
+        }
+    }
+}
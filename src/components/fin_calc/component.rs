@@ -5,23 +5,35 @@ use std::{path::PathBuf, time::Duration};
 use uuid::Uuid;
 
 use crate::components::time::{use_time, Event, EventSource, Freq, Recurrence, When};
+use crate::models::finCalc::debt;
 use crate::models::finCalc::finances::{
-    AssetEntry, AssetKind, CashFlow, FinancesFile, Frequency, LiabilityEntry, LiabilityKind,
+    currency_symbol, normalize_code, AssetClass, AssetEntry, AssetKind, CashFlow, FinancesFile,
+    Frequency, IncomeBasis, LiabilityEntry, LiabilityKind,
 };
-use crate::utils::json_store::{err_to_string, load_json, save_json};
+use crate::models::finCalc::projection::{recurrence_for, to_naive};
+use crate::utils::json_store::{err_to_string, save_json};
+
+use super::balances::BalancesSection;
+use super::debt::PayoffSection;
+use super::envelopes::EnvelopesSection;
+use super::history::HistorySection;
+use super::ledger::LedgerSection;
+use super::projection::ProjectionSection;
+use super::tax::TaxSection;
 
 // ---------------------------------------------------------------------------
 // Formatting / parsing helpers
 // ---------------------------------------------------------------------------
 
-fn format_amount(cents: i64) -> String {
+pub(super) fn format_amount(cents: i64, code: &str) -> String {
     let sign = if cents < 0 { "-" } else { "" };
     let abs = cents.abs();
-    format!("{sign}${}.{:02}", abs / 100, abs % 100)
+    let sym = currency_symbol(code);
+    format!("{sign}{sym}{}.{:02}", abs / 100, abs % 100)
 }
 
-/// Bare value for an editable input (no `$`).
-fn cents_to_input(cents: i64) -> String {
+/// Bare value for an editable input (no currency symbol).
+pub(super) fn cents_to_input(cents: i64) -> String {
     let abs = cents.abs();
     let s = format!("{}.{:02}", abs / 100, abs % 100);
     if cents < 0 {
@@ -31,12 +43,12 @@ fn cents_to_input(cents: i64) -> String {
     }
 }
 
-/// Parse a typed dollar string ("45", "45.99", "$1,200.50") into cents.
-fn parse_dollars(s: &str) -> Option<i64> {
+/// Parse a typed money string ("45", "45.99", "$1,200.50", "€12") into cents.
+pub(super) fn parse_dollars(s: &str) -> Option<i64> {
     let cleaned: String = s
         .trim()
         .chars()
-        .filter(|c| !matches!(c, '$' | ',' | ' '))
+        .filter(|c| !matches!(c, '$' | '€' | '£' | '¥' | '₹' | ',' | ' '))
         .collect();
     if cleaned.is_empty() {
         return Some(0);
@@ -47,37 +59,15 @@ fn parse_dollars(s: &str) -> Option<i64> {
         .map(|f| (f * 100.0).round() as i64)
 }
 
-fn parse_iso_date(s: &str) -> Option<time::Date> {
+pub(super) fn parse_iso_date(s: &str) -> Option<time::Date> {
     let f = time::macros::format_description!("[year]-[month]-[day]");
     time::Date::parse(s, &f).ok()
 }
 
-fn to_naive(d: time::Date) -> Option<chrono::NaiveDate> {
-    chrono::NaiveDate::from_ymd_opt(d.year(), u8::from(d.month()) as u32, d.day() as u32)
-}
-
-fn freq_to_recurrence(f: Frequency) -> Option<Recurrence> {
-    let (freq, interval) = match f {
-        Frequency::OneTime => return None,
-        Frequency::Daily => (Freq::Daily, 1),
-        Frequency::Weekly => (Freq::Weekly, 1),
-        Frequency::Monthly => (Freq::Monthly, 1),
-        Frequency::Yearly => (Freq::Yearly, 1),
-        Frequency::EveryNDays(n) => (Freq::Daily, n.max(1)),
-        Frequency::EveryNMonths(n) => (Freq::Monthly, n.max(1)),
-    };
-    Some(Recurrence {
-        freq,
-        interval,
-        by_weekday: Vec::new(),
-        count: None,
-        until: None,
-    })
-}
-
 /// Desktop-only path outside the project folder, so saves don't trigger
-/// `dx serve` rebuilds. Matches the scheduler module's convention.
-fn default_finances_path() -> String {
+/// `dx serve` rebuilds. Matches the scheduler module's convention. Goals read
+/// balances from the same file.
+pub(crate) fn default_finances_path() -> String {
     if let Some(proj) = directories::ProjectDirs::from("com", "gauss", "momentum-dioxus") {
         let mut p: PathBuf = proj.data_dir().to_path_buf();
         p.push("finances.json");
@@ -86,14 +76,22 @@ fn default_finances_path() -> String {
     "finances.json".to_string()
 }
 
-fn short_path(p: &str) -> String {
+pub(super) fn short_path(p: &str) -> String {
     p.rsplit(['/', '\\']).next().unwrap_or(p).to_string()
 }
 
-fn fmt_short_date(d: time::Date) -> String {
+pub(super) fn fmt_short_date(d: time::Date) -> String {
     format!("{}/{}", u8::from(d.month()), d.day())
 }
 
+fn basis_label(b: Option<IncomeBasis>) -> &'static str {
+    b.map_or("Fixed", |b| b.label())
+}
+
+fn fmt_created(t: Option<time::OffsetDateTime>) -> String {
+    t.map(|t| format!("Added {}", t.date())).unwrap_or_default()
+}
+
 // ---------------------------------------------------------------------------
 // Entry point
 // ---------------------------------------------------------------------------
@@ -108,22 +106,22 @@ pub fn FinCalc(#[props(default)] overview: bool) -> Element {
 
 /// Compact snapshot for the Overview page. Loads the persisted file once.
 fn Overview() -> Element {
-    let snapshot =
-        use_hook(|| load_json::<FinancesFile>(default_finances_path()).unwrap_or_default());
+    let snapshot = use_hook(|| FinancesFile::load(default_finances_path()).unwrap_or_default());
     let net_worth = snapshot.net_worth();
     let monthly_net = snapshot.monthly_net();
+    let base = snapshot.currencies.base.clone();
 
     rsx! {
         div { class: "flex gap-4 text-secondary-color",
             div { class: "border rounded p-3 flex-1",
                 div { class: "text-xs uppercase opacity-60", "Net worth" }
-                div { class: "text-xl font-bold", "{format_amount(net_worth)}" }
+                div { class: "text-xl font-bold", "{format_amount(net_worth, &base)}" }
             }
             div { class: "border rounded p-3 flex-1",
                 div { class: "text-xs uppercase opacity-60", "Monthly net" }
                 div {
                     class: if monthly_net >= 0 { "text-xl font-bold text-green-400" } else { "text-xl font-bold text-red-400" },
-                    "{format_amount(monthly_net)}"
+                    "{format_amount(monthly_net, &base)}"
                 }
             }
         }
@@ -136,7 +134,7 @@ fn Overview() -> Element {
 // ---------------------------------------------------------------------------
 
 #[component]
-fn MoneyInput(cents: i64, on_commit: EventHandler<i64>) -> Element {
+pub(super) fn MoneyInput(cents: i64, on_commit: EventHandler<i64>) -> Element {
     let mut draft = use_signal(|| cents_to_input(cents));
     rsx! {
         input {
@@ -154,203 +152,28 @@ fn MoneyInput(cents: i64, on_commit: EventHandler<i64>) -> Element {
     }
 }
 
-// ---------------------------------------------------------------------------
-// Charts (hand-rendered SVG — no JS chart deps in a desktop webview)
-// ---------------------------------------------------------------------------
-
-#[derive(Clone, Copy, PartialEq)]
-enum PieMode {
-    All,
-    Expenses,
-    Income,
-    Assets,
-}
-impl PieMode {
-    const ALL: [PieMode; 4] = [
-        PieMode::All,
-        PieMode::Expenses,
-        PieMode::Income,
-        PieMode::Assets,
-    ];
-    fn label(&self) -> &'static str {
-        match self {
-            PieMode::All => "All",
-            PieMode::Expenses => "Expenses",
-            PieMode::Income => "Income",
-            PieMode::Assets => "Assets",
-        }
-    }
-}
-
-#[derive(Clone, Copy, PartialEq)]
-enum LineMode {
-    NetWorth,
-    TakeHome,
-    Future,
-}
-impl LineMode {
-    const ALL: [LineMode; 3] = [LineMode::NetWorth, LineMode::TakeHome, LineMode::Future];
-    fn label(&self) -> &'static str {
-        match self {
-            LineMode::NetWorth => "Net worth",
-            LineMode::TakeHome => "Take-home",
-            LineMode::Future => "Future",
-        }
-    }
-}
-
-#[derive(Clone, PartialEq)]
-struct PieSlice {
-    d: String,
-    color: String,
-    label: String,
-    note: String,
-    full: bool,
-}
-
-/// Turn (label, cents) pairs into renderable pie slices. Returns the slices and
-/// the positive total (0 ⇒ nothing to draw).
-fn build_pie(items: Vec<(String, i64)>) -> (Vec<PieSlice>, i64) {
-    const PALETTE: [&str; 8] = [
-        "#60a5fa", "#f87171", "#34d399", "#fbbf24", "#a78bfa", "#fb923c", "#22d3ee", "#f472b6",
-    ];
-    let total: i64 = items.iter().map(|(_, v)| *v).filter(|v| *v > 0).sum();
-    let mut slices = Vec::new();
-    if total <= 0 {
-        return (slices, 0);
-    }
-    let (cx, cy, r) = (100.0_f64, 100.0_f64, 90.0_f64);
-    let mut a0 = -std::f64::consts::FRAC_PI_2; // start at 12 o'clock
-    let mut idx = 0usize;
-    for (label, value) in items {
-        if value <= 0 {
-            continue;
-        }
-        let frac = value as f64 / total as f64;
-        let a1 = a0 + frac * std::f64::consts::TAU;
-        let color = PALETTE[idx % PALETTE.len()].to_string();
-        idx += 1;
-        let full = frac >= 0.999;
-        let d = if full {
-            String::new()
-        } else {
-            let (x1, y1) = (cx + r * a0.cos(), cy + r * a0.sin());
-            let (x2, y2) = (cx + r * a1.cos(), cy + r * a1.sin());
-            let large = if (a1 - a0) > std::f64::consts::PI {
-                1
-            } else {
-                0
-            };
-            format!(
-                "M {cx:.2} {cy:.2} L {x1:.2} {y1:.2} A {r:.2} {r:.2} 0 {large} 1 {x2:.2} {y2:.2} Z"
-            )
-        };
-        let note = format!("{} · {:.0}%", format_amount(value), frac * 100.0);
-        slices.push(PieSlice {
-            d,
-            color,
-            label,
-            note,
-            full,
-        });
-        a0 = a1;
-    }
-    (slices, total)
-}
-
-#[component]
-fn PieChart(slices: Vec<PieSlice>) -> Element {
-    rsx! {
-        div { class: "flex gap-3 items-center flex-wrap",
-            svg { width: "150", height: "150", view_box: "0 0 200 200",
-                for (i, s) in slices.iter().enumerate() {
-                    if s.full {
-                        circle { key: "{i}", cx: "100", cy: "100", r: "90", fill: "{s.color}" }
-                    } else {
-                        path { key: "{i}", d: "{s.d}", fill: "{s.color}", stroke: "#0a0a0a", stroke_width: "1" }
-                    }
-                }
-            }
-            div { class: "flex flex-col gap-1",
-                for (i, s) in slices.iter().enumerate() {
-                    div { key: "{i}", class: "flex items-center gap-2 text-xs",
-                        span { style: "width:10px;height:10px;border-radius:2px;display:inline-block;background:{s.color}" }
-                        span { "{s.label}" }
-                        span { class: "opacity-60", "{s.note}" }
-                    }
-                }
-            }
-        }
-    }
-}
-
-struct Dot {
-    cx: String,
-    cy: String,
-}
-
+/// Currency picker for one entry. Picking the base stores `None`, so the entry
+/// keeps following the file's base currency.
 #[component]
-fn LineChart(points: Vec<(String, f64)>, color: String) -> Element {
-    if points.is_empty() {
-        return rsx! {
-            div { class: "text-xs opacity-60 p-6 text-center",
-                "No history yet — edit a value or hit “Record point”, then check back over the coming days."
-            }
-        };
-    }
-
-    let (left, right, top, bottom) = (46.0_f64, 312.0_f64, 12.0_f64, 128.0_f64);
-    let n = points.len();
-    let mut mn = f64::INFINITY;
-    let mut mx = f64::NEG_INFINITY;
-    for (_, v) in &points {
-        mn = mn.min(*v);
-        mx = mx.max(*v);
-    }
-    if (mx - mn).abs() < 1e-9 {
-        mn -= 1.0;
-        mx += 1.0;
-    }
-    let x_at = |i: usize| {
-        if n == 1 {
-            (left + right) / 2.0
-        } else {
-            left + i as f64 * (right - left) / (n as f64 - 1.0)
-        }
-    };
-    let y_at = |v: f64| bottom - (v - mn) / (mx - mn) * (bottom - top);
-
-    let poly: String = points
-        .iter()
-        .enumerate()
-        .map(|(i, (_, v))| format!("{:.1},{:.1}", x_at(i), y_at(*v)))
-        .collect::<Vec<_>>()
-        .join(" ");
-    let dots: Vec<Dot> = points
-        .iter()
-        .enumerate()
-        .map(|(i, (_, v))| Dot {
-            cx: format!("{:.1}", x_at(i)),
-            cy: format!("{:.1}", y_at(*v)),
-        })
-        .collect();
-    let y_top = format_amount(mx as i64);
-    let y_bot = format_amount(mn as i64);
-    let x_first = points.first().unwrap().0.clone();
-    let x_last = points.last().unwrap().0.clone();
-
+fn CurrencySelect(
+    code: Option<String>,
+    base: String,
+    codes: Vec<String>,
+    on_change: EventHandler<Option<String>>,
+) -> Element {
+    let current = code.unwrap_or_else(|| base.clone());
     rsx! {
-        svg { width: "100%", height: "150", view_box: "0 0 320 160",
-            line { x1: "46", y1: "12", x2: "46", y2: "128", stroke: "#444", stroke_width: "1" }
-            line { x1: "46", y1: "128", x2: "312", y2: "128", stroke: "#444", stroke_width: "1" }
-            polyline { points: "{poly}", fill: "none", stroke: "{color}", stroke_width: "2" }
-            for (i, dot) in dots.iter().enumerate() {
-                circle { key: "{i}", cx: "{dot.cx}", cy: "{dot.cy}", r: "2.5", fill: "{color}" }
+        select {
+            class: "border px-1 py-1 bg-transparent",
+            value: "{current}",
+            onchange: move |e| {
+                let v = e.value();
+                on_change.call(if v == base { None } else { Some(v) });
+            },
+            for c in codes.iter() { option { key: "{c}", value: "{c}", "{c}" } }
+            if !codes.contains(&current) {
+                option { value: "{current}", "{current}" }
             }
-            text { x: "4", y: "16", fill: "#888", font_size: "9", "{y_top}" }
-            text { x: "4", y: "128", fill: "#888", font_size: "9", "{y_bot}" }
-            text { x: "46", y: "142", fill: "#888", font_size: "9", "{x_first}" }
-            text { x: "268", y: "142", fill: "#888", font_size: "9", "{x_last}" }
         }
     }
 }
@@ -366,7 +189,7 @@ fn FinCalcDetailed() -> Element {
     // Load persisted data at mount, in the initializer (never write a signal
     // during render).
     let mut fin_state =
-        use_signal(|| load_json::<FinancesFile>(default_finances_path()).unwrap_or_default());
+        use_signal(|| FinancesFile::load(default_finances_path()).unwrap_or_default());
     let mut status = use_signal(|| None::<String>);
     let mut save_tick = use_signal(|| 0_u64);
 
@@ -404,85 +227,31 @@ fn FinCalcDetailed() -> Element {
     let net_worth = fin_state.read().net_worth();
     let total_assets = fin_state.read().total_assets();
     let total_liab = fin_state.read().total_liabilities();
+    let hard_assets = fin_state.read().total_by_class(AssetClass::Hard);
     let m_income = fin_state.read().monthly_income();
     let m_expenses = fin_state.read().monthly_expenses();
-    let m_net = m_income - m_expenses;
-
-    // ---- Chart data -------------------------------------------------------
-    let pie_mode = use_signal(|| PieMode::All);
-    let line_mode = use_signal(|| LineMode::NetWorth);
-
-    let (pie_slices, pie_total) = {
-        let st = fin_state.read();
-        let items: Vec<(String, i64)> = match *pie_mode.read() {
-            PieMode::All => vec![
-                ("Income /mo".to_string(), st.monthly_income()),
-                ("Expenses /mo".to_string(), st.monthly_expenses()),
-                ("Assets".to_string(), st.total_assets()),
-                ("Liabilities".to_string(), st.total_liabilities()),
-            ],
-            PieMode::Expenses => st
-                .expenses
-                .iter()
-                .map(|f| (f.name.clone(), f.per_month_cents()))
-                .collect(),
-            PieMode::Income => st
-                .income
-                .iter()
-                .map(|f| (f.name.clone(), f.per_month_cents()))
-                .collect(),
-            PieMode::Assets => AssetKind::ALL
-                .iter()
-                .filter_map(|k| {
-                    let sum: i64 = st
-                        .assets
-                        .iter()
-                        .filter(|a| a.kind == *k)
-                        .map(|a| a.value)
-                        .sum();
-                    (sum > 0).then(|| (k.label().to_string(), sum))
-                })
-                .collect(),
-        };
-        build_pie(items)
-    };
+    let m_withheld = fin_state.read().monthly_withheld();
+    let m_net = fin_state.read().monthly_net();
+    let base = fin_state.read().currencies.base.clone();
+    let codes = fin_state.read().currencies.codes();
+    let missing_list = fin_state.read().missing_rates().join(", ");
+    let envelope_names: Vec<(Uuid, String)> = fin_state
+        .read()
+        .envelopes
+        .iter()
+        .map(|e| (e.id, e.name.clone()))
+        .collect();
 
-    let line_color = match *line_mode.read() {
-        LineMode::NetWorth => "#60a5fa",
-        LineMode::TakeHome => "#34d399",
-        LineMode::Future => "#a78bfa",
-    }
-    .to_string();
-
-    let line_points: Vec<(String, f64)> = {
-        let st = fin_state.read();
-        match *line_mode.read() {
-            LineMode::NetWorth => st
-                .history
-                .iter()
-                .map(|s| (fmt_short_date(s.date), s.net_worth as f64))
-                .collect(),
-            LineMode::TakeHome => st
-                .history
-                .iter()
-                .map(|s| (fmt_short_date(s.date), s.take_home() as f64))
-                .collect(),
-            LineMode::Future => {
-                let base = st.net_worth();
-                let step = st.monthly_net();
-                (0..=12)
-                    .map(|m| {
-                        let label = match m {
-                            0 => "now".to_string(),
-                            12 => "+12 mo".to_string(),
-                            _ => String::new(),
-                        };
-                        (label, (base + step * m as i64) as f64)
-                    })
-                    .collect()
-            }
-        }
-    };
+    // ---- Currencies ------------------------------------------------------
+    let mut new_code = use_signal(String::new);
+    let mut new_rate = use_signal(String::new);
+    let rate_rows: Vec<(String, f64)> = fin_state
+        .read()
+        .currencies
+        .rates
+        .iter()
+        .map(|(c, r)| (c.clone(), *r))
+        .collect();
 
     let on_sync = move |_| {
         scheduler.remove_by_source(EventSource::FinCalc);
@@ -502,15 +271,51 @@ fn FinCalcDetailed() -> Element {
             };
             scheduler.add_event(Event {
                 id: Uuid::nil(),
-                title: format!("{tag}: {} ({})", flow.name, format_amount(flow.amount)),
+                title: format!(
+                    "{tag}: {} ({})",
+                    flow.name,
+                    format_amount(flow.amount, st.currencies.resolve(flow.currency.as_deref()))
+                ),
                 notes: String::new(),
                 when: When::AllDay { date },
                 source: EventSource::FinCalc,
-                recurrence: freq_to_recurrence(flow.frequency),
+                recurrence: recurrence_for(flow.frequency),
                 link: Some(flow.id.to_string()),
             });
             count += 1;
         }
+        // Loan payments: monthly on the due day, for as many months as the
+        // minimum-payment schedule runs (open-ended if it never clears).
+        let from = time::OffsetDateTime::now_utc().date();
+        for l in st.liabilities.iter() {
+            if l.min_payment == 0 || l.balance <= 0 {
+                continue;
+            }
+            let sched = debt::amortize(l, from, 0);
+            let Some(date) = to_naive(debt::next_payment_date(from, l.payment_day)) else {
+                continue;
+            };
+            scheduler.add_event(Event {
+                id: Uuid::nil(),
+                title: format!(
+                    "Payment: {} ({})",
+                    l.name,
+                    format_amount(l.min_payment, st.currencies.resolve(l.currency.as_deref()))
+                ),
+                notes: String::new(),
+                when: When::AllDay { date },
+                source: EventSource::FinCalc,
+                recurrence: Some(Recurrence {
+                    freq: Freq::Monthly,
+                    interval: 1,
+                    by_weekday: Vec::new(),
+                    count: sched.payoff.map(|_| sched.rows.len() as u32),
+                    until: None,
+                }),
+                link: Some(l.id.to_string()),
+            });
+            count += 1;
+        }
         let mut status = status;
         status.set(Some(format!("Pushed {count} item(s) to the calendar")));
     };
@@ -522,65 +327,32 @@ fn FinCalcDetailed() -> Element {
             div { class: "grid grid-cols-2 md:grid-cols-4 gap-3",
                 div { class: "border rounded p-3",
                     div { class: "text-xs uppercase opacity-60", "Net worth" }
-                    div { class: if net_worth >= 0 { "text-xl font-bold text-green-400" } else { "text-xl font-bold text-red-400" }, "{format_amount(net_worth)}" }
+                    div { class: if net_worth >= 0 { "text-xl font-bold text-green-400" } else { "text-xl font-bold text-red-400" }, "{format_amount(net_worth, &base)}" }
                 }
                 div { class: "border rounded p-3",
                     div { class: "text-xs uppercase opacity-60", "Monthly net" }
-                    div { class: if m_net >= 0 { "text-xl font-bold text-green-400" } else { "text-xl font-bold text-red-400" }, "{format_amount(m_net)}" }
+                    div { class: if m_net >= 0 { "text-xl font-bold text-green-400" } else { "text-xl font-bold text-red-400" }, "{format_amount(m_net, &base)}" }
                 }
                 div { class: "border rounded p-3",
                     div { class: "text-xs uppercase opacity-60", "Monthly income" }
-                    div { class: "text-xl font-bold text-green-400", "{format_amount(m_income)}" }
+                    div { class: "text-xl font-bold text-green-400", "{format_amount(m_income, &base)}" }
                 }
                 div { class: "border rounded p-3",
                     div { class: "text-xs uppercase opacity-60", "Monthly expenses" }
-                    div { class: "text-xl font-bold text-red-400", "{format_amount(m_expenses)}" }
+                    div { class: "text-xl font-bold text-red-400", "{format_amount(m_expenses, &base)}" }
                 }
             }
             div { class: "text-xs opacity-60",
-                "Assets {format_amount(total_assets)} − Liabilities {format_amount(total_liab)}. Income & expenses are normalized to a monthly figure by their frequency."
-            }
-
-            // ---- Charts ---------------------------------------------------
-            div { class: "grid md:grid-cols-2 gap-3",
-                div { class: "border rounded p-3",
-                    div { class: "flex items-center justify-between mb-2",
-                        h3 { class: "text-lg font-semibold", "Breakdown" }
-                        div { class: "flex gap-1",
-                            for m in PieMode::ALL {
-                                button {
-                                    key: "{m.label()}",
-                                    class: if *pie_mode.read() == m { "px-2 py-0.5 border rounded text-xs bg-neutral-700" } else { "px-2 py-0.5 border rounded text-xs" },
-                                    onclick: move |_| { let mut pm = pie_mode; pm.set(m); },
-                                    "{m.label()}"
-                                }
-                            }
-                        }
-                    }
-                    if pie_total > 0 {
-                        PieChart { slices: pie_slices }
-                    } else {
-                        div { class: "text-xs opacity-60 p-6 text-center", "Nothing with a positive value to chart yet." }
-                    }
-                }
-                div { class: "border rounded p-3",
-                    div { class: "flex items-center justify-between mb-2",
-                        h3 { class: "text-lg font-semibold", "Trend" }
-                        div { class: "flex gap-1",
-                            for m in LineMode::ALL {
-                                button {
-                                    key: "{m.label()}",
-                                    class: if *line_mode.read() == m { "px-2 py-0.5 border rounded text-xs bg-neutral-700" } else { "px-2 py-0.5 border rounded text-xs" },
-                                    onclick: move |_| { let mut lm = line_mode; lm.set(m); },
-                                    "{m.label()}"
-                                }
-                            }
-                        }
-                    }
-                    LineChart { points: line_points, color: line_color }
+                "Assets {format_amount(total_assets, &base)} (hard {format_amount(hard_assets, &base)}, soft {format_amount(total_assets - hard_assets, &base)}) − Liabilities {format_amount(total_liab, &base)}. Income & expenses are normalized to a monthly figure by their frequency."
+                if m_withheld > 0 {
+                    " Monthly net is after {format_amount(m_withheld, &base)} of tax and pre-tax contributions."
                 }
             }
 
+            ProjectionSection { fin_state, on_change: move |_| mark_dirty.call(()) }
+
+            HistorySection { fin_state, on_change: move |_| mark_dirty.call(()) }
+
             // ---- Assets ---------------------------------------------------
             section { class: "border rounded p-3 space-y-2",
                 div { class: "flex items-center justify-between",
@@ -595,7 +367,12 @@ fn FinCalcDetailed() -> Element {
                     thead { tr {
                         th { class: "text-left py-1", "Name" }
                         th { class: "text-left py-1", "Type" }
+                        th { class: "text-left py-1", "Class" }
                         th { class: "text-right py-1", "Value" }
+                        th { class: "text-left py-1 pl-2", "Cur" }
+                        th { class: "text-right py-1", "Return %" }
+                        th { class: "text-right py-1", "Volatility %" }
+                        th { class: "text-right py-1", "Contrib /mo" }
                         th { class: "w-8" }
                     } }
                     tbody {
@@ -604,6 +381,7 @@ fn FinCalcDetailed() -> Element {
                                 td { class: "py-1 pr-2",
                                     input {
                                         class: "border px-2 py-1 w-full bg-transparent",
+                                        title: "{fmt_created(a.created_at)}",
                                         value: "{a.name}",
                                         oninput: move |e| {
                                             let v = e.value();
@@ -624,15 +402,88 @@ fn FinCalcDetailed() -> Element {
                                         for k in AssetKind::ALL { option { value: "{k.label()}", "{k.label()}" } }
                                     }
                                 }
+                                td { class: "py-1 pr-2",
+                                    select {
+                                        class: "border px-1 py-1 bg-transparent",
+                                        value: "{a.class().label()}",
+                                        onchange: move |e| {
+                                            let c = AssetClass::from_label(&e.value());
+                                            {
+                                                let mut st = fin_state.write();
+                                                if let Some(x) = st.assets.iter_mut().find(|x| x.id == a.id) {
+                                                    // Following the kind's default stays implicit.
+                                                    x.class = (c != x.kind.default_class()).then_some(c);
+                                                }
+                                            }
+                                            mark_dirty.call(());
+                                        },
+                                        for c in AssetClass::ALL { option { value: "{c.label()}", "{c.label()}" } }
+                                    }
+                                }
                                 td { class: "py-1 pr-2 text-right",
                                     MoneyInput {
                                         cents: a.value,
                                         on_commit: move |c| {
-                                            { let mut st = fin_state.write(); if let Some(x) = st.assets.iter_mut().find(|x| x.id == a.id) { x.value = c; } }
+                                            { let mut st = fin_state.write(); if let Some(x) = st.assets.iter_mut().find(|x| x.id == a.id) { x.record_value(time::OffsetDateTime::now_utc().date(), c); } }
+                                            mark_dirty.call(());
+                                        }
+                                    }
+                                }
+                                td { class: "py-1 pl-2",
+                                    CurrencySelect {
+                                        code: a.currency.clone(),
+                                        base: base.clone(),
+                                        codes: codes.clone(),
+                                        on_change: move |c| {
+                                            { let mut st = fin_state.write(); if let Some(x) = st.assets.iter_mut().find(|x| x.id == a.id) { x.currency = c; } }
                                             mark_dirty.call(());
                                         }
                                     }
                                 }
+                                if a.kind == AssetKind::Investment {
+                                    td { class: "py-1 pr-2 text-right",
+                                        input {
+                                            r#type: "number",
+                                            step: "0.1",
+                                            class: "border px-2 py-1 w-16 bg-transparent text-right",
+                                            value: "{a.expected_return}",
+                                            onchange: move |e| {
+                                                if let Ok(v) = e.value().trim().parse::<f64>() {
+                                                    { let mut st = fin_state.write(); if let Some(x) = st.assets.iter_mut().find(|x| x.id == a.id) { x.expected_return = v; } }
+                                                    mark_dirty.call(());
+                                                }
+                                            }
+                                        }
+                                    }
+                                    td { class: "py-1 pr-2 text-right",
+                                        input {
+                                            r#type: "number",
+                                            step: "0.1",
+                                            min: "0",
+                                            class: "border px-2 py-1 w-16 bg-transparent text-right",
+                                            value: "{a.volatility}",
+                                            onchange: move |e| {
+                                                if let Ok(v) = e.value().trim().parse::<f64>() {
+                                                    { let mut st = fin_state.write(); if let Some(x) = st.assets.iter_mut().find(|x| x.id == a.id) { x.volatility = v.max(0.0); } }
+                                                    mark_dirty.call(());
+                                                }
+                                            }
+                                        }
+                                    }
+                                    td { class: "py-1 pr-2 text-right",
+                                        MoneyInput {
+                                            cents: a.contribution,
+                                            on_commit: move |c: i64| {
+                                                { let mut st = fin_state.write(); if let Some(x) = st.assets.iter_mut().find(|x| x.id == a.id) { x.contribution = c.max(0); } }
+                                                mark_dirty.call(());
+                                            }
+                                        }
+                                    }
+                                } else {
+                                    td {}
+                                    td {}
+                                    td {}
+                                }
                                 td { class: "py-1 text-center",
                                     button {
                                         class: "px-2 text-red-400",
@@ -661,6 +512,10 @@ fn FinCalcDetailed() -> Element {
                         th { class: "text-left py-1", "Name" }
                         th { class: "text-left py-1", "Type" }
                         th { class: "text-right py-1", "Balance" }
+                        th { class: "text-left py-1 pl-2", "Cur" }
+                        th { class: "text-right py-1", "APR %" }
+                        th { class: "text-right py-1", "Min /mo" }
+                        th { class: "text-right py-1", "Due day" }
                         th { class: "w-8" }
                     } }
                     tbody {
@@ -669,6 +524,7 @@ fn FinCalcDetailed() -> Element {
                                 td { class: "py-1 pr-2",
                                     input {
                                         class: "border px-2 py-1 w-full bg-transparent",
+                                        title: "{fmt_created(l.created_at)}",
                                         value: "{l.name}",
                                         oninput: move |e| {
                                             let v = e.value();
@@ -693,11 +549,61 @@ fn FinCalcDetailed() -> Element {
                                     MoneyInput {
                                         cents: l.balance,
                                         on_commit: move |c| {
-                                            { let mut st = fin_state.write(); if let Some(x) = st.liabilities.iter_mut().find(|x| x.id == l.id) { x.balance = c; } }
+                                            { let mut st = fin_state.write(); if let Some(x) = st.liabilities.iter_mut().find(|x| x.id == l.id) { x.record_balance(time::OffsetDateTime::now_utc().date(), c); } }
+                                            mark_dirty.call(());
+                                        }
+                                    }
+                                }
+                                td { class: "py-1 pl-2",
+                                    CurrencySelect {
+                                        code: l.currency.clone(),
+                                        base: base.clone(),
+                                        codes: codes.clone(),
+                                        on_change: move |c| {
+                                            { let mut st = fin_state.write(); if let Some(x) = st.liabilities.iter_mut().find(|x| x.id == l.id) { x.currency = c; } }
+                                            mark_dirty.call(());
+                                        }
+                                    }
+                                }
+                                td { class: "py-1 pr-2 text-right",
+                                    input {
+                                        r#type: "number",
+                                        step: "0.01",
+                                        min: "0",
+                                        class: "border px-2 py-1 w-20 bg-transparent text-right",
+                                        value: "{l.apr}",
+                                        onchange: move |e| {
+                                            if let Ok(v) = e.value().trim().parse::<f64>() {
+                                                { let mut st = fin_state.write(); if let Some(x) = st.liabilities.iter_mut().find(|x| x.id == l.id) { x.apr = v.max(0.0); } }
+                                                mark_dirty.call(());
+                                            }
+                                        }
+                                    }
+                                }
+                                td { class: "py-1 pr-2 text-right",
+                                    MoneyInput {
+                                        cents: l.min_payment,
+                                        on_commit: move |c| {
+                                            { let mut st = fin_state.write(); if let Some(x) = st.liabilities.iter_mut().find(|x| x.id == l.id) { x.min_payment = c.max(0); } }
                                             mark_dirty.call(());
                                         }
                                     }
                                 }
+                                td { class: "py-1 pr-2 text-right",
+                                    input {
+                                        r#type: "number",
+                                        min: "1",
+                                        max: "28",
+                                        class: "border px-2 py-1 w-14 bg-transparent text-right",
+                                        value: "{l.payment_day}",
+                                        onchange: move |e| {
+                                            if let Ok(v) = e.value().trim().parse::<u8>() {
+                                                { let mut st = fin_state.write(); if let Some(x) = st.liabilities.iter_mut().find(|x| x.id == l.id) { x.payment_day = v.clamp(1, 28); } }
+                                                mark_dirty.call(());
+                                            }
+                                        }
+                                    }
+                                }
                                 td { class: "py-1 text-center",
                                     button {
                                         class: "px-2 text-red-400",
@@ -711,6 +617,11 @@ fn FinCalcDetailed() -> Element {
                 }
             }
 
+            // ---- Balances -------------------------------------------------
+            BalancesSection { fin_state, on_change: move |_| mark_dirty.call(()) }
+
+            PayoffSection { fin_state }
+
             // ---- Income ---------------------------------------------------
             section { class: "border rounded p-3 space-y-2",
                 div { class: "flex items-center justify-between",
@@ -724,10 +635,13 @@ fn FinCalcDetailed() -> Element {
                 table { class: "w-full text-sm border-collapse",
                     thead { tr {
                         th { class: "text-left py-1", "Name" }
+                        th { class: "text-left py-1 pl-2", "Basis" }
                         th { class: "text-right py-1", "Amount" }
+                        th { class: "text-left py-1 pl-2", "Cur" }
                         th { class: "text-left py-1 pl-2", "Frequency" }
                         th { class: "text-left py-1 pl-2", "Next date" }
                         th { class: "text-right py-1", "Per month" }
+                        th { class: "text-center py-1 pl-2", "Gross" }
                         th { class: "w-8" }
                     } }
                     tbody {
@@ -736,6 +650,7 @@ fn FinCalcDetailed() -> Element {
                                 td { class: "py-1 pr-2",
                                     input {
                                         class: "border px-2 py-1 w-full bg-transparent",
+                                        title: "{fmt_created(f.created_at)}",
                                         value: "{f.name}",
                                         oninput: move |e| {
                                             let v = e.value();
@@ -744,11 +659,88 @@ fn FinCalcDetailed() -> Element {
                                         }
                                     }
                                 }
+                                td { class: "py-1 pl-2",
+                                    select {
+                                        class: "border px-1 py-1 bg-transparent",
+                                        value: "{basis_label(f.basis)}",
+                                        onchange: move |e| {
+                                            {
+                                                let mut st = fin_state.write();
+                                                if let Some(x) = st.income.iter_mut().find(|x| x.id == f.id) {
+                                                    x.basis = match e.value().as_str() {
+                                                        "Hourly" => Some(IncomeBasis::Hourly { rate: 0, hours_per_week: 40.0 }),
+                                                        "Salary" => Some(IncomeBasis::Salary { yearly: x.per_month_cents() * 12 }),
+                                                        _ => None,
+                                                    };
+                                                    if x.basis.is_some() { x.taxable = true; }
+                                                    x.apply_basis();
+                                                }
+                                            }
+                                            mark_dirty.call(());
+                                        },
+                                        option { value: "Fixed", "Fixed" }
+                                        option { value: "Hourly", "Hourly" }
+                                        option { value: "Salary", "Salary" }
+                                    }
+                                }
                                 td { class: "py-1 pr-2 text-right",
-                                    MoneyInput {
-                                        cents: f.amount,
-                                        on_commit: move |amt| {
-                                            { let mut st = fin_state.write(); if let Some(x) = st.income.iter_mut().find(|x| x.id == f.id) { x.amount = amt; } }
+                                    {match f.basis {
+                                        None => rsx! {
+                                            MoneyInput {
+                                                cents: f.amount,
+                                                on_commit: move |amt| {
+                                                    { let mut st = fin_state.write(); if let Some(x) = st.income.iter_mut().find(|x| x.id == f.id) { x.amount = amt; } }
+                                                    mark_dirty.call(());
+                                                }
+                                            }
+                                        },
+                                        Some(IncomeBasis::Hourly { rate, hours_per_week }) => rsx! {
+                                            div { class: "flex items-center justify-end gap-1",
+                                                MoneyInput {
+                                                    cents: rate,
+                                                    on_commit: move |c: i64| {
+                                                        { let mut st = fin_state.write(); if let Some(x) = st.income.iter_mut().find(|x| x.id == f.id) { x.basis = Some(IncomeBasis::Hourly { rate: c.max(0), hours_per_week }); x.apply_basis(); } }
+                                                        mark_dirty.call(());
+                                                    }
+                                                }
+                                                span { class: "opacity-60 text-xs", "/h ×" }
+                                                input {
+                                                    r#type: "number",
+                                                    step: "0.5",
+                                                    min: "0",
+                                                    class: "border px-2 py-1 w-16 bg-transparent text-right",
+                                                    value: "{hours_per_week}",
+                                                    onchange: move |e| {
+                                                        if let Ok(h) = e.value().trim().parse::<f64>() {
+                                                            { let mut st = fin_state.write(); if let Some(x) = st.income.iter_mut().find(|x| x.id == f.id) { x.basis = Some(IncomeBasis::Hourly { rate, hours_per_week: h.max(0.0) }); x.apply_basis(); } }
+                                                            mark_dirty.call(());
+                                                        }
+                                                    }
+                                                }
+                                                span { class: "opacity-60 text-xs", "h/wk" }
+                                            }
+                                        },
+                                        Some(IncomeBasis::Salary { yearly }) => rsx! {
+                                            div { class: "flex items-center justify-end gap-1",
+                                                MoneyInput {
+                                                    cents: yearly,
+                                                    on_commit: move |c: i64| {
+                                                        { let mut st = fin_state.write(); if let Some(x) = st.income.iter_mut().find(|x| x.id == f.id) { x.basis = Some(IncomeBasis::Salary { yearly: c.max(0) }); x.apply_basis(); } }
+                                                        mark_dirty.call(());
+                                                    }
+                                                }
+                                                span { class: "opacity-60 text-xs", "/yr" }
+                                            }
+                                        },
+                                    }}
+                                }
+                                td { class: "py-1 pl-2",
+                                    CurrencySelect {
+                                        code: f.currency.clone(),
+                                        base: base.clone(),
+                                        codes: codes.clone(),
+                                        on_change: move |c| {
+                                            { let mut st = fin_state.write(); if let Some(x) = st.income.iter_mut().find(|x| x.id == f.id) { x.currency = c; } }
                                             mark_dirty.call(());
                                         }
                                     }
@@ -756,6 +748,7 @@ fn FinCalcDetailed() -> Element {
                                 td { class: "py-1 pl-2",
                                     select {
                                         class: "border px-1 py-1 bg-transparent",
+                                        disabled: f.basis.is_some(),
                                         value: "{f.frequency.label()}",
                                         onchange: move |e| {
                                             let fr = Frequency::from_label(&e.value());
@@ -778,7 +771,19 @@ fn FinCalcDetailed() -> Element {
                                         }
                                     }
                                 }
-                                td { class: "py-1 pr-2 text-right opacity-70", "{format_amount(f.per_month_cents())}" }
+                                td { class: "py-1 pr-2 text-right opacity-70", "{format_amount(f.per_month_cents(), fin_state.read().currencies.resolve(f.currency.as_deref()))}" }
+                                td { class: "py-1 pl-2 text-center",
+                                    input {
+                                        r#type: "checkbox",
+                                        title: "Gross pay: taxes and pre-tax contributions come out of it",
+                                        checked: f.taxable,
+                                        onchange: move |e| {
+                                            let v = e.checked();
+                                            { let mut st = fin_state.write(); if let Some(x) = st.income.iter_mut().find(|x| x.id == f.id) { x.taxable = v; } }
+                                            mark_dirty.call(());
+                                        }
+                                    }
+                                }
                                 td { class: "py-1 text-center",
                                     button {
                                         class: "px-2 text-red-400",
@@ -792,6 +797,9 @@ fn FinCalcDetailed() -> Element {
                 }
             }
 
+            // ---- Taxes ----------------------------------------------------
+            TaxSection { fin_state, on_change: move |_| mark_dirty.call(()) }
+
             // ---- Expenses -------------------------------------------------
             section { class: "border rounded p-3 space-y-2",
                 div { class: "flex items-center justify-between",
@@ -806,9 +814,11 @@ fn FinCalcDetailed() -> Element {
                     thead { tr {
                         th { class: "text-left py-1", "Name" }
                         th { class: "text-right py-1", "Amount" }
+                        th { class: "text-left py-1 pl-2", "Cur" }
                         th { class: "text-left py-1 pl-2", "Frequency" }
                         th { class: "text-left py-1 pl-2", "Next date" }
                         th { class: "text-right py-1", "Per month" }
+                        th { class: "text-left py-1 pl-2", "Envelope" }
                         th { class: "w-8" }
                     } }
                     tbody {
@@ -817,6 +827,7 @@ fn FinCalcDetailed() -> Element {
                                 td { class: "py-1 pr-2",
                                     input {
                                         class: "border px-2 py-1 w-full bg-transparent",
+                                        title: "{fmt_created(f.created_at)}",
                                         value: "{f.name}",
                                         oninput: move |e| {
                                             let v = e.value();
@@ -834,6 +845,17 @@ fn FinCalcDetailed() -> Element {
                                         }
                                     }
                                 }
+                                td { class: "py-1 pl-2",
+                                    CurrencySelect {
+                                        code: f.currency.clone(),
+                                        base: base.clone(),
+                                        codes: codes.clone(),
+                                        on_change: move |c| {
+                                            { let mut st = fin_state.write(); if let Some(x) = st.expenses.iter_mut().find(|x| x.id == f.id) { x.currency = c; } }
+                                            mark_dirty.call(());
+                                        }
+                                    }
+                                }
                                 td { class: "py-1 pl-2",
                                     select {
                                         class: "border px-1 py-1 bg-transparent",
@@ -859,7 +881,22 @@ fn FinCalcDetailed() -> Element {
                                         }
                                     }
                                 }
-                                td { class: "py-1 pr-2 text-right opacity-70", "{format_amount(f.per_month_cents())}" }
+                                td { class: "py-1 pr-2 text-right opacity-70", "{format_amount(f.per_month_cents(), fin_state.read().currencies.resolve(f.currency.as_deref()))}" }
+                                td { class: "py-1 pl-2",
+                                    select {
+                                        class: "border px-1 py-1 bg-transparent",
+                                        value: "{f.envelope.map(|id| id.to_string()).unwrap_or_default()}",
+                                        onchange: move |e| {
+                                            let env = Uuid::parse_str(&e.value()).ok();
+                                            { let mut st = fin_state.write(); if let Some(x) = st.expenses.iter_mut().find(|x| x.id == f.id) { x.envelope = env; } }
+                                            mark_dirty.call(());
+                                        },
+                                        option { value: "", "—" }
+                                        for (id, name) in envelope_names.iter() {
+                                            option { key: "{id}", value: "{id}", "{name}" }
+                                        }
+                                    }
+                                }
                                 td { class: "py-1 text-center",
                                     button {
                                         class: "px-2 text-red-400",
@@ -873,6 +910,118 @@ fn FinCalcDetailed() -> Element {
                 }
             }
 
+            // ---- Envelopes ------------------------------------------------
+            EnvelopesSection { fin_state, on_change: move |_| mark_dirty.call(()) }
+
+            // ---- Ledger ---------------------------------------------------
+            LedgerSection { fin_state, on_change: move |_| mark_dirty.call(()) }
+
+            // ---- Currencies -----------------------------------------------
+            section { class: "border rounded p-3 space-y-2",
+                div { class: "flex items-center justify-between",
+                    h3 { class: "text-lg font-semibold", "Currencies" }
+                    div { class: "flex items-center gap-2 text-xs",
+                        span { class: "opacity-60", "Base" }
+                        input {
+                            class: "border px-2 py-1 w-16 bg-transparent uppercase",
+                            value: "{base}",
+                            onchange: move |e| {
+                                let res = fin_state.write().set_base_currency(&e.value());
+                                match res {
+                                    Ok(()) => mark_dirty.call(()),
+                                    Err(msg) => status.set(Some(msg)),
+                                }
+                            },
+                        }
+                    }
+                }
+                table { class: "w-full text-sm border-collapse",
+                    thead { tr {
+                        th { class: "text-left py-1", "Currency" }
+                        th { class: "text-right py-1", "1 unit = ? {base}" }
+                        th { class: "w-8" }
+                    } }
+                    tbody {
+                        for (code, rate) in rate_rows {
+                            tr { key: "{code}",
+                                td { class: "py-1 pr-2", "{code}" }
+                                td { class: "py-1 pr-2 text-right",
+                                    input {
+                                        r#type: "number",
+                                        step: "0.0001",
+                                        min: "0",
+                                        class: "border px-2 py-1 w-28 bg-transparent text-right",
+                                        value: "{rate}",
+                                        onchange: {
+                                            let code = code.clone();
+                                            move |e: FormEvent| {
+                                                if let Ok(v) = e.value().trim().parse::<f64>() {
+                                                    fin_state.write().currencies.rates.insert(code.clone(), v.max(0.0));
+                                                    mark_dirty.call(());
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                td { class: "py-1 text-center",
+                                    button {
+                                        class: "px-2 text-red-400",
+                                        onclick: {
+                                            let code = code.clone();
+                                            move |_| { fin_state.write().currencies.rates.remove(&code); mark_dirty.call(()); }
+                                        },
+                                        "✕"
+                                    }
+                                }
+                            }
+                        }
+                        tr {
+                            td { class: "py-1 pr-2",
+                                input {
+                                    class: "border px-2 py-1 w-20 bg-transparent uppercase",
+                                    placeholder: "EUR",
+                                    value: "{new_code}",
+                                    oninput: move |e| new_code.set(e.value()),
+                                }
+                            }
+                            td { class: "py-1 pr-2 text-right",
+                                input {
+                                    class: "border px-2 py-1 w-28 bg-transparent text-right",
+                                    placeholder: "1.08",
+                                    value: "{new_rate}",
+                                    oninput: move |e| new_rate.set(e.value()),
+                                }
+                            }
+                            td { class: "py-1 text-center",
+                                button {
+                                    class: "px-2",
+                                    onclick: move |_| {
+                                        let code = normalize_code(&new_code.peek());
+                                        let Ok(rate) = new_rate.peek().trim().parse::<f64>() else { return; };
+                                        if code.is_empty() || rate <= 0.0 || code == fin_state.peek().currencies.base {
+                                            return;
+                                        }
+                                        fin_state.write().currencies.rates.insert(code, rate);
+                                        new_code.set(String::new());
+                                        new_rate.set(String::new());
+                                        mark_dirty.call(());
+                                    },
+                                    "+"
+                                }
+                            }
+                        }
+                    }
+                }
+                if !missing_list.is_empty() {
+                    p { class: "text-xs text-red-400",
+                        "No rate for {missing_list} — those amounts are left out of {base} totals until you add one."
+                    }
+                }
+                div { class: "text-xs opacity-60",
+                    "Totals, charts and the payoff plan are in {base}. Changing the base re-expresses the rates and history against it."
+                }
+            }
+
             // ---- File controls --------------------------------------------
             div { class: "flex items-center gap-2 text-xs pt-2 border-t border-neutral-700",
                 input {
@@ -885,7 +1034,7 @@ fn FinCalcDetailed() -> Element {
                     onclick: move |_| {
                         let path = fin_path.peek().clone();
                         let mut status = status;
-                        match load_json::<FinancesFile>(&path) {
+                        match FinancesFile::load(&path) {
                             Ok(f) => { fin_state.set(f); status.set(Some(format!("Loaded {}", short_path(&path)))); }
                             Err(e) => status.set(Some(err_to_string(e))),
                         }
@@ -0,0 +1,118 @@
+//! Debt payoff: snowball and avalanche compared side by side, and one
+//! liability's minimum-payment schedule on request.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::finCalc::debt;
+use crate::models::finCalc::finances::FinancesFile;
+
+use super::component::{format_amount, MoneyInput};
+
+fn fmt_payoff(d: Option<time::Date>) -> String {
+    d.map_or_else(|| "never".to_string(), |d| d.to_string())
+}
+
+#[component]
+pub(super) fn PayoffSection(fin_state: Signal<FinancesFile>) -> Element {
+    let base = fin_state.read().currencies.base.clone();
+    let mut extra_payment = use_signal(|| 0_i64);
+    let mut schedule_for = use_signal(|| None::<Uuid>);
+    let plan_start = time::OffsetDateTime::now_utc().date();
+    let payoff_plans = {
+        let st = fin_state.read();
+        if st.liabilities.is_empty() {
+            Vec::new()
+        } else {
+            debt::compare_strategies(&st.liabilities_in_base(), plan_start, *extra_payment.read())
+        }
+    };
+    let selected = *schedule_for.read();
+    let schedule = selected.and_then(|id| {
+        let st = fin_state.read();
+        st.liabilities.iter().find(|l| l.id == id).map(|l| {
+            let code = st.currencies.resolve(l.currency.as_deref()).to_string();
+            (debt::amortize(l, plan_start, 0), code)
+        })
+    });
+
+    rsx! {
+        if !payoff_plans.is_empty() {
+            section { class: "border rounded p-3 space-y-2",
+                div { class: "flex items-center justify-between",
+                    h3 { class: "text-lg font-semibold", "Payoff plan" }
+                    div { class: "flex items-center gap-2 text-xs",
+                        span { class: "opacity-60", "Extra /mo" }
+                        MoneyInput { cents: *extra_payment.read(), on_commit: move |c| extra_payment.set(c.max(0)) }
+                    }
+                }
+                table { class: "w-full text-sm border-collapse",
+                    thead { tr {
+                        th { class: "text-left py-1", "Liability" }
+                        for p in payoff_plans.iter() {
+                            th { class: "text-right py-1", "{p.strategy.label()} payoff" }
+                            th { class: "text-right py-1", "{p.strategy.label()} interest" }
+                        }
+                    } }
+                    tbody {
+                        for (i, l) in fin_state.read().liabilities.iter().cloned().enumerate() {
+                            tr { key: "{l.id}",
+                                td { class: "py-1 pr-2",
+                                    button {
+                                        class: "underline decoration-dotted",
+                                        onclick: move |_| {
+                                            let cur = *schedule_for.peek();
+                                            schedule_for.set(if cur == Some(l.id) { None } else { Some(l.id) });
+                                        },
+                                        "{l.name}"
+                                    }
+                                }
+                                for p in payoff_plans.iter() {
+                                    td { class: "py-1 pr-2 text-right", "{fmt_payoff(p.debts[i].payoff)}" }
+                                    td { class: "py-1 pr-2 text-right", "{format_amount(p.debts[i].total_interest, &base)}" }
+                                }
+                            }
+                        }
+                        tr { class: "font-semibold border-t border-neutral-700",
+                            td { class: "py-1 pr-2", "All debts" }
+                            for p in payoff_plans.iter() {
+                                td { class: "py-1 pr-2 text-right", "{fmt_payoff(p.payoff)}" }
+                                td { class: "py-1 pr-2 text-right", "{format_amount(p.total_interest, &base)}" }
+                            }
+                        }
+                    }
+                }
+                if let Some((sched, sched_code)) = schedule {
+                    div { class: "max-h-64 overflow-auto",
+                        table { class: "w-full text-xs border-collapse",
+                            thead { tr {
+                                th { class: "text-left py-1", "Date" }
+                                th { class: "text-right py-1", "Payment" }
+                                th { class: "text-right py-1", "Interest" }
+                                th { class: "text-right py-1", "Principal" }
+                                th { class: "text-right py-1", "Balance" }
+                            } }
+                            tbody {
+                                for r in sched.rows.iter() {
+                                    tr { key: "{r.date}",
+                                        td { class: "py-0.5", "{r.date}" }
+                                        td { class: "py-0.5 text-right", "{format_amount(r.payment, &sched_code)}" }
+                                        td { class: "py-0.5 text-right", "{format_amount(r.interest, &sched_code)}" }
+                                        td { class: "py-0.5 text-right", "{format_amount(r.principal, &sched_code)}" }
+                                        td { class: "py-0.5 text-right", "{format_amount(r.balance, &sched_code)}" }
+                                    }
+                                }
+                            }
+                        }
+                        if sched.payoff.is_none() {
+                            p { class: "text-xs text-red-400 pt-1", "The minimum payment doesn't cover the interest — this balance never clears." }
+                        }
+                    }
+                }
+                div { class: "text-xs opacity-60",
+                    "Minimums on everything, plus the extra (and every freed-up minimum) on one target: smallest balance first for snowball, highest APR first for avalanche. Click a name for its own minimum-payment schedule."
+                }
+            }
+        }
+    }
+}
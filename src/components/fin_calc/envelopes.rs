@@ -0,0 +1,226 @@
+//! Envelopes panel: divvying up the monthly net, with rollover and
+//! overspend alerts.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::finCalc::envelopes::{self, Allocation, Envelope, ManualSpend};
+use crate::models::finCalc::finances::FinancesFile;
+
+use super::component::{format_amount, parse_dollars, MoneyInput};
+
+#[component]
+pub(super) fn EnvelopesSection(mut fin_state: Signal<FinancesFile>, on_change: EventHandler<()>) -> Element {
+    let mut spend_env = use_signal(|| None::<Uuid>);
+    let mut spend_amount = use_signal(String::new);
+    let mut spend_note = use_signal(String::new);
+
+    let today = time::OffsetDateTime::now_utc().date();
+    let base = fin_state.read().currencies.base.clone();
+    let unallocated = fin_state.read().unallocated();
+    let rows: Vec<(Envelope, envelopes::EnvelopeMonth)> = {
+        let st = fin_state.read();
+        st.envelopes
+            .iter()
+            .map(|e| (e.clone(), envelopes::envelope_month(&st, e, today)))
+            .collect()
+    };
+    let over: Vec<(String, i64)> = rows
+        .iter()
+        .filter(|(_, m)| m.is_over())
+        .map(|(e, m)| (e.name.clone(), -m.left()))
+        .collect();
+    let this_month: Vec<(Uuid, String, ManualSpend)> = rows
+        .iter()
+        .flat_map(|(e, m)| {
+            e.spending
+                .iter()
+                .filter(|s| s.date >= m.month && s.date <= today)
+                .map(|s| (e.id, e.name.clone(), s.clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    rsx! {
+        section { class: "border rounded p-3 space-y-2",
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-lg font-semibold", "Envelopes" }
+                button {
+                    class: "px-3 py-1 border rounded text-xs",
+                    onclick: move |_| { fin_state.write().envelopes.push(Envelope::new("New envelope", today)); on_change.call(()); },
+                    "+ Add envelope"
+                }
+            }
+            if !over.is_empty() {
+                div { class: "border border-red-500 rounded p-2 text-sm text-red-400",
+                    div { class: "font-semibold", "Over the limit this month" }
+                    for (name, by) in over.iter() {
+                        div { key: "{name}", "{name}: over by {format_amount(*by, &base)}" }
+                    }
+                }
+            }
+            table { class: "w-full text-sm border-collapse",
+                thead { tr {
+                    th { class: "text-left py-1", "Name" }
+                    th { class: "text-left py-1 pl-2", "Allocation" }
+                    th { class: "text-right py-1", "Amount" }
+                    th { class: "text-right py-1", "Rolled in" }
+                    th { class: "text-right py-1", "Spent" }
+                    th { class: "text-right py-1", "Left" }
+                    th { class: "w-8" }
+                } }
+                tbody {
+                    for (e, m) in rows.iter().cloned() {
+                        tr { key: "{e.id}",
+                            td { class: "py-1 pr-2",
+                                input {
+                                    class: "border px-2 py-1 w-full bg-transparent",
+                                    value: "{e.name}",
+                                    oninput: move |ev| {
+                                        let v = ev.value();
+                                        { let mut st = fin_state.write(); if let Some(x) = st.envelopes.iter_mut().find(|x| x.id == e.id) { x.name = v; } }
+                                        on_change.call(());
+                                    }
+                                }
+                            }
+                            td { class: "py-1 pl-2",
+                                select {
+                                    class: "border px-1 py-1 bg-transparent",
+                                    value: "{e.allocation.label()}",
+                                    onchange: move |ev| {
+                                        let alloc = if ev.value() == Allocation::LABELS[1] { Allocation::Percent(0.0) } else { Allocation::Fixed(0) };
+                                        fin_state.write().set_envelope_allocation(e.id, alloc, today);
+                                        on_change.call(());
+                                    },
+                                    for l in Allocation::LABELS { option { value: "{l}", "{l}" } }
+                                }
+                            }
+                            td { class: "py-1 pr-2 text-right",
+                                {match e.allocation {
+                                    Allocation::Fixed(c) => rsx! {
+                                        MoneyInput {
+                                            cents: c,
+                                            on_commit: move |amt: i64| {
+                                                fin_state.write().set_envelope_allocation(e.id, Allocation::Fixed(amt.max(0)), today);
+                                                on_change.call(());
+                                            }
+                                        }
+                                    },
+                                    Allocation::Percent(p) => rsx! {
+                                        input {
+                                            r#type: "number",
+                                            step: "0.5",
+                                            min: "0",
+                                            class: "border px-2 py-1 w-20 bg-transparent text-right",
+                                            value: "{p}",
+                                            onchange: move |ev| {
+                                                if let Ok(v) = ev.value().trim().parse::<f64>() {
+                                                    fin_state.write().set_envelope_allocation(e.id, Allocation::Percent(v.max(0.0)), today);
+                                                    on_change.call(());
+                                                }
+                                            }
+                                        }
+                                        span { class: "pl-1 opacity-60", "% = {format_amount(m.allocated, &base)}" }
+                                    },
+                                }}
+                            }
+                            td { class: "py-1 pr-2 text-right opacity-70", "{format_amount(m.rolled_in, &base)}" }
+                            td { class: "py-1 pr-2 text-right", "{format_amount(m.spent, &base)}" }
+                            td {
+                                class: if m.is_over() { "py-1 pr-2 text-right text-red-400" } else { "py-1 pr-2 text-right text-green-400" },
+                                "{format_amount(m.left(), &base)}"
+                            }
+                            td { class: "py-1 text-center",
+                                button {
+                                    class: "px-2 text-red-400",
+                                    onclick: move |_| {
+                                        {
+                                            let mut st = fin_state.write();
+                                            st.envelopes.retain(|x| x.id != e.id);
+                                            for f in st.expenses.iter_mut().filter(|f| f.envelope == Some(e.id)) {
+                                                f.envelope = None;
+                                            }
+                                        }
+                                        on_change.call(());
+                                    },
+                                    "✕"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: if unallocated < 0 { "text-sm text-red-400" } else { "text-sm opacity-80" },
+                "Unallocated monthly net: {format_amount(unallocated, &base)}"
+            }
+
+            // Manual spending
+            if !rows.is_empty() {
+                div { class: "flex items-center gap-2 text-xs pt-2",
+                    span { class: "opacity-60", "Spend from" }
+                    select {
+                        class: "border px-1 py-1 bg-transparent",
+                        value: "{spend_env.read().map(|id| id.to_string()).unwrap_or_default()}",
+                        onchange: move |ev| spend_env.set(Uuid::parse_str(&ev.value()).ok()),
+                        option { value: "", "—" }
+                        for (e, _) in rows.iter() {
+                            option { key: "{e.id}", value: "{e.id}", "{e.name}" }
+                        }
+                    }
+                    input {
+                        class: "border px-2 py-1 w-24 bg-transparent text-right",
+                        placeholder: "0.00",
+                        value: "{spend_amount}",
+                        oninput: move |ev| spend_amount.set(ev.value()),
+                    }
+                    input {
+                        class: "border px-2 py-1 flex-1 bg-transparent",
+                        placeholder: "Note",
+                        value: "{spend_note}",
+                        oninput: move |ev| spend_note.set(ev.value()),
+                    }
+                    button {
+                        class: "px-3 py-1 border rounded",
+                        onclick: move |_| {
+                            let Some(env_id) = *spend_env.peek() else { return; };
+                            let Some(amount) = parse_dollars(&spend_amount.peek()) else { return; };
+                            let entry = ManualSpend { id: Uuid::new_v4(), date: today, amount, note: spend_note.peek().trim().to_string() };
+                            { let mut st = fin_state.write(); if let Some(x) = st.envelopes.iter_mut().find(|x| x.id == env_id) { x.spending.push(entry); } }
+                            spend_amount.set(String::new());
+                            spend_note.set(String::new());
+                            on_change.call(());
+                        },
+                        "Add spending"
+                    }
+                }
+            }
+            if !this_month.is_empty() {
+                table { class: "w-full text-xs border-collapse",
+                    tbody {
+                        for (env_id, env_name, s) in this_month {
+                            tr { key: "{s.id}",
+                                td { class: "py-0.5 pr-2", "{s.date}" }
+                                td { class: "py-0.5 pr-2", "{env_name}" }
+                                td { class: "py-0.5 pr-2 opacity-70", "{s.note}" }
+                                td { class: "py-0.5 pr-2 text-right", "{format_amount(s.amount, &base)}" }
+                                td { class: "py-0.5 text-center w-8",
+                                    button {
+                                        class: "px-2 text-red-400",
+                                        onclick: move |_| {
+                                            { let mut st = fin_state.write(); if let Some(x) = st.envelopes.iter_mut().find(|x| x.id == env_id) { x.spending.retain(|y| y.id != s.id); } }
+                                            on_change.call(());
+                                        },
+                                        "✕"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "text-xs opacity-60",
+                "Spent counts assigned expenses on the days they land, up to today, plus spending added here. Whatever's left at month end rolls into the next month. Changing an allocation applies from this month on."
+            }
+        }
+    }
+}
@@ -1,2 +0,0 @@
-mod component;
-pub use component::*;
\ No newline at end of file
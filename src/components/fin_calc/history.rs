@@ -0,0 +1,200 @@
+//! Net-worth history panel: editable snapshots, backfill and retention.
+
+use dioxus::prelude::*;
+
+use crate::models::finCalc::finances::{FinancesFile, Snapshot};
+
+use super::component::{format_amount, parse_iso_date, MoneyInput};
+
+/// Change the point on `date`. Hand-edited points count as recorded from then
+/// on.
+fn edit_snapshot(
+    mut fin_state: Signal<FinancesFile>,
+    date: time::Date,
+    f: impl FnOnce(&mut Snapshot),
+) {
+    let mut st = fin_state.write();
+    if let Some(s) = st.history.iter_mut().find(|s| s.date == date) {
+        f(s);
+        s.interpolated = false;
+    }
+}
+
+#[component]
+pub(super) fn HistorySection(mut fin_state: Signal<FinancesFile>, on_change: EventHandler<()>) -> Element {
+    let mut open = use_signal(|| false);
+    let mut new_date = use_signal(String::new);
+    let mut history_msg = use_signal(|| None::<String>);
+
+    let today = time::OffsetDateTime::now_utc().date();
+    let base = fin_state.read().currencies.base.clone();
+    let retention = fin_state.read().history_retention;
+    // Newest first; that's the end people edit.
+    let rows: Vec<Snapshot> = fin_state.read().history.iter().rev().copied().collect();
+    let interpolated = rows.iter().filter(|s| s.interpolated).count();
+    let summary = format!("{} points, {} interpolated", rows.len(), interpolated);
+
+    rsx! {
+        section { class: "border rounded p-3 space-y-2",
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-lg font-semibold", "History" }
+                div { class: "flex items-center gap-2 text-xs",
+                    span { class: "opacity-60", "{summary}" }
+                    button {
+                        class: "px-2 py-0.5 border rounded",
+                        onclick: move |_| { let o = *open.peek(); open.set(!o); },
+                        if *open.read() { "Hide" } else { "Edit" }
+                    }
+                }
+            }
+            if *open.read() {
+                div { class: "flex flex-wrap items-center gap-2 text-xs",
+                    input {
+                        r#type: "date",
+                        class: "border px-1 py-1 bg-transparent",
+                        value: "{new_date}",
+                        oninput: move |e| new_date.set(e.value()),
+                    }
+                    button {
+                        class: "px-3 py-1 border rounded",
+                        onclick: move |_| {
+                            let Some(date) = parse_iso_date(&new_date.peek()) else { return; };
+                            {
+                                let mut st = fin_state.write();
+                                // Start from today's figures; adjust in the table.
+                                let snap = Snapshot {
+                                    date,
+                                    net_worth: st.net_worth(),
+                                    monthly_income: st.monthly_income(),
+                                    monthly_expenses: st.monthly_expenses(),
+                                    monthly_withheld: st.monthly_withheld(),
+                                    interpolated: false,
+                                };
+                                st.upsert_snapshot(snap);
+                            }
+                            new_date.set(String::new());
+                            on_change.call(());
+                        },
+                        "+ Add point"
+                    }
+                    button {
+                        class: "px-3 py-1 border rounded",
+                        onclick: move |_| {
+                            let n = fin_state.write().backfill_history();
+                            history_msg.set(Some(format!("Filled {n} missing days.")));
+                            if n > 0 { on_change.call(()); }
+                        },
+                        "Backfill gaps"
+                    }
+                    button {
+                        class: "px-3 py-1 border rounded",
+                        onclick: move |_| {
+                            let n = fin_state.write().compact_history(today);
+                            history_msg.set(Some(format!("Dropped {n} points.")));
+                            if n > 0 { on_change.call(()); }
+                        },
+                        "Compact"
+                    }
+                    label { class: "flex items-center gap-1",
+                        span { class: "opacity-60", "Daily for" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            class: "border px-2 py-0.5 w-16 bg-transparent text-right",
+                            value: "{retention.daily_days}",
+                            onchange: move |e| {
+                                if let Ok(v) = e.value().trim().parse::<u32>() {
+                                    fin_state.write().history_retention.daily_days = v;
+                                    on_change.call(());
+                                }
+                            }
+                        }
+                        span { class: "opacity-60", "days, weekly until" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            class: "border px-2 py-0.5 w-16 bg-transparent text-right",
+                            value: "{retention.weekly_days}",
+                            onchange: move |e| {
+                                if let Ok(v) = e.value().trim().parse::<u32>() {
+                                    fin_state.write().history_retention.weekly_days = v;
+                                    on_change.call(());
+                                }
+                            }
+                        }
+                        span { class: "opacity-60", "days, then monthly" }
+                    }
+                }
+                if let Some(msg) = history_msg.read().clone() {
+                    div { class: "text-xs opacity-70", "{msg}" }
+                }
+                if rows.is_empty() {
+                    div { class: "text-xs opacity-60", "No points yet." }
+                } else {
+                    div { class: "max-h-96 overflow-y-auto",
+                        table { class: "w-full text-sm border-collapse",
+                            thead { tr {
+                                th { class: "text-left py-1", "Date" }
+                                th { class: "text-right py-1", "Net worth" }
+                                th { class: "text-right py-1", "Income /mo" }
+                                th { class: "text-right py-1", "Expenses /mo" }
+                                th { class: "text-right py-1", "Withheld /mo" }
+                                th { class: "text-right py-1", "Take-home" }
+                                th { class: "w-8" }
+                            } }
+                            tbody {
+                                for s in rows {
+                                    tr {
+                                        key: "{s.date}",
+                                        class: if s.interpolated { "italic opacity-60" } else { "" },
+                                        title: if s.interpolated { "Interpolated" } else { "" },
+                                        td { class: "py-1 pr-2",
+                                            input {
+                                                r#type: "date",
+                                                class: "border px-1 py-1 bg-transparent",
+                                                value: "{s.date}",
+                                                onchange: move |e| {
+                                                    if let Some(d) = parse_iso_date(&e.value()) {
+                                                        let moved = Snapshot { date: d, interpolated: false, ..s };
+                                                        fin_state.write().update_snapshot(s.date, moved);
+                                                        on_change.call(());
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        td { class: "py-1 pr-2 text-right",
+                                            MoneyInput { cents: s.net_worth, on_commit: move |v: i64| { edit_snapshot(fin_state, s.date, |x| x.net_worth = v); on_change.call(()); } }
+                                        }
+                                        td { class: "py-1 pr-2 text-right",
+                                            MoneyInput { cents: s.monthly_income, on_commit: move |v: i64| { edit_snapshot(fin_state, s.date, |x| x.monthly_income = v); on_change.call(()); } }
+                                        }
+                                        td { class: "py-1 pr-2 text-right",
+                                            MoneyInput { cents: s.monthly_expenses, on_commit: move |v: i64| { edit_snapshot(fin_state, s.date, |x| x.monthly_expenses = v); on_change.call(()); } }
+                                        }
+                                        td { class: "py-1 pr-2 text-right",
+                                            MoneyInput { cents: s.monthly_withheld, on_commit: move |v: i64| { edit_snapshot(fin_state, s.date, |x| x.monthly_withheld = v); on_change.call(()); } }
+                                        }
+                                        td { class: "py-1 pr-2 text-right opacity-70", "{format_amount(s.take_home(), &base)}" }
+                                        td { class: "py-1 text-center",
+                                            button {
+                                                class: "px-2 text-red-400",
+                                                onclick: move |_| {
+                                                    fin_state.write().remove_snapshot(s.date);
+                                                    on_change.call(());
+                                                },
+                                                "✕"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                div { class: "text-xs opacity-60",
+                    "Figures are in {base}. Backfill draws straight lines between known points (shown in italics); editing a point marks it as recorded. Recording a point also compacts older history."
+                }
+            }
+        }
+    }
+}
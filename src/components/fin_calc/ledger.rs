@@ -0,0 +1,367 @@
+//! Ledger panel: bank statement imports, matching rules and budgeted vs
+//! actual by month.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::finCalc::finances::FinancesFile;
+use crate::models::finCalc::ledger::{self, CsvMapping, DateFormat, MatchRule, Transaction};
+use crate::models::finCalc::projection::add_months;
+
+use super::component::{cents_to_input, format_amount, parse_dollars, short_path};
+
+/// Column field for the CSV mapping. Shown 1-based like a spreadsheet; blank
+/// means "not in this export".
+fn column_input(col: Option<usize>) -> String {
+    col.map(|c| (c + 1).to_string()).unwrap_or_default()
+}
+
+fn parse_column(s: &str) -> Option<usize> {
+    s.trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|c| c.checked_sub(1))
+}
+
+#[component]
+pub(super) fn LedgerSection(mut fin_state: Signal<FinancesFile>, on_change: EventHandler<()>) -> Element {
+    let mut import_path = use_signal(String::new);
+    let mut import_msg = use_signal(|| None::<String>);
+    let mut show_mapping = use_signal(|| false);
+    let mut month = use_signal(|| {
+        let today = time::OffsetDateTime::now_utc().date();
+        today.replace_day(1).unwrap_or(today)
+    });
+
+    let m = *month.read();
+    let lines = ledger::budget_vs_actual(&fin_state.read(), m.year(), m.month());
+    let (sum_budget, sum_actual) = lines
+        .iter()
+        .fold((0, 0), |(b, a), l| (b + l.budgeted, a + l.actual));
+    let month_txs: Vec<Transaction> = fin_state
+        .read()
+        .transactions
+        .iter()
+        .filter(|t| t.date.year() == m.year() && t.date.month() == m.month())
+        .cloned()
+        .collect();
+    let base = fin_state.read().currencies.base.clone();
+    let expenses: Vec<(Uuid, String)> = fin_state
+        .read()
+        .expenses
+        .iter()
+        .map(|f| (f.id, f.name.clone()))
+        .collect();
+    let mapping = fin_state.read().csv_mapping.clone();
+    let rules = fin_state.read().match_rules.clone();
+
+    let mut set_mapping = move |f: &dyn Fn(&mut CsvMapping)| {
+        f(&mut fin_state.write().csv_mapping);
+        on_change.call(());
+    };
+
+    let on_import = move |_| {
+        let path = import_path.peek().trim().to_string();
+        if path.is_empty() {
+            return;
+        }
+        let text = match std::fs::read_to_string(&path) {
+            Ok(t) => t,
+            Err(e) => {
+                import_msg.set(Some(format!("Couldn't read {}: {e}", short_path(&path))));
+                return;
+            }
+        };
+        let mapping = fin_state.peek().csv_mapping.clone();
+        match ledger::parse_statement(&text, &mapping) {
+            Ok(txs) => {
+                let r = fin_state.write().import_transactions(txs);
+                import_msg.set(Some(format!(
+                    "Imported {} · {} duplicate(s) skipped · {} matched to expenses",
+                    r.added, r.duplicates, r.matched
+                )));
+                on_change.call(());
+            }
+            Err(e) => import_msg.set(Some(e.to_string())),
+        }
+    };
+
+    rsx! {
+        section { class: "border rounded p-3 space-y-2",
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-lg font-semibold", "Ledger" }
+                div { class: "flex items-center gap-2 text-xs",
+                    button { class: "px-2", onclick: move |_| month.set(add_months(m, -1)), "◀" }
+                    span { class: "w-20 text-center", "{m.month()} {m.year()}" }
+                    button { class: "px-2", onclick: move |_| month.set(add_months(m, 1)), "▶" }
+                }
+            }
+
+            // Import
+            div { class: "flex items-center gap-2 text-xs",
+                input {
+                    class: "border px-2 py-1 flex-1 bg-transparent",
+                    placeholder: "Path to a bank export (.csv, .ofx, .qfx)",
+                    value: "{import_path}",
+                    oninput: move |e| import_path.set(e.value()),
+                }
+                button { class: "px-3 py-1 border rounded", onclick: on_import, "Import" }
+                button {
+                    class: "px-3 py-1 border rounded",
+                    onclick: move |_| { let v = *show_mapping.peek(); show_mapping.set(!v); },
+                    "CSV columns"
+                }
+            }
+            if let Some(msg) = import_msg.read().as_ref() {
+                p { class: "text-xs text-blue-400", "{msg}" }
+            }
+            if *show_mapping.read() {
+                div { class: "grid grid-cols-4 gap-2 text-xs items-center",
+                    label { class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: mapping.has_header,
+                            onchange: move |e| { let v = e.checked(); set_mapping(&|m| m.has_header = v); },
+                        }
+                        "Header row"
+                    }
+                    label { class: "flex items-center gap-1",
+                        input {
+                            r#type: "checkbox",
+                            checked: mapping.spending_positive,
+                            onchange: move |e| { let v = e.checked(); set_mapping(&|m| m.spending_positive = v); },
+                        }
+                        "Spending is positive"
+                    }
+                    label { class: "flex items-center gap-1 col-span-2",
+                        span { class: "opacity-60", "Date format" }
+                        select {
+                            class: "border px-1 py-1 bg-transparent",
+                            value: "{mapping.date_format.label()}",
+                            onchange: move |e| { let f = DateFormat::from_label(&e.value()); set_mapping(&|m| m.date_format = f); },
+                            for f in DateFormat::ALL { option { value: "{f.label()}", "{f.label()}" } }
+                        }
+                    }
+                    for (label, value, field) in [
+                        ("Date", Some(mapping.date_col), 0),
+                        ("Description", Some(mapping.description_col), 1),
+                        ("Amount", mapping.amount_col, 2),
+                        ("Debit", mapping.debit_col, 3),
+                        ("Credit", mapping.credit_col, 4),
+                    ] {
+                        label { class: "flex items-center gap-1",
+                            span { class: "opacity-60 w-20", "{label} col" }
+                            input {
+                                class: "border px-2 py-1 w-12 bg-transparent text-right",
+                                value: "{column_input(value)}",
+                                onchange: move |e| {
+                                    let c = parse_column(&e.value());
+                                    set_mapping(&|m| match field {
+                                        0 => m.date_col = c.unwrap_or(m.date_col),
+                                        1 => m.description_col = c.unwrap_or(m.description_col),
+                                        2 => m.amount_col = c,
+                                        3 => m.debit_col = c,
+                                        _ => m.credit_col = c,
+                                    });
+                                },
+                            }
+                        }
+                    }
+                    p { class: "col-span-4 opacity-60",
+                        "Columns count from 1. Leave Amount blank to use separate Debit/Credit columns. OFX/QFX files don't need a mapping."
+                    }
+                }
+            }
+
+            // Budgeted vs actual
+            table { class: "w-full text-sm border-collapse",
+                thead { tr {
+                    th { class: "text-left py-1", "Expense" }
+                    th { class: "text-right py-1", "Budgeted" }
+                    th { class: "text-right py-1", "Actual" }
+                    th { class: "text-right py-1", "Left" }
+                } }
+                tbody {
+                    for l in lines.iter() {
+                        tr { key: "{l.name}{l.flow_id:?}",
+                            td { class: if l.flow_id.is_none() { "py-1 pr-2 italic opacity-70" } else { "py-1 pr-2" }, "{l.name}" }
+                            td { class: "py-1 pr-2 text-right", "{format_amount(l.budgeted, &base)}" }
+                            td { class: "py-1 pr-2 text-right", "{format_amount(l.actual, &base)}" }
+                            td {
+                                class: if l.remaining() < 0 { "py-1 pr-2 text-right text-red-400" } else { "py-1 pr-2 text-right" },
+                                "{format_amount(l.remaining(), &base)}"
+                            }
+                        }
+                    }
+                    tr { class: "font-semibold border-t border-neutral-700",
+                        td { class: "py-1 pr-2", "Total" }
+                        td { class: "py-1 pr-2 text-right", "{format_amount(sum_budget, &base)}" }
+                        td { class: "py-1 pr-2 text-right", "{format_amount(sum_actual, &base)}" }
+                        td {
+                            class: if sum_budget < sum_actual { "py-1 pr-2 text-right text-red-400" } else { "py-1 pr-2 text-right" },
+                            "{format_amount(sum_budget - sum_actual, &base)}"
+                        }
+                    }
+                }
+            }
+
+            // Transactions this month
+            if !month_txs.is_empty() {
+                div { class: "max-h-64 overflow-auto",
+                    table { class: "w-full text-xs border-collapse",
+                        thead { tr {
+                            th { class: "text-left py-1", "Date" }
+                            th { class: "text-left py-1", "Description" }
+                            th { class: "text-right py-1", "Amount" }
+                            th { class: "text-left py-1 pl-2", "Counts toward" }
+                            th { class: "w-8" }
+                        } }
+                        tbody {
+                            for t in month_txs {
+                                tr { key: "{t.id}",
+                                    td { class: "py-0.5 pr-2", "{t.date}" }
+                                    td { class: "py-0.5 pr-2", "{t.description}" }
+                                    td { class: "py-0.5 pr-2 text-right",
+                                        "{format_amount(t.amount, fin_state.read().currencies.resolve(t.currency.as_deref()))}"
+                                    }
+                                    td { class: "py-0.5 pl-2",
+                                        select {
+                                            class: "border px-1 bg-transparent",
+                                            value: "{t.flow_id.map(|id| id.to_string()).unwrap_or_default()}",
+                                            onchange: move |e| {
+                                                let id = Uuid::parse_str(&e.value()).ok();
+                                                { let mut st = fin_state.write(); if let Some(x) = st.transactions.iter_mut().find(|x| x.id == t.id) { x.flow_id = id; } }
+                                                on_change.call(());
+                                            },
+                                            option { value: "", "—" }
+                                            for (id, name) in expenses.iter() {
+                                                option { key: "{id}", value: "{id}", "{name}" }
+                                            }
+                                        }
+                                    }
+                                    td { class: "py-0.5 text-center",
+                                        button {
+                                            class: "px-2 text-red-400",
+                                            onclick: move |_| { fin_state.write().transactions.retain(|x| x.id != t.id); on_change.call(()); },
+                                            "✕"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Matching rules
+            div { class: "flex items-center justify-between pt-2",
+                h4 { class: "font-semibold text-sm", "Matching rules" }
+                div { class: "flex gap-2 text-xs",
+                    button {
+                        class: "px-3 py-1 border rounded",
+                        disabled: expenses.is_empty(),
+                        onclick: move |_| {
+                            let Some(first) = fin_state.peek().expenses.first().map(|f| f.id) else { return; };
+                            fin_state.write().match_rules.push(MatchRule { flow_id: first, contains: Some(String::new()), amount: None, tolerance: 0 });
+                            on_change.call(());
+                        },
+                        "+ Add rule"
+                    }
+                    button {
+                        class: "px-3 py-1 border rounded",
+                        onclick: move |_| {
+                            let n = fin_state.write().rematch_transactions();
+                            import_msg.set(Some(format!("Matched {n} more transaction(s)")));
+                            on_change.call(());
+                        },
+                        "Re-match"
+                    }
+                }
+            }
+            if !rules.is_empty() {
+                table { class: "w-full text-xs border-collapse",
+                    thead { tr {
+                        th { class: "text-left py-1", "Expense" }
+                        th { class: "text-left py-1 pl-2", "Description contains" }
+                        th { class: "text-right py-1", "Amount" }
+                        th { class: "text-right py-1", "± cents" }
+                        th { class: "w-8" }
+                    } }
+                    tbody {
+                        for (i, r) in rules.into_iter().enumerate() {
+                            tr { key: "{i}",
+                                td { class: "py-0.5 pr-2",
+                                    select {
+                                        class: "border px-1 bg-transparent",
+                                        value: "{r.flow_id}",
+                                        onchange: move |e| {
+                                            if let Ok(id) = Uuid::parse_str(&e.value()) {
+                                                if let Some(x) = fin_state.write().match_rules.get_mut(i) { x.flow_id = id; }
+                                                on_change.call(());
+                                            }
+                                        },
+                                        for (id, name) in expenses.iter() {
+                                            option { key: "{id}", value: "{id}", "{name}" }
+                                        }
+                                    }
+                                }
+                                td { class: "py-0.5 pl-2",
+                                    input {
+                                        class: "border px-2 py-0.5 w-full bg-transparent",
+                                        value: "{r.contains.clone().unwrap_or_default()}",
+                                        onchange: move |e| {
+                                            let v = e.value();
+                                            if let Some(x) = fin_state.write().match_rules.get_mut(i) {
+                                                x.contains = (!v.trim().is_empty()).then_some(v);
+                                            }
+                                            on_change.call(());
+                                        }
+                                    }
+                                }
+                                td { class: "py-0.5 pr-2 text-right",
+                                    input {
+                                        class: "border px-2 py-0.5 w-24 bg-transparent text-right",
+                                        value: "{r.amount.map(cents_to_input).unwrap_or_default()}",
+                                        onchange: move |e| {
+                                            let amt = parse_dollars(&e.value()).map(i64::abs);
+                                            if let Some(x) = fin_state.write().match_rules.get_mut(i) { x.amount = amt; }
+                                            on_change.call(());
+                                        }
+                                    }
+                                }
+                                td { class: "py-0.5 pr-2 text-right",
+                                    input {
+                                        r#type: "number",
+                                        min: "0",
+                                        class: "border px-2 py-0.5 w-16 bg-transparent text-right",
+                                        value: "{r.tolerance}",
+                                        onchange: move |e| {
+                                            let tol = e.value().trim().parse::<i64>().unwrap_or(0).max(0);
+                                            if let Some(x) = fin_state.write().match_rules.get_mut(i) { x.tolerance = tol; }
+                                            on_change.call(());
+                                        }
+                                    }
+                                }
+                                td { class: "py-0.5 text-center",
+                                    button {
+                                        class: "px-2 text-red-400",
+                                        onclick: move |_| {
+                                            let mut st = fin_state.write();
+                                            if i < st.match_rules.len() { st.match_rules.remove(i); }
+                                            drop(st);
+                                            on_change.call(());
+                                        },
+                                        "✕"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "text-xs opacity-60",
+                "Spending is matched to an expense by the first rule that fits, otherwise by the expense's name appearing in the description. Re-importing the same file skips rows already in the ledger."
+            }
+        }
+    }
+}
@@ -1,2 +1,11 @@
+mod balances;
+mod charts;
 mod component;
-pub use component::*;
\ No newline at end of file
+mod debt;
+mod envelopes;
+mod history;
+mod ledger;
+mod projection;
+mod scenario;
+mod tax;
+pub use component::*;
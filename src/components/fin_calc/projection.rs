@@ -0,0 +1,220 @@
+//! Where the money is and where it's heading: a breakdown pie and a trend
+//! line over history, the twelve-month projection or the long-term scenario.
+
+use dioxus::prelude::*;
+
+use crate::models::finCalc::finances::{AssetKind, FinancesFile};
+use crate::models::finCalc::projection::{self, add_months, Granularity};
+use crate::models::finCalc::scenario;
+
+use super::charts::{build_pie, LineChart, PieChart};
+use super::scenario::ScenarioControls;
+use super::component::fmt_short_date;
+
+#[derive(Clone, Copy, PartialEq)]
+enum PieMode {
+    All,
+    Expenses,
+    Income,
+    Assets,
+}
+impl PieMode {
+    const ALL: [PieMode; 4] = [
+        PieMode::All,
+        PieMode::Expenses,
+        PieMode::Income,
+        PieMode::Assets,
+    ];
+    fn label(&self) -> &'static str {
+        match self {
+            PieMode::All => "All",
+            PieMode::Expenses => "Expenses",
+            PieMode::Income => "Income",
+            PieMode::Assets => "Assets",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum LineMode {
+    NetWorth,
+    TakeHome,
+    Future,
+    LongTerm,
+}
+impl LineMode {
+    const ALL: [LineMode; 4] = [
+        LineMode::NetWorth,
+        LineMode::TakeHome,
+        LineMode::Future,
+        LineMode::LongTerm,
+    ];
+    fn label(&self) -> &'static str {
+        match self {
+            LineMode::NetWorth => "Net worth",
+            LineMode::TakeHome => "Take-home",
+            LineMode::Future => "Future",
+            LineMode::LongTerm => "Long-term",
+        }
+    }
+}
+
+#[component]
+pub(super) fn ProjectionSection(fin_state: Signal<FinancesFile>, on_change: EventHandler<()>) -> Element {
+    let base = fin_state.read().currencies.base.clone();
+    let pie_mode = use_signal(|| PieMode::All);
+    let line_mode = use_signal(|| LineMode::NetWorth);
+
+    let (pie_slices, pie_total) = {
+        let st = fin_state.read();
+        let items: Vec<(String, i64)> = match *pie_mode.read() {
+            PieMode::All => vec![
+                ("Income /mo".to_string(), st.monthly_income()),
+                ("Taxes /mo".to_string(), st.monthly_withheld()),
+                ("Expenses /mo".to_string(), st.monthly_expenses()),
+                ("Assets".to_string(), st.total_assets()),
+                ("Liabilities".to_string(), st.total_liabilities()),
+            ],
+            PieMode::Expenses => st
+                .expenses
+                .iter()
+                .filter_map(|f| {
+                    let cents = st
+                        .currencies
+                        .to_base(f.per_month_cents(), f.currency.as_deref())?;
+                    Some((f.name.clone(), cents))
+                })
+                .collect(),
+            PieMode::Income => st
+                .income
+                .iter()
+                .filter_map(|f| {
+                    let cents = st
+                        .currencies
+                        .to_base(f.per_month_cents(), f.currency.as_deref())?;
+                    Some((f.name.clone(), cents))
+                })
+                .collect(),
+            PieMode::Assets => AssetKind::ALL
+                .iter()
+                .filter_map(|k| {
+                    let sum: i64 = st
+                        .assets
+                        .iter()
+                        .filter(|a| a.kind == *k)
+                        .filter_map(|a| st.currencies.to_base(a.value, a.currency.as_deref()))
+                        .sum();
+                    (sum > 0).then(|| (k.label().to_string(), sum))
+                })
+                .collect(),
+        };
+        build_pie(items, &base)
+    };
+
+    let line_color = match *line_mode.read() {
+        LineMode::NetWorth => "#60a5fa",
+        LineMode::TakeHome => "#34d399",
+        LineMode::Future => "#a78bfa",
+        LineMode::LongTerm => "#fbbf24",
+    }
+    .to_string();
+
+    // Only simulate when the long-term view is showing; it's a few thousand
+    // paths.
+    let scenario = fin_state.read().scenario;
+    let scenario_points = if *line_mode.read() == LineMode::LongTerm {
+        scenario::simulate(&fin_state.read(), &scenario)
+    } else {
+        Vec::new()
+    };
+
+    let line_points: Vec<(String, f64)> = {
+        let st = fin_state.read();
+        match *line_mode.read() {
+            LineMode::NetWorth => st
+                .history
+                .iter()
+                .map(|s| (fmt_short_date(s.date), s.net_worth as f64))
+                .collect(),
+            LineMode::TakeHome => st
+                .history
+                .iter()
+                .map(|s| (fmt_short_date(s.date), s.take_home() as f64))
+                .collect(),
+            LineMode::Future => {
+                // Month-end balances from dated occurrences of every flow, so
+                // anchors and real frequencies show up as steps in the line.
+                let start = time::OffsetDateTime::now_utc().date();
+                let months =
+                    projection::project(&st, start, add_months(start, 12), Granularity::Monthly);
+                std::iter::once(("now".to_string(), st.net_worth() as f64))
+                    .chain(
+                        months
+                            .iter()
+                            .map(|p| (fmt_short_date(p.date), p.balance as f64)),
+                    )
+                    .collect()
+            }
+            LineMode::LongTerm => scenario_points
+                .iter()
+                .map(|p| (format!("{}y", p.year), p.deterministic as f64))
+                .collect(),
+        }
+    };
+    let (line_band, line_dashed): (Vec<(f64, f64)>, Vec<f64>) =
+        if *line_mode.read() == LineMode::LongTerm {
+            (
+                scenario_points
+                    .iter()
+                    .map(|p| (p.p10 as f64, p.p90 as f64))
+                    .collect(),
+                scenario_points.iter().map(|p| p.p50 as f64).collect(),
+            )
+        } else {
+            (Vec::new(), Vec::new())
+        };
+
+    rsx! {
+        div { class: "grid md:grid-cols-2 gap-3",
+            div { class: "border rounded p-3",
+                div { class: "flex items-center justify-between mb-2",
+                    h3 { class: "text-lg font-semibold", "Breakdown" }
+                    div { class: "flex gap-1",
+                        for m in PieMode::ALL {
+                            button {
+                                key: "{m.label()}",
+                                class: if *pie_mode.read() == m { "px-2 py-0.5 border rounded text-xs bg-neutral-700" } else { "px-2 py-0.5 border rounded text-xs" },
+                                onclick: move |_| { let mut pm = pie_mode; pm.set(m); },
+                                "{m.label()}"
+                            }
+                        }
+                    }
+                }
+                if pie_total > 0 {
+                    PieChart { slices: pie_slices }
+                } else {
+                    div { class: "text-xs opacity-60 p-6 text-center", "Nothing with a positive value to chart yet." }
+                }
+            }
+            div { class: "border rounded p-3",
+                div { class: "flex items-center justify-between mb-2",
+                    h3 { class: "text-lg font-semibold", "Trend" }
+                    div { class: "flex gap-1",
+                        for m in LineMode::ALL {
+                            button {
+                                key: "{m.label()}",
+                                class: if *line_mode.read() == m { "px-2 py-0.5 border rounded text-xs bg-neutral-700" } else { "px-2 py-0.5 border rounded text-xs" },
+                                onclick: move |_| { let mut lm = line_mode; lm.set(m); },
+                                "{m.label()}"
+                            }
+                        }
+                    }
+                }
+                LineChart { points: line_points, color: line_color, currency: base.clone(), band: line_band, dashed: line_dashed }
+                if *line_mode.read() == LineMode::LongTerm {
+                    ScenarioControls { fin_state, on_change, points: scenario_points.clone() }
+                }
+            }
+        }
+    }
+}
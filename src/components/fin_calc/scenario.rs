@@ -0,0 +1,77 @@
+//! Long-term scenario controls under the projection chart: horizon, Monte
+//! Carlo runs and seed, plus a summary of where the runs end up.
+
+use dioxus::prelude::*;
+
+use crate::models::finCalc::finances::FinancesFile;
+use crate::models::finCalc::scenario::{self, ScenarioPoint};
+
+use super::component::format_amount;
+
+#[component]
+pub(super) fn ScenarioControls(
+    mut fin_state: Signal<FinancesFile>,
+    on_change: EventHandler<()>,
+    points: Vec<ScenarioPoint>,
+) -> Element {
+    let base = fin_state.read().currencies.base.clone();
+    let scenario = fin_state.read().scenario;
+
+    rsx! {
+        div { class: "flex items-center gap-3 text-xs pt-2",
+            label { class: "flex items-center gap-1",
+                span { class: "opacity-60", "Years" }
+                input {
+                    r#type: "range",
+                    min: "{scenario::MIN_YEARS}",
+                    max: "{scenario::MAX_YEARS}",
+                    value: "{scenario.years}",
+                    oninput: move |e| {
+                        if let Ok(v) = e.value().parse::<u32>() {
+                            fin_state.write().scenario.years = v.clamp(scenario::MIN_YEARS, scenario::MAX_YEARS);
+                            on_change.call(());
+                        }
+                    }
+                }
+                span { "{scenario.years}" }
+            }
+            label { class: "flex items-center gap-1",
+                span { class: "opacity-60", "Runs" }
+                input {
+                    r#type: "number",
+                    min: "1",
+                    max: "{scenario::MAX_RUNS}",
+                    class: "border px-2 py-0.5 w-20 bg-transparent text-right",
+                    value: "{scenario.runs}",
+                    onchange: move |e| {
+                        if let Ok(v) = e.value().trim().parse::<u32>() {
+                            fin_state.write().scenario.runs = v.clamp(1, scenario::MAX_RUNS);
+                            on_change.call(());
+                        }
+                    }
+                }
+            }
+            label { class: "flex items-center gap-1",
+                span { class: "opacity-60", "Seed" }
+                input {
+                    r#type: "number",
+                    min: "0",
+                    class: "border px-2 py-0.5 w-20 bg-transparent text-right",
+                    value: "{scenario.seed}",
+                    onchange: move |e| {
+                        if let Ok(v) = e.value().trim().parse::<u64>() {
+                            fin_state.write().scenario.seed = v;
+                            on_change.call(());
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(last) = points.last() {
+            div { class: "text-xs opacity-70",
+                "In {last.year} years: {format_amount(last.deterministic, &base)} at expected returns; "
+                "10th–90th percentile {format_amount(last.p10, &base)} – {format_amount(last.p90, &base)} (median {format_amount(last.p50, &base)}, dashed)."
+            }
+        }
+    }
+}
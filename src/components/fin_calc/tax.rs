@@ -0,0 +1,136 @@
+//! Taxes panel: gross to take-home for income marked as gross pay.
+
+use dioxus::prelude::*;
+
+use crate::models::finCalc::finances::FinancesFile;
+use crate::models::finCalc::tax::{BracketTable, DEFAULT_TAX_TABLE_PATH};
+use crate::utils::json_store::err_to_string;
+
+use super::component::{format_amount, short_path, MoneyInput};
+
+#[component]
+pub(super) fn TaxSection(mut fin_state: Signal<FinancesFile>, on_change: EventHandler<()>) -> Element {
+    let mut table_path = use_signal(|| DEFAULT_TAX_TABLE_PATH.to_string());
+    let mut table_msg = use_signal(|| None::<String>);
+
+    let base = fin_state.read().currencies.base.clone();
+    let settings = fin_state.read().tax.clone();
+    let b = fin_state.read().tax_breakdown();
+    let has_gross = fin_state.read().income.iter().any(|f| f.taxable);
+    let effective = format!("{:.1}", b.effective_rate());
+    let table_name = if settings.table.brackets.is_empty() {
+        "No bracket table loaded".to_string()
+    } else {
+        settings.table.name.clone()
+    };
+    let rows: [(&str, i64); 7] = [
+        ("Gross", b.gross),
+        ("Pre-tax contributions", -b.pre_tax),
+        ("Standard deduction", -b.deduction),
+        ("Taxable income", b.taxable),
+        ("Income tax", -b.income_tax),
+        ("State / payroll", -b.flat_tax),
+        ("Take-home", b.net()),
+    ];
+
+    rsx! {
+        section { class: "border rounded p-3 space-y-2",
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-lg font-semibold", "Taxes" }
+                span { class: "text-xs opacity-60", "{table_name}" }
+            }
+            div { class: "flex items-center gap-2 text-xs",
+                input {
+                    class: "border px-2 py-1 flex-1 bg-transparent",
+                    value: "{table_path}",
+                    oninput: move |e| table_path.set(e.value()),
+                }
+                button {
+                    class: "px-3 py-1 border rounded",
+                    onclick: move |_| {
+                        let path = table_path.peek().clone();
+                        match BracketTable::load(&path) {
+                            Ok(t) => {
+                                table_msg.set(Some(format!("Loaded {} bracket(s) from {}", t.brackets.len(), short_path(&path))));
+                                fin_state.write().tax.table = t;
+                                on_change.call(());
+                            }
+                            Err(e) => table_msg.set(Some(err_to_string(e))),
+                        }
+                    },
+                    "Load brackets"
+                }
+            }
+            if let Some(msg) = table_msg.read().as_ref() {
+                p { class: "text-xs text-blue-400", "{msg}" }
+            }
+            div { class: "flex items-center gap-3 text-xs",
+                label { class: "flex items-center gap-1",
+                    span { class: "opacity-60", "Pre-tax /yr" }
+                    MoneyInput {
+                        cents: settings.pre_tax_annual,
+                        on_commit: move |c: i64| { fin_state.write().tax.pre_tax_annual = c.max(0); on_change.call(()); }
+                    }
+                }
+                label { class: "flex items-center gap-1",
+                    span { class: "opacity-60", "+ % of gross" }
+                    input {
+                        r#type: "number",
+                        step: "0.5",
+                        min: "0",
+                        class: "border px-2 py-1 w-16 bg-transparent text-right",
+                        value: "{settings.pre_tax_percent}",
+                        onchange: move |e| {
+                            if let Ok(v) = e.value().trim().parse::<f64>() {
+                                fin_state.write().tax.pre_tax_percent = v.max(0.0);
+                                on_change.call(());
+                            }
+                        }
+                    }
+                }
+                label { class: "flex items-center gap-1",
+                    span { class: "opacity-60", "State / payroll %" }
+                    input {
+                        r#type: "number",
+                        step: "0.05",
+                        min: "0",
+                        class: "border px-2 py-1 w-16 bg-transparent text-right",
+                        value: "{settings.flat_rate}",
+                        onchange: move |e| {
+                            if let Ok(v) = e.value().trim().parse::<f64>() {
+                                fin_state.write().tax.flat_rate = v.max(0.0);
+                                on_change.call(());
+                            }
+                        }
+                    }
+                }
+            }
+            if has_gross {
+                table { class: "w-full text-sm border-collapse",
+                    thead { tr {
+                        th { class: "text-left py-1", "" }
+                        th { class: "text-right py-1", "Per year" }
+                        th { class: "text-right py-1", "Per month" }
+                    } }
+                    tbody {
+                        for (label, cents) in rows {
+                            tr { key: "{label}",
+                                class: if label == "Take-home" { "font-semibold border-t border-neutral-700" } else { "" },
+                                td { class: "py-1 pr-2", "{label}" }
+                                td { class: "py-1 pr-2 text-right", "{format_amount(cents, &base)}" }
+                                td { class: "py-1 pr-2 text-right opacity-70", "{format_amount((cents as f64 / 12.0).round() as i64, &base)}" }
+                            }
+                        }
+                    }
+                }
+                div { class: "text-xs opacity-70",
+                    "Effective rate {effective}% · marginal {b.marginal_rate}%"
+                }
+            } else {
+                p { class: "text-xs opacity-60",
+                    "Tick “Gross” on income entered before tax to estimate what's withheld. Unticked income is treated as take-home."
+                }
+            }
+        }
+    }
+}
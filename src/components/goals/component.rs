@@ -0,0 +1,567 @@
+/**
+ * 
+ * I want to have my embedded sys/ engineering firm, live in missouri, have land, prep building a house
+ * 
+ * all geared toward engineering firm which you need to start ASAP
+ * 
+ * finish the project, ensure drafting the project in a professional manner that shows:
+ * 		how well you came from concept to practice to refinement
+ * 		how well tolerances are
+ * 		what concepts you've realistically touched to showcase capabilities, and thus what industries it can apply to  (all really)
+ * 
+ * get in contact with patent attorneys, grant money ppl, 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ * 
+ */
+/// src/components/goals/component.rs
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::components::fin_calc::default_finances_path;
+use crate::components::jax_brain::DEFAULT_DICT_PATH;
+use crate::components::time::{today_local, use_time, Freq};
+use crate::models::finCalc::finances::{currency_symbol, FinancesFile};
+use crate::models::finCalc::projection::from_naive;
+use crate::models::goals::forecast::GoalStatus;
+use crate::models::goals::links::{KnownTargets, JAXBRAIN_PREFIX};
+use crate::models::goals::goals::{GoalCategory, GoalNode, GoalsFile, Metric, Smart, DEFAULT_GOALS_PATH};
+use crate::models::jaxBrain::templates::dictionary::{build_graph_from_dictionary, load_dictionary_from_path};
+use crate::utils::json_store::{err_to_string, load_json, save_json};
+use crate::Route;
+
+use super::editor::{clear_create_form, CreateGoalForm};
+use super::store::use_goals;
+use super::templates::TemplatePicker;
+use super::tree::GoalTree;
+
+#[derive(Clone)]
+pub(super) struct GoalsCtx {
+    pub(super) goals_state: Signal<GoalsFile>,
+    pub(super) status: Signal<Option<String>>,
+
+    // editing
+    pub(super) editing_id: Signal<Option<Uuid>>,
+    pub(super) draft_title: Signal<String>,
+    pub(super) draft_s: Signal<String>,
+    pub(super) draft_m: Signal<String>,
+    pub(super) draft_a: Signal<String>,
+    pub(super) draft_r: Signal<String>,
+    pub(super) draft_t: Signal<String>,
+
+    // creating (root or subgoal)
+    pub(super) create_open: Signal<bool>,
+    pub(super) create_parent: Signal<Option<Uuid>>, // None => root, Some(id) => subgoal under id
+    pub(super) create_title: Signal<String>,
+    pub(super) create_category: Signal<GoalCategory>,
+    pub(super) create_s: Signal<String>,
+    pub(super) create_m: Signal<String>,
+    pub(super) create_a: Signal<String>,
+    pub(super) create_r: Signal<String>,
+    pub(super) create_t: Signal<String>,
+    pub(super) create_metric_mode: Signal<String>, // "boolean" | "numeric" | "finance" | "habit"
+    pub(super) create_unit: Signal<String>,
+    pub(super) create_target: Signal<String>,
+    pub(super) create_source: Signal<String>, // "net_worth" | "asset:<id>" | "debt:<id>"
+    pub(super) create_deadline: Signal<String>, // YYYY-MM-DD, blank = none
+    pub(super) create_freq: Signal<Freq>,
+    pub(super) create_interval: Signal<String>,
+    pub(super) create_weekdays: Signal<[bool; 7]>, // 0=Mon, weekly habits only
+
+    // FinCalc file that finance goals read from (None until it loads)
+    pub(super) finances: Signal<Option<FinancesFile>>,
+
+    // tree filter: active goals, or only archived ones
+    pub(super) show_archived: Signal<bool>,
+    // deep-linked goal: it and its ancestors start expanded
+    pub(super) focus: Option<Uuid>,
+
+    // JaxBrain node ids (None until a goal links there), and everything
+    // context links are checked against
+    pub(super) jax_nodes: Signal<Option<HashSet<String>>>,
+    pub(super) known_links: Memo<KnownTargets>,
+}
+
+pub(super) fn cat_label(c: GoalCategory) -> &'static str {
+    match c {
+        GoalCategory::Health => "health",
+        GoalCategory::Wealth => "wealth",
+        GoalCategory::Research => "research",
+        GoalCategory::Time => "time",
+        GoalCategory::Other => "other",
+    }
+}
+
+/// Today's local date, the day check-ins and logged time land on.
+pub(super) fn local_today() -> time::Date {
+    from_naive(today_local()).unwrap_or_else(|| time::OffsetDateTime::now_utc().date())
+}
+
+pub(super) fn parse_iso_date(s: &str) -> Option<time::Date> {
+    let f = time::macros::format_description!("[year]-[month]-[day]");
+    time::Date::parse(s.trim(), &f).ok()
+}
+
+pub(super) fn status_class(s: GoalStatus) -> &'static str {
+    match s {
+        GoalStatus::Done => "text-xs px-2 rounded border border-green-600 text-green-400 whitespace-nowrap",
+        GoalStatus::Ahead => "text-xs px-2 rounded border border-emerald-600 text-emerald-300 whitespace-nowrap",
+        GoalStatus::OnTrack => "text-xs px-2 rounded border border-blue-600 text-blue-300 whitespace-nowrap",
+        GoalStatus::AtRisk => "text-xs px-2 rounded border border-amber-600 text-amber-300 whitespace-nowrap",
+        GoalStatus::Overdue => "text-xs px-2 rounded border border-red-600 text-red-400 whitespace-nowrap",
+        GoalStatus::Unknown => "text-xs px-2 rounded border border-neutral-700 text-neutral-500 whitespace-nowrap",
+    }
+}
+
+/// One-line progress for numeric, finance and habit metrics; `None` for
+/// checkboxes.
+pub(super) fn metric_summary(metric: &Metric, fin: Option<&FinancesFile>, today: time::Date) -> Option<String> {
+    match metric {
+        Metric::Boolean { .. } => None,
+        Metric::Numeric { unit, current, target, .. } => {
+            Some(format!("{current} / {target} {unit}"))
+        }
+        Metric::Finance { source, current, target } => {
+            let (label, sym) = match fin {
+                Some(f) => (source.label(f), currency_symbol(&f.currencies.base)),
+                None => ("FinCalc not loaded".to_string(), "$"),
+            };
+            Some(format!("{sym}{current:.2} / {sym}{target:.2} · {label}"))
+        }
+        Metric::Habit { cadence, .. } => {
+            let stats = metric.habit_stats(today)?;
+            Some(format!(
+                "streak {} (best {}) · {:.0}% · {}",
+                stats.current_streak,
+                stats.longest_streak,
+                stats.completion_rate * 100.0,
+                cadence.human()
+            ))
+        }
+    }
+}
+
+/// The newest `n` journal entries as (date, text), newest first.
+pub(super) fn journal_lines(node: &GoalNode, n: usize) -> Vec<(String, String)> {
+    node.journal
+        .iter()
+        .rev()
+        .take(n)
+        .map(|e| (e.at.date().to_string(), e.kind.describe()))
+        .collect()
+}
+
+/// Re-read the FinCalc file and push live balances into finance goals.
+fn refresh_finances(ctx: &GoalsCtx) {
+    let mut finances = ctx.finances.clone();
+    let mut goals_state = ctx.goals_state.clone();
+    let mut status = ctx.status.clone();
+    match FinancesFile::load(default_finances_path()) {
+        Ok(fin) => {
+            let changed = goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| g.refresh_from_finances(&fin));
+            if changed > 0 {
+                status.set(Some(format!("Updated {} goal(s) from FinCalc.", changed)));
+            }
+            finances.set(Some(fin));
+        }
+        Err(e) => status.set(Some(format!("FinCalc balances unavailable: {}", err_to_string(e)))),
+    }
+}
+
+fn has_jaxbrain_link(nodes: &[GoalNode]) -> bool {
+    nodes.iter().any(|n| {
+        n.context_links.iter().any(|l| l.value.trim().starts_with(JAXBRAIN_PREFIX))
+            || has_jaxbrain_link(&n.children)
+    })
+}
+
+/// Node ids in the default JaxBrain dictionary; `None` if it can't be read.
+pub(super) fn load_jax_nodes() -> Option<HashSet<String>> {
+    let dict = load_dictionary_from_path(DEFAULT_DICT_PATH).ok()?;
+    Some(build_graph_from_dictionary(&dict).nodes.into_iter().map(|n| n.id).collect())
+}
+
+fn seed_dummy_goals() -> GoalsFile {
+    let mut f = GoalsFile::default();
+
+    let smart_weight = Smart {
+        specific: "I want to lose weight, tone down, lose unwanted fat, become fucking ripped".to_string(),
+        measurable: "I am currently 214.6 [12-29-25] so i have to lose 44.6 lbs in 4 months (11.15 lbs / month | 2.78 lbs / week)".to_string(),
+        achievable: "I have lost weight before, I am more than capable stopping bad habits, it's all about momentum and self discapline".to_string(),
+        relevant: "it's important for find my wife, living my life to the fullest without hinderance, and I must lead by example for the coming struggle".to_string(),
+        time_bound: "I want to acheive this by april 29th".to_string(),
+    };
+
+    let metric_weight = Metric::Numeric {
+        unit: "lb".to_string(),
+        current: 214.6,
+        target: 170.0,
+        clamp_0_100: false,
+    };
+
+    let mut weight_goal = GoalNode::new("weight", GoalCategory::Health, smart_weight, metric_weight);
+
+    weight_goal.add_child(GoalNode::new(
+        "hit 10k steps/day",
+        GoalCategory::Health,
+        Smart {
+            specific: "Walk daily to increase baseline calorie burn".into(),
+            measurable: "10,000 steps per day tracked".into(),
+            achievable: "I can do this even on busy days by splitting walks".into(),
+            relevant: "Supports fat loss and conditioning".into(),
+            time_bound: "Start today; maintain for 12 weeks".into(),
+        },
+        Metric::Boolean { done: false },
+    ));
+
+    weight_goal.add_child(GoalNode::new(
+        "keep carbs ~10%",
+        GoalCategory::Health,
+        Smart {
+            specific: "Keep carbs limited, mostly rice around training".into(),
+            measurable: "Log macros; carbs ~10% of calories".into(),
+            achievable: "Food list is simple; I can repeat meals".into(),
+            relevant: "Helps cut while holding appetite stable".into(),
+            time_bound: "For the next 16 weeks".into(),
+        },
+        Metric::Boolean { done: false },
+    ));
+
+    f.roots.push(weight_goal);
+
+    f.roots.push(GoalNode::new(
+        "new business",
+        GoalCategory::Wealth,
+        Smart {
+            specific: "Launch/scale the engineering/embedded services business with a clear offer and pipeline".into(),
+            measurable: "Publish offer + portfolio, close first 3 paid engagements".into(),
+            achievable: "I already build systems; package into sellable outcomes".into(),
+            relevant: "Funds land + future R&D".into(),
+            time_bound: "Within 90 days: first clients".into(),
+        },
+        Metric::Numeric {
+            unit: "clients".into(),
+            current: 0.0,
+            target: 3.0,
+            clamp_0_100: true,
+        },
+    ));
+
+    f.roots.push(GoalNode::new(
+        "purchase land",
+        GoalCategory::Wealth,
+        Smart {
+            specific: "Save and prepare to purchase land suitable for building and long-term living".into(),
+            measurable: "Down payment saved + shortlist 10 viable properties".into(),
+            achievable: "Automate saving; strict criteria".into(),
+            relevant: "Foundation for house + workshop".into(),
+            time_bound: "12–18 months".into(),
+        },
+        Metric::Numeric {
+            unit: "usd".into(),
+            current: 0.0,
+            target: 25_000.0,
+            clamp_0_100: true,
+        },
+    ));
+
+    f
+}
+
+
+#[component]
+pub fn Goals(
+    #[props(default)] overview: bool,
+    /// Deep link: a goal to open on, with its ancestors expanded.
+    #[props(default)] focus: Option<Uuid>,
+) -> Element {
+    let mut file_path = use_signal(|| DEFAULT_GOALS_PATH.to_string());
+    let goals_state = use_goals().goals();
+    let status = use_signal(|| None::<String>);
+
+    // editing
+    let editing_id = use_signal(|| None::<Uuid>);
+    let draft_title = use_signal(|| "".to_string());
+    let draft_s = use_signal(|| "".to_string());
+    let draft_m = use_signal(|| "".to_string());
+    let draft_a = use_signal(|| "".to_string());
+    let draft_r = use_signal(|| "".to_string());
+    let draft_t = use_signal(|| "".to_string());
+
+    // creating
+    let create_open = use_signal(|| false);
+    let create_parent = use_signal(|| None::<Uuid>);
+    let create_title = use_signal(|| "".to_string());
+    let create_category = use_signal(|| GoalCategory::Health);
+    let create_s = use_signal(|| "".to_string());
+    let create_m = use_signal(|| "".to_string());
+    let create_a = use_signal(|| "".to_string());
+    let create_r = use_signal(|| "".to_string());
+    let create_t = use_signal(|| "".to_string());
+    let create_metric_mode = use_signal(|| "boolean".to_string());
+    let create_unit = use_signal(|| "usd".to_string());
+    let create_target = use_signal(|| "0".to_string());
+    let create_source = use_signal(|| "net_worth".to_string());
+    let create_deadline = use_signal(|| "".to_string());
+    let create_freq = use_signal(|| Freq::Weekly);
+    let create_interval = use_signal(|| "1".to_string());
+    let create_weekdays = use_signal(|| [false; 7]);
+    let finances = use_signal(|| None::<FinancesFile>);
+    // A shelved goal only shows in the archived view.
+    let show_archived = use_signal(|| {
+        focus.is_some_and(|f| goals_state.peek().archived().iter().any(|n| n.contains(f)))
+    });
+
+    // The dictionary is big, so only read it when some goal links into it.
+    let jax_nodes = use_signal(|| {
+        has_jaxbrain_link(&goals_state.peek().roots).then(load_jax_nodes).flatten()
+    });
+    let scheduler = use_time();
+    let known_links = use_memo(move || KnownTargets {
+        events: Some(scheduler.events().read().iter().map(|e| e.id).collect()),
+        jax_nodes: jax_nodes.read().clone(),
+    });
+
+    use_context_provider(|| GoalsCtx {
+        goals_state,
+        status,
+
+        editing_id,
+        draft_title,
+        draft_s,
+        draft_m,
+        draft_a,
+        draft_r,
+        draft_t,
+
+        create_open,
+        create_parent,
+        create_title,
+        create_category,
+        create_s,
+        create_m,
+        create_a,
+        create_r,
+        create_t,
+        create_metric_mode,
+        create_unit,
+        create_target,
+        create_source,
+        create_deadline,
+        create_freq,
+        create_interval,
+        create_weekdays,
+
+        finances,
+
+        show_archived,
+        focus,
+
+        jax_nodes,
+        known_links,
+    });
+
+    let ctx = use_context::<GoalsCtx>();
+
+    // Pick up FinCalc balances once on mount (the effect reads no signals).
+    {
+        let ctx = ctx.clone();
+        use_effect(move || refresh_finances(&ctx));
+    }
+
+    // IMPORT: replace the tree with a file (it autosaves from then on)
+    let on_import = {
+        let mut goals_state = ctx.goals_state.clone();
+        let mut status = ctx.status.clone();
+        let file_path = file_path.clone();
+        let mut editing_id = ctx.editing_id.clone();
+        let mut create_open = ctx.create_open.clone();
+        let finances = ctx.finances.clone();
+
+        move |_| {
+            let path = file_path.read().clone();
+            match load_json::<GoalsFile>(&path) {
+                Ok(mut f) => {
+                    if let Some(fin) = finances.read().as_ref() {
+                        f.refresh_from_finances(fin);
+                    }
+                    goals_state.set(f);
+                    editing_id.set(None);
+                    create_open.set(false);
+                    status.set(Some(format!("Imported goals from {}", path)));
+                }
+                Err(e) => status.set(Some(err_to_string(e))),
+            }
+        }
+    };
+
+    // EXPORT: copy of the current tree
+    let on_export = {
+        let goals_state = ctx.goals_state.clone();
+        let mut status = ctx.status.clone();
+        let file_path = file_path.clone();
+
+        move |_| {
+            let path = file_path.read().clone();
+            let data = goals_state.read().clone();
+            match save_json(&path, &data) {
+                Ok(()) => status.set(Some(format!("Exported goals to {}", path))),
+                Err(e) => status.set(Some(err_to_string(e))),
+            }
+        }
+    };
+
+    // Seeding would overwrite real goals now that everything autosaves.
+    let on_seed_dummy = {
+        let mut goals_state = ctx.goals_state.clone();
+        let mut status = ctx.status.clone();
+        let mut editing_id = ctx.editing_id.clone();
+        let mut create_open = ctx.create_open.clone();
+
+        move |_| {
+            if !goals_state.read().roots.is_empty() {
+                status.set(Some("Seeding only works on an empty goal list.".into()));
+                return;
+            }
+            goals_state.set(seed_dummy_goals());
+            editing_id.set(None);
+            create_open.set(false);
+            status.set(Some("Seeded dummy goals.".into()));
+        }
+    };
+
+    let on_refresh_finances = {
+        let ctx = ctx.clone();
+        move |_| refresh_finances(&ctx)
+    };
+
+    // Add root goal button
+    let on_open_create_root = {
+        let mut ctx = ctx.clone();
+        move |_| {
+            ctx.create_parent.set(None);
+            ctx.create_open.set(true);
+            clear_create_form(&ctx);
+            ctx.status.set(Some("Creating a ROOT goal. Fill the form and click Create.".into()));
+        }
+    };
+
+    if overview {
+        return rsx! { GoalsOverview {} };
+    }
+
+    let due_reviews = ctx.goals_state.read().due_for_review(local_today()).len();
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: asset!("./style.css") }
+
+        div { class: "flex flex-col gap-3 text-secondary-color",
+
+            // Path + Import/Export
+            div { class: "flex items-center gap-2",
+                input {
+                    class: "border px-2 py-1 flex-1 bg-transparent",
+                    value: "{file_path.read()}",
+                    oninput: {
+                        let mut file_path = file_path.clone();
+                        move |evt| file_path.set(evt.value().to_string())
+                    }
+                }
+                button { class: "px-3 py-1 border rounded", onclick: on_import, "Import" }
+                button { class: "px-3 py-1 border rounded", onclick: on_export, "Export" }
+                button { class: "px-3 py-1 border rounded", onclick: on_seed_dummy, "Seed dummy goals" }
+                button { class: "px-3 py-1 border rounded", onclick: on_refresh_finances, "Refresh balances" }
+                button { class: "px-3 py-1 border rounded", onclick: on_open_create_root, "+ Add Goal" }
+                Link { class: "px-3 py-1 border rounded", to: Route::GoalReviewView {},
+                    if due_reviews > 0 { "Review ({due_reviews} due)" } else { "Review" }
+                }
+                TemplatePicker {}
+                label { class: "flex items-center gap-1 text-xs text-neutral-400",
+                    input {
+                        r#type: "checkbox",
+                        checked: ctx.goals_state.read().auto_complete_parents,
+                        onchange: {
+                            let mut goals_state = ctx.goals_state.clone();
+                            move |e: Event<FormData>| {
+                                goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| {
+                                    g.auto_complete_parents = e.checked();
+                                    g.apply_auto_complete();
+                                });
+                            }
+                        }
+                    }
+                    "Auto-complete parents"
+                }
+            }
+
+            if let Some(msg) = ctx.status.read().as_ref() {
+                p { class: "text-sm text-blue-400", "{msg}" }
+            }
+
+            if *ctx.create_open.read() {
+                CreateGoalForm {}
+            }
+
+            GoalTree {}
+        }
+    }
+}
+
+/// Compact summary for the Overview page: every live root with its rolled-up
+/// progress and status. Reads the shared store, so it's always current.
+#[component]
+fn GoalsOverview() -> Element {
+    let goals = use_goals().goals();
+    let today = local_today();
+    let g = goals.read();
+    let statuses = g.statuses(today);
+    let roots: Vec<(Uuid, String, f64, GoalStatus)> = g
+        .roots
+        .iter()
+        .filter(|r| !r.archived)
+        .map(|r| {
+            let status = statuses.get(&r.id).copied().unwrap_or(GoalStatus::Unknown);
+            (r.id, r.title.clone(), r.progress() * 100.0, status)
+        })
+        .collect();
+    let slipping = roots
+        .iter()
+        .filter(|(_, _, _, s)| matches!(s, GoalStatus::AtRisk | GoalStatus::Overdue))
+        .count();
+
+    rsx! {
+        div { class: "flex flex-col gap-2 text-secondary-color border rounded p-3",
+            div { class: "flex items-center justify-between",
+                div { class: "text-xs uppercase opacity-60", "Goals" }
+                if slipping > 0 {
+                    span { class: status_class(GoalStatus::AtRisk), "{slipping} slipping" }
+                }
+            }
+            if roots.is_empty() {
+                p { class: "text-sm text-neutral-500", "No goals yet." }
+            }
+            for (id, title, pct, status) in roots {
+                div { key: "{id}", class: "flex items-center gap-3",
+                    span { class: "flex-1 truncate", "{title}" }
+                    div { class: "w-24 h-1.5 rounded bg-neutral-800 overflow-hidden", title: "{pct:.0}%",
+                        div { class: "h-full bg-blue-500", style: format!("width: {:.0}%;", pct) }
+                    }
+                    span { class: status_class(status), "{status.label()}" }
+                }
+            }
+        }
+    }
+}
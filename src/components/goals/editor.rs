@@ -0,0 +1,441 @@
+//! Creating goals (root or subgoal) and editing a goal's title and SMART fields.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::components::time::{Freq, Recurrence};
+use crate::models::finCalc::finances::FinancesFile;
+use crate::models::goals::goals::{FinanceSource, GoalCategory, GoalNode, Metric, Smart};
+use crate::models::goals::smart::{parse_time_bound, SmartIssue};
+
+use super::component::{local_today, parse_iso_date, GoalsCtx};
+
+/// "net_worth" | "asset:<id>" | "debt:<id>" from the create form.
+fn parse_source(s: &str) -> Option<FinanceSource> {
+    match s.split_once(':') {
+        Some(("asset", id)) => Uuid::parse_str(id).ok().map(|id| FinanceSource::Asset { id }),
+        Some(("debt", id)) => Uuid::parse_str(id).ok().map(|id| FinanceSource::DebtPaidOff { id }),
+        _ if s == "net_worth" => Some(FinanceSource::NetWorth),
+        _ => None,
+    }
+}
+
+const WEEKDAY_ABBR: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+pub(super) fn clear_create_form(ctx: &GoalsCtx) {
+    ctx.create_title.clone().set("".into());
+    ctx.create_s.clone().set("".into());
+    ctx.create_m.clone().set("".into());
+    ctx.create_a.clone().set("".into());
+    ctx.create_r.clone().set("".into());
+    ctx.create_t.clone().set("".into());
+    ctx.create_metric_mode.clone().set("boolean".into());
+    ctx.create_unit.clone().set("usd".into());
+    ctx.create_target.clone().set("0".into());
+    ctx.create_source.clone().set("net_worth".into());
+    ctx.create_deadline.clone().set("".into());
+    ctx.create_freq.clone().set(Freq::Weekly);
+    ctx.create_interval.clone().set("1".into());
+    ctx.create_weekdays.clone().set([false; 7]);
+}
+
+#[component]
+pub(super) fn CreateGoalForm() -> Element {
+    let ctx = use_context::<GoalsCtx>();
+    let is_sub = ctx.create_parent.read().is_some();
+
+    // (value, label) for the finance source picker
+    let finance_options: Vec<(String, String)> = match ctx.finances.read().as_ref() {
+        Some(fin) => fin
+            .assets
+            .iter()
+            .map(|a| (format!("asset:{}", a.id), a.name.clone()))
+            .chain(
+                fin.liabilities
+                    .iter()
+                    .map(|l| (format!("debt:{}", l.id), format!("Pay off {}", l.name))),
+            )
+            .collect(),
+        None => vec![],
+    };
+
+    let can_create = {
+        !ctx.create_title.read().trim().is_empty()
+            && !ctx.create_s.read().trim().is_empty()
+            && !ctx.create_m.read().trim().is_empty()
+            && !ctx.create_a.read().trim().is_empty()
+            && !ctx.create_r.read().trim().is_empty()
+            && !ctx.create_t.read().trim().is_empty()
+            && match ctx.create_metric_mode.read().as_str() {
+                "boolean" => true,
+                "habit" => ctx.create_interval.read().trim().parse::<u32>().is_ok_and(|n| n > 0),
+                "finance" => {
+                    let source = parse_source(ctx.create_source.read().as_str());
+                    // Debt goals take their target from today's balance.
+                    matches!(source, Some(FinanceSource::DebtPaidOff { .. }))
+                        || (source.is_some() && ctx.create_target.read().trim().parse::<f64>().is_ok())
+                }
+                _ => ctx.create_target.read().trim().parse::<f64>().is_ok(),
+            }
+    };
+
+    // SMART warnings for the draft; they don't block creating.
+    let draft_issues: Vec<&'static str> = {
+        let draft = Smart {
+            specific: String::new(),
+            measurable: ctx.create_m.read().clone(),
+            achievable: String::new(),
+            relevant: String::new(),
+            time_bound: ctx.create_t.read().clone(),
+        };
+        let mut issues = draft.lint(local_today());
+        let has_target = match ctx.create_metric_mode.read().as_str() {
+            "numeric" => true,
+            "finance" => !matches!(parse_source(ctx.create_source.read().as_str()), Some(FinanceSource::DebtPaidOff { .. })),
+            _ => false,
+        };
+        if has_target && ctx.create_target.read().trim().parse::<f64>() == Ok(0.0) {
+            issues.push(SmartIssue::ZeroTarget);
+        }
+        issues.iter().map(|i| i.message()).collect()
+    };
+
+    let on_cancel = {
+        let mut ctx = ctx.clone();
+        move |_| {
+            ctx.create_open.set(false);
+            ctx.create_parent.set(None);
+            ctx.status.set(Some("Create cancelled.".into()));
+        }
+    };
+
+    let on_create = {
+        let mut ctx = ctx.clone();
+        move |_| {
+            if !can_create {
+                ctx.status.set(Some("Fill all SMART fields and valid target (if numeric).".into()));
+                return;
+            }
+
+            let smart = Smart {
+                specific: ctx.create_s.read().trim().to_string(),
+                measurable: ctx.create_m.read().trim().to_string(),
+                achievable: ctx.create_a.read().trim().to_string(),
+                relevant: ctx.create_r.read().trim().to_string(),
+                time_bound: ctx.create_t.read().trim().to_string(),
+            };
+
+            let tgt = ctx.create_target.read().trim().parse::<f64>().unwrap_or(0.0);
+            let mode = ctx.create_metric_mode.read().clone();
+            let mut metric = match mode.as_str() {
+                "boolean" => Metric::Boolean { done: false },
+                "habit" => {
+                    let freq = *ctx.create_freq.read();
+                    let by_weekday = if freq == Freq::Weekly {
+                        (0..7u8).filter(|&i| ctx.create_weekdays.read()[i as usize]).collect()
+                    } else {
+                        vec![]
+                    };
+                    Metric::Habit {
+                        cadence: Recurrence {
+                            freq,
+                            interval: ctx.create_interval.read().trim().parse().unwrap_or(1),
+                            by_weekday,
+                            count: None,
+                            until: None,
+                        },
+                        start: local_today(),
+                        checkins: vec![],
+                    }
+                }
+                "finance" => {
+                    let empty = FinancesFile::default();
+                    let fin = ctx.finances.read().clone().unwrap_or(empty);
+                    match parse_source(ctx.create_source.read().as_str()) {
+                        Some(FinanceSource::DebtPaidOff { id }) => Metric::debt_payoff(&fin, id),
+                        Some(source) => Metric::Finance { source, current: 0.0, target: tgt },
+                        None => Metric::Boolean { done: false },
+                    }
+                }
+                _ => Metric::Numeric {
+                    unit: ctx.create_unit.read().trim().to_string(),
+                    current: 0.0,
+                    target: tgt,
+                    clamp_0_100: true,
+                },
+            };
+            if let Some(fin) = ctx.finances.read().as_ref() {
+                metric.refresh(fin);
+            }
+
+            let mut node = GoalNode::new(
+                ctx.create_title.read().trim().to_string(),
+                *ctx.create_category.read(),
+                smart,
+                metric,
+            );
+            // A blank deadline falls back to whatever the T field says.
+            node.deadline = parse_iso_date(&ctx.create_deadline.read())
+                .or_else(|| parse_time_bound(&node.smart.time_bound, local_today()));
+            // Start the log where the goal starts, so the trend has an origin.
+            if let Some((current, _)) = node.metric.values() {
+                node.record_progress(local_today(), current);
+            }
+
+            if let Some(parent_id) = *ctx.create_parent.read() {
+                // add as subgoal
+                if let Some(parent) = ctx.goals_state.write().find_mut(parent_id) {
+                    parent.add_child(node);
+                    ctx.status.set(Some("Created subgoal.".into()));
+                } else {
+                    ctx.status.set(Some("Parent goal not found.".into()));
+                }
+            } else {
+                // add root
+                ctx.goals_state.write().roots.push(node);
+                ctx.status.set(Some("Created root goal.".into()));
+            }
+
+            ctx.create_open.set(false);
+            ctx.create_parent.set(None);
+            clear_create_form(&ctx);
+        }
+    };
+
+    rsx! {
+        div { class: "border rounded p-4 space-y-3",
+            div { class: "flex items-center justify-between",
+                h3 { class: "text-xl font-bold",
+                    if is_sub { "Create Subgoal (SMART)" } else { "Create Goal (SMART)" }
+                }
+                div { class: "flex gap-2",
+                    button { class: "px-3 py-1 border rounded", onclick: on_create, "Create" }
+                    button { class: "px-3 py-1 border rounded", onclick: on_cancel, "Cancel" }
+                }
+            }
+
+            input {
+                class: "border px-2 py-1 bg-transparent w-full",
+                placeholder: "Title",
+                value: "{ctx.create_title.read()}",
+                oninput: { let mut s = ctx.create_title.clone(); move |e| s.set(e.value()) }
+            }
+            div { class: "flex items-center justify-between",
+				select {
+					class: "border px-2 py-1 bg-red",
+					onchange: {
+						let mut category = ctx.create_category.clone();
+						move |e| {
+							category.set(match e.value().as_str() {
+								"health" => GoalCategory::Health,
+								"wealth" => GoalCategory::Wealth,
+								"research" => GoalCategory::Research,
+								"time" => GoalCategory::Time,
+								_ => GoalCategory::Other,
+							})
+						}
+					},
+					option { value: "health", "Health" }
+					option { value: "wealth", "Wealth" }
+					option { value: "research", "Research" }
+					option { value: "time", "Time" }
+					option { value: "other", "Other" }
+				}
+
+				textarea { class: "border px-2 py-1 bg-transparent", rows: "2",
+					placeholder: "S: Specific — what exactly is the outcome?",
+					value: "{ctx.create_s.read()}",
+					oninput: { let mut s = ctx.create_s.clone(); move |e| s.set(e.value()) }
+				}
+				textarea { class: "border px-2 py-1 bg-transparent", rows: "2",
+					placeholder: "M: Measurable — how will you measure progress?",
+					value: "{ctx.create_m.read()}",
+					oninput: { let mut s = ctx.create_m.clone(); move |e| s.set(e.value()) }
+				}
+				textarea { class: "border px-2 py-1 bg-transparent", rows: "2",
+					placeholder: "A: Achievable — what makes it realistic?",
+					value: "{ctx.create_a.read()}",
+					oninput: { let mut s = ctx.create_a.clone(); move |e| s.set(e.value()) }
+				}
+				textarea { class: "border px-2 py-1 bg-transparent", rows: "2",
+					placeholder: "R: Relevant — why does it matter / what does it support?",
+					value: "{ctx.create_r.read()}",
+					oninput: { let mut s = ctx.create_r.clone(); move |e| s.set(e.value()) }
+				}
+				textarea { class: "border px-2 py-1 bg-transparent", rows: "2",
+					placeholder: "T: Time-bound — by when / cadence?",
+					value: "{ctx.create_t.read()}",
+					oninput: { let mut s = ctx.create_t.clone(); move |e| s.set(e.value()) }
+				}
+				label { class: "flex items-center gap-1 text-xs text-neutral-400",
+					"Deadline"
+					input {
+						r#type: "date",
+						class: "border px-2 py-1 bg-transparent",
+						value: "{ctx.create_deadline.read()}",
+						oninput: { let mut d = ctx.create_deadline.clone(); move |e| d.set(e.value()) }
+					}
+				}
+
+				div { class: "flex items-center gap-2",
+					select {
+						class: "border px-2 py-1 bg-transparent",
+						onchange: { let mut mm = ctx.create_metric_mode.clone(); move |e| mm.set(e.value()) },
+						option { value: "boolean", "Checkbox (done / not)" }
+						option { value: "numeric", "Numeric (progress)" }
+						option { value: "finance", "FinCalc balance (live)" }
+						option { value: "habit", "Habit (recurring check-ins)" }
+					}
+
+					if ctx.create_metric_mode.read().as_str() == "habit" {
+						"every"
+						input {
+							r#type: "number",
+							min: "1",
+							class: "border px-2 py-1 bg-transparent w-16",
+							value: "{ctx.create_interval.read()}",
+							oninput: { let mut n = ctx.create_interval.clone(); move |e| n.set(e.value()) }
+						}
+						select {
+							class: "border px-2 py-1 bg-transparent",
+							onchange: {
+								let mut freq = ctx.create_freq.clone();
+								move |e| {
+									freq.set(match e.value().as_str() {
+										"day" => Freq::Daily,
+										"month" => Freq::Monthly,
+										"year" => Freq::Yearly,
+										_ => Freq::Weekly,
+									})
+								}
+							},
+							for f in Freq::ALL {
+								option { value: "{f.label()}", selected: *ctx.create_freq.read() == f, "{f.label()}(s)" }
+							}
+						}
+						if *ctx.create_freq.read() == Freq::Weekly {
+							for i in 0..7usize {
+								button {
+									key: "{i}",
+									class: if ctx.create_weekdays.read()[i] { "px-2 py-1 border rounded text-xs bg-neutral-800" } else { "px-2 py-1 border rounded text-xs" },
+									onclick: {
+										let mut wd = ctx.create_weekdays.clone();
+										move |_| { let mut w = *wd.read(); w[i] = !w[i]; wd.set(w); }
+									},
+									"{WEEKDAY_ABBR[i]}"
+								}
+							}
+						}
+					}
+
+					if ctx.create_metric_mode.read().as_str() == "numeric" {
+						input {
+							class: "border px-2 py-1 bg-transparent w-24",
+							placeholder: "unit",
+							value: "{ctx.create_unit.read()}",
+							oninput: { let mut u = ctx.create_unit.clone(); move |e| u.set(e.value()) }
+						}
+						input {
+							class: "border px-2 py-1 bg-transparent w-24",
+							placeholder: "target",
+							value: "{ctx.create_target.read()}",
+							oninput: { let mut t = ctx.create_target.clone(); move |e| t.set(e.value()) }
+						}
+					}
+
+					if ctx.create_metric_mode.read().as_str() == "finance" {
+						select {
+							class: "border px-2 py-1 bg-transparent",
+							value: "{ctx.create_source.read()}",
+							onchange: { let mut src = ctx.create_source.clone(); move |e| src.set(e.value()) },
+							option { value: "net_worth", "Net worth" }
+							for (value, label) in finance_options.iter().cloned() {
+								option { key: "{value}", value: "{value}", "{label}" }
+							}
+						}
+						if !matches!(parse_source(ctx.create_source.read().as_str()), Some(FinanceSource::DebtPaidOff { .. })) {
+							input {
+								class: "border px-2 py-1 bg-transparent w-24",
+								placeholder: "target",
+								value: "{ctx.create_target.read()}",
+								oninput: { let mut t = ctx.create_target.clone(); move |e| t.set(e.value()) }
+							}
+						}
+					}
+				}
+			}
+
+            if !can_create {
+                p { class: "text-xs text-neutral-400",
+                    "Fill title + all SMART fields. If numeric, target must be a number."
+                }
+            }
+            for msg in draft_issues {
+                p { class: "text-xs text-amber-300", "⚠ {msg}" }
+            }
+            if ctx.create_metric_mode.read().as_str() == "finance" && ctx.finances.read().is_none() {
+                p { class: "text-xs text-neutral-400",
+                    "FinCalc balances aren't loaded; the value will fill in on the next refresh."
+                }
+            }
+        }
+    }
+}
+
+/// Title and SMART fields while a goal is being edited. The drafts live in
+/// the context; the tree item's Save button writes them back.
+#[component]
+pub(super) fn GoalEditForm() -> Element {
+    let ctx = use_context::<GoalsCtx>();
+
+    rsx! {
+        div { class: "space-y-2",
+            label { class: "text-xs text-neutral-300", "Title" }
+            input {
+                class: "border px-2 py-1 w-full bg-transparent",
+                value: "{ctx.draft_title.read()}",
+                oninput: { let mut s = ctx.draft_title.clone(); move |e| s.set(e.value()) }
+            }
+
+            label { class: "text-xs text-neutral-300", "S — Specific" }
+            textarea {
+                class: "border px-2 py-1 w-full bg-transparent",
+                rows: "2",
+                value: "{ctx.draft_s.read()}",
+                oninput: { let mut s = ctx.draft_s.clone(); move |e| s.set(e.value()) }
+            }
+
+            label { class: "text-xs text-neutral-300", "M — Measurable" }
+            textarea {
+                class: "border px-2 py-1 w-full bg-transparent",
+                rows: "2",
+                value: "{ctx.draft_m.read()}",
+                oninput: { let mut s = ctx.draft_m.clone(); move |e| s.set(e.value()) }
+            }
+
+            label { class: "text-xs text-neutral-300", "A — Achievable" }
+            textarea {
+                class: "border px-2 py-1 w-full bg-transparent",
+                rows: "2",
+                value: "{ctx.draft_a.read()}",
+                oninput: { let mut s = ctx.draft_a.clone(); move |e| s.set(e.value()) }
+            }
+
+            label { class: "text-xs text-neutral-300", "R — Relevant" }
+            textarea {
+                class: "border px-2 py-1 w-full bg-transparent",
+                rows: "2",
+                value: "{ctx.draft_r.read()}",
+                oninput: { let mut s = ctx.draft_r.clone(); move |e| s.set(e.value()) }
+            }
+
+            label { class: "text-xs text-neutral-300", "T — Time-bound" }
+            textarea {
+                class: "border px-2 py-1 w-full bg-transparent",
+                rows: "2",
+                value: "{ctx.draft_t.read()}",
+                oninput: { let mut s = ctx.draft_t.clone(); move |e| s.set(e.value()) }
+            }
+        }
+    }
+}
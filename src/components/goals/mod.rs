@@ -0,0 +1,11 @@
+mod component;
+mod editor;
+mod review;
+mod storage;
+mod store;
+mod templates;
+mod tree;
+
+pub use component::*;
+pub use review::GoalReview;
+pub use store::{use_goals, use_provide_goals, GoalsStore};
@@ -0,0 +1,155 @@
+//! The periodic review: the cadence setting, then every goal due for review,
+//! one card at a time.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::goals::review::{ReviewCadence, ReviewSchedule};
+use crate::Route;
+
+use super::component::{cat_label, journal_lines, local_today, metric_summary, parse_iso_date, status_class};
+use super::store::use_goals;
+
+/// The review screen: the cadence setting, then every goal due for review,
+/// one at a time. Marking one reviewed (or skipping it) moves to the next.
+#[component]
+pub fn GoalReview() -> Element {
+    let mut goals = use_goals().goals();
+    let today = local_today();
+    let skipped = use_signal(Vec::<Uuid>::new);
+
+    let (schedule, queue) = {
+        let g = goals.read();
+        let skipped = skipped.read();
+        let queue: Vec<Uuid> = g.due_for_review(today).into_iter().filter(|id| !skipped.contains(id)).collect();
+        (g.review.clone(), queue)
+    };
+    let remaining = queue.len();
+    let current = queue.first().copied();
+    let cadence_value = schedule.as_ref().map_or("", |s| s.cadence.label());
+    let start_value = schedule.as_ref().map(|s| s.start.to_string()).unwrap_or_default();
+    let since = schedule.as_ref().and_then(|s| s.last_due(today)).map(|d| d.to_string()).unwrap_or_default();
+
+    rsx! {
+        div { class: "flex flex-col gap-3 text-secondary-color",
+            div { class: "flex flex-wrap items-center gap-2 text-sm",
+                h2 { class: "text-2xl font-bold flex-1", "Goal review" }
+                label { class: "flex items-center gap-1",
+                    "Cadence"
+                    select {
+                        class: "border px-2 py-1 bg-transparent",
+                        value: "{cadence_value}",
+                        onchange: move |e: Event<FormData>| {
+                            let cadence = ReviewCadence::ALL.into_iter().find(|c| c.label() == e.value());
+                            let mut g = goals.write();
+                            g.review = match (cadence, g.review.take()) {
+                                (None, _) => None,
+                                (Some(c), Some(s)) => Some(ReviewSchedule { cadence: c, ..s }),
+                                (Some(c), None) => Some(ReviewSchedule::new(c, today)),
+                            };
+                        },
+                        option { value: "", "Off" }
+                        for c in ReviewCadence::ALL {
+                            option { value: "{c.label()}", "{c.label()}" }
+                        }
+                    }
+                }
+                if schedule.is_some() {
+                    label { class: "flex items-center gap-1",
+                        "Starting"
+                        input {
+                            r#type: "date",
+                            class: "border px-2 py-1 bg-transparent",
+                            value: "{start_value}",
+                            onchange: move |e: Event<FormData>| {
+                                let Some(d) = parse_iso_date(&e.value()) else { return; };
+                                if let Some(s) = goals.write().review.as_mut() {
+                                    s.start = d;
+                                }
+                            }
+                        }
+                    }
+                }
+                Link { class: "px-3 py-1 border rounded", to: Route::GoalView { focus: String::new() }, "Back to goals" }
+            }
+
+            if schedule.is_none() {
+                p { class: "text-sm text-neutral-500", "Pick a cadence to put reviews on the calendar." }
+            } else if let Some(id) = current {
+                p { class: "text-xs text-neutral-500", "{remaining} goal(s) due since {since}" }
+                ReviewCard { key: "{id}", id, skipped }
+            } else {
+                p { class: "text-sm text-neutral-500", "All caught up: nothing is due for review." }
+            }
+        }
+    }
+}
+
+/// One goal on the review screen: where it stands, its recent journal, and
+/// a note to file with the review.
+#[component]
+fn ReviewCard(id: Uuid, skipped: Signal<Vec<Uuid>>) -> Element {
+    let mut goals = use_goals().goals();
+    let mut note = use_signal(|| "".to_string());
+    let today = local_today();
+
+    let Some(node) = goals.read().find(id).cloned() else {
+        return rsx! { div {} };
+    };
+    let status = node.rolled_up_status(today);
+    let summary = metric_summary(&node.metric, None, today);
+    let pct = node.progress() * 100.0;
+    let deadline = node.deadline.map(|d| d.to_string());
+    let last_reviewed = node.last_reviewed().to_string();
+    let journal = journal_lines(&node, 8);
+
+    rsx! {
+        div { class: "border rounded p-4 flex flex-col gap-2",
+            div { class: "flex items-center gap-2",
+                span { class: "text-xs text-neutral-500", "{cat_label(node.category)}" }
+                span { class: "text-lg font-semibold flex-1", "{node.title}" }
+                span { class: status_class(status), "{status.label()}" }
+            }
+            p { class: "text-sm", "{node.smart.specific}" }
+            p { class: "text-xs text-neutral-400", "Measure: {node.smart.measurable} · By: {node.smart.time_bound}" }
+            if let Some(summary) = summary {
+                p { class: "text-xs text-neutral-300", "{summary} ({pct:.0}%)" }
+            }
+            if let Some(d) = deadline {
+                p { class: "text-xs text-neutral-400", "Deadline {d}" }
+            }
+            p { class: "text-xs text-neutral-500", "Last reviewed {last_reviewed}" }
+            div { class: "space-y-1 text-xs text-neutral-400",
+                for (when, text) in journal {
+                    div { class: "flex gap-2",
+                        span { class: "text-neutral-500 whitespace-nowrap", "{when}" }
+                        span { "{text}" }
+                    }
+                }
+            }
+            textarea {
+                class: "border px-2 py-1 bg-transparent text-sm",
+                rows: "3",
+                placeholder: "What changed, what's next?",
+                value: "{note.read()}",
+                oninput: move |e| note.set(e.value())
+            }
+            div { class: "flex gap-2",
+                button {
+                    class: "px-3 py-1 border rounded",
+                    onclick: move |_| {
+                        if let Some(n) = goals.write().find_mut(id) {
+                            n.mark_reviewed(&note.read(), time::OffsetDateTime::now_utc());
+                        }
+                    },
+                    "Reviewed"
+                }
+                button {
+                    class: "px-3 py-1 border rounded",
+                    onclick: move |_| skipped.write().push(id),
+                    "Skip"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Persistence for the goals module.
+//!
+//! Same convention as `time/storage.rs`: one JSON file in the platform data dir
+//! (`directories::ProjectDirs::from("com", "gauss", "momentum-dioxus")`), written
+//! through `utils::json_store`. `GoalsFile` carries its own `version`, so it's
+//! stored as-is.
+
+use std::path::PathBuf;
+
+use crate::models::goals::goals::{GoalsFile, DEFAULT_GOALS_PATH};
+use crate::utils::json_store::{load_json, save_json};
+
+/// `~/.local/share/momentum-dioxus/goals.json` on Linux (platform-appropriate
+/// elsewhere). Falls back to a bare relative path only if the OS dirs are
+/// somehow unavailable.
+fn goals_path() -> PathBuf {
+    if let Some(proj) = directories::ProjectDirs::from("com", "gauss", "momentum-dioxus") {
+        let mut p = proj.data_dir().to_path_buf();
+        p.push("goals.json");
+        return p;
+    }
+    PathBuf::from("goals.json")
+}
+
+/// Load saved goals. Before the first autosave there's no file in the data dir,
+/// so pick up the old in-repo `DEFAULT_GOALS_PATH` file if there is one;
+/// otherwise start empty.
+pub fn load() -> GoalsFile {
+    load_json::<GoalsFile>(goals_path())
+        .or_else(|_| load_json::<GoalsFile>(DEFAULT_GOALS_PATH))
+        .unwrap_or_default()
+}
+
+/// Best-effort save, like the time store's.
+pub fn save(goals: &GoalsFile) {
+    let _ = save_json(goals_path(), goals);
+}
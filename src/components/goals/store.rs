@@ -0,0 +1,69 @@
+//! The shared goals store.
+//!
+//! Provide it ONCE near the root of your app (after `use_provide_time`) with
+//! [`use_provide_goals`]. The Goals page and the Overview reach it with
+//! [`use_goals`]. Any mutation autosaves to JSON and re-syncs habit and review
+//! events into the `TimeStore`. Value, completion and archive changes are
+//! journaled by the edits themselves (`GoalsFile::journaled`).
+
+use dioxus::prelude::*;
+
+use crate::components::time::use_time;
+use crate::models::goals::goals::GoalsFile;
+use crate::models::goals::habit::is_habit_event;
+use crate::models::goals::review::is_review_event;
+
+use super::storage;
+
+/// Cheap, `Copy` handle to the goal tree. `PartialEq` so it can be a prop;
+/// `Signal` compares by identity.
+#[derive(Clone, Copy, PartialEq)]
+pub struct GoalsStore {
+    goals: Signal<GoalsFile>,
+}
+
+/// Call once, high in the tree (in `App`, before the `Router`). Loads
+/// persisted goals, provides the context, and wires autosave.
+pub fn use_provide_goals() -> GoalsStore {
+    let saved = use_hook(storage::load);
+    let goals = use_signal(|| saved.clone());
+
+    let store = GoalsStore { goals };
+    use_context_provider(|| store);
+
+    // Persist whenever the tree changes.
+    use_effect(move || storage::save(&goals.read()));
+
+    // Keep habit and review schedules on the shared calendar. Only those
+    // events are replaced; time blocks and anything filed under Goals by hand
+    // stay.
+    let time = use_time();
+    use_effect(move || {
+        let g = goals.read();
+        let mut fresh = g.habit_events();
+        fresh.extend(g.review_event());
+        time.resync(|e| is_habit_event(e) || is_review_event(e), fresh);
+    });
+
+    store
+}
+
+/// Grab the shared goals from any descendant component.
+pub fn use_goals() -> GoalsStore {
+    use_context::<GoalsStore>()
+}
+
+impl GoalsStore {
+    /// Raw signal access: read to render, write to edit (autosaves either way).
+    /// Wrap edits that can change values, completion or archiving in
+    /// `GoalsFile::journaled` so they're journaled.
+    pub fn goals(&self) -> Signal<GoalsFile> {
+        self.goals
+    }
+
+    /// Swap in a whole file (import, seeding).
+    pub fn replace(&self, file: GoalsFile) {
+        let mut goals = self.goals;
+        goals.set(file);
+    }
+}
@@ -0,0 +1,35 @@
+//! Starter goal trees, added as a new root from the toolbar.
+
+use dioxus::prelude::*;
+
+use crate::models::goals::templates::{self, TEMPLATES};
+
+use super::component::{cat_label, local_today, GoalsCtx};
+
+/// Picking a template adds its whole tree as a new root.
+#[component]
+pub(super) fn TemplatePicker() -> Element {
+    let ctx = use_context::<GoalsCtx>();
+
+    let on_add_template = {
+        let mut goals_state = ctx.goals_state.clone();
+        let mut status = ctx.status.clone();
+        move |e: Event<FormData>| {
+            let Some(t) = templates::find(&e.value()) else { return; };
+            goals_state.write().roots.push(t.instantiate(local_today()));
+            status.set(Some(format!("Added \"{}\" from a template; adjust the numbers to fit.", t.name)));
+        }
+    };
+
+    rsx! {
+        select {
+            class: "border px-2 py-1 bg-transparent",
+            value: "",
+            onchange: on_add_template,
+            option { value: "", "From template…" }
+            for t in TEMPLATES {
+                option { key: "{t.key}", value: "{t.key}", "{cat_label(t.category)}: {t.name}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,941 @@
+//! The goal tree itself: the Active/Archived switch, one accordion item per
+//! goal, and each item's metric, progress, links and tree edits.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use dioxus_primitives::checkbox::CheckboxState;
+
+use crate::components::accordion::{Accordion, AccordionContent, AccordionItem, AccordionTrigger};
+use crate::components::checkbox::Checkbox;
+
+use crate::components::time::use_time;
+use crate::models::goals::links::{LinkTarget, JAXBRAIN_PREFIX};
+use crate::models::goals::timeblock::{week_of, TimeBudget};
+use crate::models::goals::goals::{ContextLink, GoalNode, Metric};
+use crate::utils::open::open_external;
+use crate::Route;
+
+use super::component::{
+    cat_label, journal_lines, load_jax_nodes, local_today, metric_summary, parse_iso_date, status_class, GoalsCtx,
+};
+use super::editor::{clear_create_form, GoalEditForm};
+
+/// The goal list, live or archived, under its header and link warnings.
+#[component]
+pub(super) fn GoalTree() -> Element {
+    let ctx = use_context::<GoalsCtx>();
+
+    // stable indices across roots; the archived view lists each shelved
+    // subtree once, wherever it sits
+    let show_archived_now = *ctx.show_archived.read();
+    let archived_count = ctx.goals_state.read().archived().len();
+    let broken_links = ctx.goals_state.read().broken_link_count(&ctx.known_links.read());
+    let root_items = {
+        let g = ctx.goals_state.read();
+        let roots: Vec<&GoalNode> = if show_archived_now {
+            g.archived()
+        } else {
+            g.roots.iter().filter(|r| !r.archived).collect()
+        };
+        let mut items: Vec<(Uuid, usize)> = vec![];
+        let mut next = 0usize;
+        for r in roots {
+            items.push((r.id, next));
+            next += subtree_size(r);
+        }
+        items
+    };
+
+    rsx! {
+        div { class: "border rounded p-4 w-full",
+            div { class: "flex items-center gap-2 mb-3",
+                h2 { class: "text-2xl font-bold flex-1",
+                    if show_archived_now { "Archived goals" } else { "Goals" }
+                }
+                if broken_links > 0 {
+                    span { class: "text-xs px-2 rounded border border-red-600 text-red-400", "{broken_links} broken link(s)" }
+                }
+                button {
+                    class: if show_archived_now { "px-2 py-1 border rounded text-xs" } else { "px-2 py-1 border rounded text-xs bg-neutral-800" },
+                    onclick: { let mut s = ctx.show_archived.clone(); move |_| s.set(false) },
+                    "Active"
+                }
+                button {
+                    class: if show_archived_now { "px-2 py-1 border rounded text-xs bg-neutral-800" } else { "px-2 py-1 border rounded text-xs" },
+                    onclick: { let mut s = ctx.show_archived.clone(); move |_| s.set(true) },
+                    "Archived ({archived_count})"
+                }
+            }
+
+            Accordion {
+                id: "goals-accordion".to_string(),
+                allow_multiple_open: true,
+                collapsible: true,
+
+                for (id, idx) in root_items {
+                    GoalTreeItem { key: "{id}", id, depth: 0, index: idx }
+                }
+            }
+
+            p { class: "text-xs text-neutral-500 pt-3",
+                "Roots: {ctx.goals_state.read().roots.len()} (saved automatically)"
+            }
+        }
+    }
+}
+
+#[component]
+fn GoalTreeItem(id: Uuid, depth: usize, index: usize) -> Element {
+    let ctx = use_context::<GoalsCtx>();
+
+    let node_opt = {
+        let g = ctx.goals_state.read();
+        find_node(&g.roots, id).cloned()
+    };
+
+    let Some(node) = node_opt else {
+        return rsx! { div {} };
+    };
+
+    let is_editing = *ctx.editing_id.read() == Some(id);
+
+    // primitives expects ReadSignal<Option<CheckboxState>>
+    let done_now = is_done(&node);
+    let mut checked_sig: Signal<Option<CheckboxState>> = use_signal(|| {
+        Some(if done_now { CheckboxState::Checked } else { CheckboxState::Unchecked })
+    });
+
+    // keep checkbox synced with model
+    {
+        let mut checked_sig = checked_sig.clone();
+        let desired = Some(if done_now { CheckboxState::Checked } else { CheckboxState::Unchecked });
+        use_effect(move || {
+            if *checked_sig.read() != desired {
+                checked_sig.set(desired);
+            }
+        });
+    }
+
+    // children indices (archived subgoals only show inside the archived view)
+    let show_archived = *ctx.show_archived.read();
+    let child_items = {
+        let mut v: Vec<(Uuid, usize)> = vec![];
+        let mut next = index + 1;
+        for c in &node.children {
+            if show_archived || !c.archived {
+                v.push((c.id, next));
+            }
+            next += subtree_size(c);
+        }
+        v
+    };
+
+    let indent_px = (depth * 14) as i32;
+    let cat = cat_label(node.category);
+    let time_hint = node.smart.time_bound.clone();
+    let today = local_today();
+    let progress = metric_summary(&node.metric, ctx.finances.read().as_ref(), today);
+    let status = node.rolled_up_status(today);
+    let own_status = node.status(today);
+    let forecast_note = match (node.forecast(), node.metric.values()) {
+        (Some(f), _) => match f.predicted {
+            Some(p) => format!("Trend reaches the target around {} ({:+.2} / day)", p, f.slope_per_day),
+            None => format!("Trend is flat or moving away from the target ({:+.2} / day)", f.slope_per_day),
+        },
+        (None, Some(_)) => "Log at least two days of progress to see a forecast".to_string(),
+        (None, None) => String::new(),
+    };
+    let deadline_value = node.deadline.map(|d| d.to_string()).unwrap_or_default();
+    let mut log_value = use_signal(|| "".to_string());
+    let mut confirm_delete = use_signal(|| false);
+    let move_options = {
+        let mut v = vec![];
+        move_targets(&ctx.goals_state.read().roots, id, 0, &mut v);
+        v
+    };
+    let archived = node.archived;
+    let recent_log: Vec<(time::Date, f64)> =
+        node.progress.iter().rev().take(5).map(|p| (p.date, p.value)).collect();
+    let has_values = node.metric.values().is_some();
+    let smart_issues: Vec<&'static str> = node.lint(today).iter().map(|i| i.message()).collect();
+    let habit_stats = node.metric.habit_stats(today);
+    let checked_in_today = matches!(&node.metric, Metric::Habit { checkins, .. } if checkins.contains(&today));
+    // time blocking: this week's blocks and what's been logged
+    let scheduler = use_time();
+    let now = chrono::Local::now().naive_local();
+    let week_start = week_of(now.date());
+    let week_occs = scheduler.occurrences_in(week_start, week_start + chrono::Duration::days(6));
+    let scheduled_hours = node.scheduled_hours(&week_occs);
+    let budget_hours = node.time_budget.map(|b| b.hours_per_week.to_string()).unwrap_or_default();
+    let block_minutes = node.time_budget.map(|b| b.block_minutes.to_string()).unwrap_or_default();
+    let hours_logged = node.hours_logged();
+    let mut time_value = use_signal(|| "".to_string());
+    // context links, with the ones whose target is gone flagged
+    let nav = navigator();
+    let links: Vec<(usize, String, String, String, Option<LinkTarget>, bool)> = {
+        let known = ctx.known_links.read();
+        node.context_links
+            .iter()
+            .enumerate()
+            .map(|(i, l)| (i, l.label.clone(), l.kind.clone(), l.value.clone(), l.target(), l.is_broken(&known)))
+            .collect()
+    };
+    let mut link_label = use_signal(|| "".to_string());
+    let mut link_kind = use_signal(|| "path".to_string());
+    let mut link_value = use_signal(|| "".to_string());
+    let recent_journal = journal_lines(&node, 5);
+    let mut note_value = use_signal(|| "".to_string());
+    let recent_periods: Vec<(time::Date, u32, u32, bool)> = habit_stats
+        .as_ref()
+        .map(|s| s.periods.iter().rev().take(8).rev().map(|p| (p.start, p.done, p.scheduled, p.complete())).collect())
+        .unwrap_or_default();
+    let pct = node.progress() * 100.0;
+    let bar_style = format!("width: {:.0}%;", pct);
+    let weight_value = node.weight.map(|w| w.to_string()).unwrap_or_default();
+	let parent_title = node.title.clone();
+    let parent_category = node.category;
+    // Add subgoal button
+     let on_open_create_sub = {
+        let mut ctx = ctx.clone();
+        move |_| {
+            ctx.create_parent.set(Some(id));
+            ctx.create_open.set(true);
+            // Use the copied category
+            ctx.create_category.set(parent_category);
+            clear_create_form(&ctx);
+            // Use the cloned title
+            ctx.status.set(Some(format!("Creating a SUBGOAL under \"{}\".", parent_title)));
+        }
+    };
+
+    // Open the deep-linked goal and everything above it.
+    let focused = ctx.focus == Some(id);
+    let open_for_focus = ctx.focus.is_some_and(|f| node.contains(f));
+
+    rsx! {
+        div { class: "w-full",
+            div {
+                class: if focused { "flex items-start w-full rounded ring-1 ring-blue-500" } else { "flex items-start w-full" },
+                style: format!("padding-left: {}px;", indent_px),
+
+                if depth > 0 {
+                    div { class: "mr-2 mt-2",
+                        div { class: "w-3 h-3 border-l border-b border-neutral-700" }
+                    }
+                }
+
+                AccordionItem {
+                    index: index,
+                    default_open: open_for_focus,
+                    AccordionTrigger {
+                        div { class: "flex items-center gap-3 w-full pb-2 border-b-1",
+
+                            Checkbox {
+                                // ✅ FIX: no read_only(); just convert Signal -> ReadSignal
+                                checked: checked_sig,
+                                on_checked_change: {
+                                    let mut checked_sig = checked_sig.clone();
+                                    let mut goals_state = ctx.goals_state.clone();
+                                    let mut status = ctx.status.clone();
+
+                                    Callback::new(move |state: CheckboxState| {
+                                        checked_sig.set(Some(state));
+                                        let new_done = matches!(state, CheckboxState::Checked);
+
+                                        goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| {
+                                            if let Some(n) = g.find_mut(id) {
+                                                set_done(n, new_done);
+                                                status.set(Some("Updated completion.".into()));
+                                            }
+                                            if g.apply_auto_complete() > 0 {
+                                                status.set(Some("Updated completion; finished parents were completed too.".into()));
+                                            }
+                                        });
+                                    })
+                                }
+                            }
+
+                            div { class: "flex-1 text-center font-semibold",
+                                "{node.title}"
+                            }
+
+                            if let Some(p) = progress {
+                                div { class: "text-xs text-neutral-300 whitespace-nowrap", "{p}" }
+                            }
+
+                            div { class: "w-24 h-1.5 rounded bg-neutral-800 overflow-hidden", title: "{pct:.0}% including subgoals",
+                                div { class: "h-full bg-blue-500", style: "{bar_style}" }
+                            }
+
+                            span { class: status_class(status), title: "Own status: {own_status.label()}", "{status.label()}" }
+
+                            div { class: "text-xs text-neutral-400 whitespace-nowrap",
+                                "{cat} • {time_hint}"
+                            }
+                        }
+                    }
+
+                    AccordionContent {
+                        div { class: "space-y-2 text-sm overflow-auto ",
+
+                            div { class: "flex gap-2 flex-wrap justify-evenly",
+                                button {
+                                    class: "px-2 py-1 border rounded text-xs",
+                                    onclick: on_open_create_sub,
+                                    "+ Subgoal"
+                                }
+
+                                button {
+                                    class: "px-2 py-1 border rounded text-xs",
+                                    title: "Move up",
+                                    onclick: { let mut goals_state = ctx.goals_state.clone(); move |_| { goals_state.write().shift(id, -1); } },
+                                    "↑"
+                                }
+                                button {
+                                    class: "px-2 py-1 border rounded text-xs",
+                                    title: "Move down",
+                                    onclick: { let mut goals_state = ctx.goals_state.clone(); move |_| { goals_state.write().shift(id, 1); } },
+                                    "↓"
+                                }
+
+                                select {
+                                    class: "border px-2 py-1 bg-transparent text-xs",
+                                    value: "",
+                                    onchange: {
+                                        let mut goals_state = ctx.goals_state.clone();
+                                        let mut status = ctx.status.clone();
+                                        move |e: Event<FormData>| {
+                                            let v = e.value();
+                                            let parent = match v.as_str() {
+                                                "" => return,
+                                                "root" => None,
+                                                other => match Uuid::parse_str(other) {
+                                                    Ok(p) => Some(p),
+                                                    Err(_) => return,
+                                                },
+                                            };
+                                            // Lands after the new parent's existing subgoals.
+                                            let res = goals_state.write().move_node(id, parent, usize::MAX);
+                                            match res {
+                                                Ok(()) => status.set(Some("Moved goal.".into())),
+                                                Err(e) => status.set(Some(e.to_string())),
+                                            }
+                                        }
+                                    },
+                                    option { value: "", "Move under…" }
+                                    option { value: "root", "Top level" }
+                                    for (tid, label) in move_options {
+                                        option { key: "{tid}", value: "{tid}", "{label}" }
+                                    }
+                                }
+
+                                button {
+                                    class: "px-2 py-1 border rounded text-xs",
+                                    onclick: {
+                                        let mut goals_state = ctx.goals_state.clone();
+                                        let mut status = ctx.status.clone();
+                                        move |_| {
+                                            let res = goals_state.write().duplicate(id);
+                                            match res {
+                                                Ok(_) => status.set(Some("Duplicated goal and its subgoals.".into())),
+                                                Err(e) => status.set(Some(e.to_string())),
+                                            }
+                                        }
+                                    },
+                                    "Duplicate"
+                                }
+
+                                button {
+                                    class: "px-2 py-1 border rounded text-xs",
+                                    onclick: {
+                                        let mut goals_state = ctx.goals_state.clone();
+                                        let mut status = ctx.status.clone();
+                                        move |_| {
+                                            let res = goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| g.set_archived(id, !archived));
+                                            match res {
+                                                Ok(()) if archived => status.set(Some("Restored goal.".into())),
+                                                Ok(()) => status.set(Some("Archived goal (see the Archived view).".into())),
+                                                Err(e) => status.set(Some(e.to_string())),
+                                            }
+                                        }
+                                    },
+                                    if archived { "Restore" } else { "Archive" }
+                                }
+
+                                if *confirm_delete.read() {
+                                    button {
+                                        class: "px-2 py-1 border rounded text-xs border-red-600 text-red-400",
+                                        onclick: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            let mut status = ctx.status.clone();
+                                            let mut editing_id = ctx.editing_id.clone();
+                                            move |_| {
+                                                if goals_state.write().remove(id).is_some() {
+                                                    if *editing_id.read() == Some(id) {
+                                                        editing_id.set(None);
+                                                    }
+                                                    status.set(Some("Deleted goal and its subgoals.".into()));
+                                                }
+                                            }
+                                        },
+                                        "Delete with subgoals"
+                                    }
+                                    button {
+                                        class: "px-2 py-1 border rounded text-xs",
+                                        onclick: move |_| confirm_delete.set(false),
+                                        "Keep"
+                                    }
+                                } else {
+                                    button {
+                                        class: "px-2 py-1 border rounded text-xs",
+                                        onclick: move |_| confirm_delete.set(true),
+                                        "Delete"
+                                    }
+                                }
+
+                                if !is_editing {
+                                    button {
+                                        class: "px-2 py-1 border rounded text-xs",
+                                        onclick: {
+                                            let mut editing_id = ctx.editing_id.clone();
+                                            let mut draft_title = ctx.draft_title.clone();
+                                            let mut draft_s = ctx.draft_s.clone();
+                                            let mut draft_m = ctx.draft_m.clone();
+                                            let mut draft_a = ctx.draft_a.clone();
+                                            let mut draft_r = ctx.draft_r.clone();
+                                            let mut draft_t = ctx.draft_t.clone();
+
+                                            let node = node.clone();
+                                            move |_| {
+                                                editing_id.set(Some(id));
+                                                draft_title.set(node.title.clone());
+                                                draft_s.set(node.smart.specific.clone());
+                                                draft_m.set(node.smart.measurable.clone());
+                                                draft_a.set(node.smart.achievable.clone());
+                                                draft_r.set(node.smart.relevant.clone());
+                                                draft_t.set(node.smart.time_bound.clone());
+                                            }
+                                        },
+                                        "Edit"
+                                    }
+                                } else {
+                                    button {
+                                        class: "px-2 py-1 border rounded text-xs",
+                                        onclick: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            let mut status = ctx.status.clone();
+                                            let mut editing_id = ctx.editing_id.clone();
+
+                                            let draft_title = ctx.draft_title.clone();
+                                            let draft_s = ctx.draft_s.clone();
+                                            let draft_m = ctx.draft_m.clone();
+                                            let draft_a = ctx.draft_a.clone();
+                                            let draft_r = ctx.draft_r.clone();
+                                            let draft_t = ctx.draft_t.clone();
+
+                                            move |_| {
+                                                if let Some(n) = goals_state.write().find_mut(id) {
+                                                    n.title = draft_title.read().trim().to_string();
+                                                    n.smart.specific = draft_s.read().clone();
+                                                    n.smart.measurable = draft_m.read().clone();
+                                                    n.smart.achievable = draft_a.read().clone();
+                                                    n.smart.relevant = draft_r.read().clone();
+                                                    n.smart.time_bound = draft_t.read().clone();
+                                                    n.touch();
+
+                                                    editing_id.set(None);
+                                                    status.set(Some("Updated goal.".into()));
+                                                }
+                                            }
+                                        },
+                                        "Save"
+                                    }
+
+                                    button {
+                                        class: "px-2 py-1 border rounded text-xs",
+                                        onclick: {
+                                            let mut editing_id = ctx.editing_id.clone();
+                                            move |_| editing_id.set(None)
+                                        },
+                                        "Cancel"
+                                    }
+                                }
+                            }
+
+                            if is_editing {
+                                GoalEditForm {}
+                            } else {
+								div {
+									class:"flex justify-center",
+									div { class: "space-y-2 max-w-90 ",
+										p { span { class: "font-extrabold", "S: " } "{node.smart.specific}" }
+										p { span { class: "font-extrabold", "M: " } "{node.smart.measurable}" }
+										p { span { class: "font-extrabold", "A: " } "{node.smart.achievable}" }
+										p { span { class: "font-extrabold", "R: " } "{node.smart.relevant}" }
+										p { span { class: "font-extrabold", "T: " } "{node.smart.time_bound}" }
+										for msg in smart_issues {
+											p { class: "text-xs text-amber-300", "⚠ {msg}" }
+										}
+									}
+								}
+                            }
+
+                            div { class: "flex flex-wrap items-center gap-2 text-xs text-neutral-400",
+                                label { class: "flex items-center gap-1",
+                                    "Deadline"
+                                    input {
+                                        r#type: "date",
+                                        class: "border px-2 py-0.5 bg-transparent",
+                                        value: "{deadline_value}",
+                                        onchange: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            let mut status = ctx.status.clone();
+                                            move |e: Event<FormData>| {
+                                                if let Some(n) = goals_state.write().find_mut(id) {
+                                                    n.deadline = parse_iso_date(&e.value());
+                                                    n.touch();
+                                                    status.set(Some("Updated deadline.".into()));
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                if !forecast_note.is_empty() {
+                                    span { "{forecast_note}" }
+                                }
+                                if depth > 0 {
+                                    label { class: "flex items-center gap-1",
+                                        "Weight"
+                                        input {
+                                            r#type: "number",
+                                            min: "0",
+                                            step: "0.5",
+                                            class: "border px-2 py-0.5 bg-transparent w-16",
+                                            placeholder: "1",
+                                            value: "{weight_value}",
+                                            onchange: {
+                                                let mut goals_state = ctx.goals_state.clone();
+                                                move |e: Event<FormData>| {
+                                                    if let Some(n) = goals_state.write().find_mut(id) {
+                                                        n.weight = e.value().trim().parse::<f64>().ok().map(|w| w.max(0.0));
+                                                        n.touch();
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if has_values {
+                                div { class: "flex flex-wrap items-center gap-2 text-xs",
+                                    input {
+                                        class: "border px-2 py-0.5 bg-transparent w-24",
+                                        placeholder: "value today",
+                                        value: "{log_value.read()}",
+                                        oninput: move |e| log_value.set(e.value())
+                                    }
+                                    button {
+                                        class: "px-2 py-0.5 border rounded",
+                                        onclick: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            let mut status = ctx.status.clone();
+                                            move |_| {
+                                                let Ok(v) = log_value.read().trim().parse::<f64>() else { return; };
+                                                goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| {
+                                                    if let Some(n) = g.find_mut(id) {
+                                                        n.log_progress(today, v);
+                                                        status.set(Some("Logged progress.".into()));
+                                                    }
+                                                });
+                                                log_value.set("".into());
+                                            }
+                                        },
+                                        "Log progress"
+                                    }
+                                    for (date, value) in recent_log {
+                                        span { key: "{date}", class: "px-1 border border-neutral-800 rounded text-neutral-400",
+                                            "{date}: {value}"
+                                            button {
+                                                class: "pl-1 text-red-400",
+                                                onclick: {
+                                                    let mut goals_state = ctx.goals_state.clone();
+                                                    move |_| {
+                                                        goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| {
+                                                            if let Some(n) = g.find_mut(id) {
+                                                                n.remove_progress(date);
+                                                            }
+                                                        });
+                                                    }
+                                                },
+                                                "✕"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if habit_stats.is_some() {
+                                div { class: "flex flex-wrap items-center gap-2 text-xs",
+                                    button {
+                                        class: if checked_in_today { "px-2 py-0.5 border rounded border-green-600 text-green-400" } else { "px-2 py-0.5 border rounded" },
+                                        onclick: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            let mut status = ctx.status.clone();
+                                            move |_| {
+                                                goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| {
+                                                    if let Some(n) = g.find_mut(id) {
+                                                        let msg = if n.toggle_check_in(today) { "Checked in for today." } else { "Removed today's check-in." };
+                                                        status.set(Some(msg.into()));
+                                                    }
+                                                });
+                                            }
+                                        },
+                                        if checked_in_today { "Done today ✓" } else { "Check in today" }
+                                    }
+                                    for (start, done, scheduled, complete) in recent_periods {
+                                        span {
+                                            key: "{start}",
+                                            class: if complete { "px-1 border border-green-700 rounded text-green-400" } else { "px-1 border border-neutral-800 rounded text-neutral-400" },
+                                            title: "Period from {start}",
+                                            "{done}/{scheduled}"
+                                        }
+                                    }
+                                }
+                            }
+
+                            div { class: "flex flex-wrap items-center gap-2 text-xs text-neutral-400",
+                                label { class: "flex items-center gap-1",
+                                    "Time budget"
+                                    input {
+                                        r#type: "number",
+                                        min: "0",
+                                        step: "0.5",
+                                        class: "border px-2 py-0.5 bg-transparent w-16",
+                                        placeholder: "h/week",
+                                        value: "{budget_hours}",
+                                        onchange: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            move |e: Event<FormData>| {
+                                                if let Some(n) = goals_state.write().find_mut(id) {
+                                                    let block = n.time_budget.map_or(60, |b| b.block_minutes);
+                                                    n.time_budget = e
+                                                        .value()
+                                                        .trim()
+                                                        .parse::<f64>()
+                                                        .ok()
+                                                        .filter(|h| *h > 0.0)
+                                                        .map(|hours_per_week| TimeBudget { hours_per_week, block_minutes: block });
+                                                    n.touch();
+                                                }
+                                            }
+                                        }
+                                    }
+                                    "h/week in"
+                                    input {
+                                        r#type: "number",
+                                        min: "30",
+                                        step: "30",
+                                        class: "border px-2 py-0.5 bg-transparent w-16",
+                                        placeholder: "60",
+                                        value: "{block_minutes}",
+                                        onchange: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            move |e: Event<FormData>| {
+                                                let Ok(m) = e.value().trim().parse::<u32>() else { return; };
+                                                if let Some(n) = goals_state.write().find_mut(id) {
+                                                    if let Some(b) = n.time_budget.as_mut() {
+                                                        b.block_minutes = m.max(30);
+                                                        n.touch();
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    "min blocks"
+                                }
+                                if node.time_budget.is_some() {
+                                    span { "This week: {scheduled_hours:.1} h scheduled" }
+                                    button {
+                                        class: "px-2 py-0.5 border rounded",
+                                        onclick: {
+                                            let goals_state = ctx.goals_state.clone();
+                                            let mut status = ctx.status.clone();
+                                            let week_occs = week_occs.clone();
+                                            move |_| {
+                                                let now = chrono::Local::now().naive_local();
+                                                let blocks = match find_node(&goals_state.read().roots, id) {
+                                                    Some(n) => n.plan_blocks(&week_occs, week_start, now),
+                                                    None => return,
+                                                };
+                                                let count = blocks.len();
+                                                for ev in blocks {
+                                                    scheduler.add_event(ev);
+                                                }
+                                                status.set(Some(if count == 0 {
+                                                    "Nothing to schedule: the budget is covered or there's no free time left this week.".into()
+                                                } else {
+                                                    format!("Scheduled {} block(s) on the calendar.", count)
+                                                }));
+                                            }
+                                        },
+                                        "Plan this week"
+                                    }
+                                }
+                                span { "Logged: {hours_logged:.1} h" }
+                                button {
+                                    class: "px-2 py-0.5 border rounded",
+                                    title: "Log every finished calendar block for this goal from the last 8 weeks",
+                                    onclick: {
+                                        let mut goals_state = ctx.goals_state.clone();
+                                        let mut status = ctx.status.clone();
+                                        move |_| {
+                                            let now = chrono::Local::now().naive_local();
+                                            let occs = scheduler.occurrences_in(now.date() - chrono::Duration::weeks(8), now.date());
+                                            goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| {
+                                                if let Some(n) = g.find_mut(id) {
+                                                    let logged = n.log_finished_blocks(&occs, now);
+                                                    status.set(Some(format!("Logged {} finished block(s).", logged)));
+                                                }
+                                            });
+                                        }
+                                    },
+                                    "Log finished blocks"
+                                }
+                                input {
+                                    class: "border px-2 py-0.5 bg-transparent w-16",
+                                    placeholder: "hours",
+                                    value: "{time_value.read()}",
+                                    oninput: move |e| time_value.set(e.value())
+                                }
+                                button {
+                                    class: "px-2 py-0.5 border rounded",
+                                    onclick: {
+                                        let mut goals_state = ctx.goals_state.clone();
+                                        let mut status = ctx.status.clone();
+                                        move |_| {
+                                            let Ok(h) = time_value.read().trim().parse::<f64>() else { return; };
+                                            goals_state.write().journaled(time::OffsetDateTime::now_utc(), |g| {
+                                                if let Some(n) = g.find_mut(id) {
+                                                    n.log_time(today, h, None);
+                                                    status.set(Some("Logged time.".into()));
+                                                }
+                                            });
+                                            time_value.set("".into());
+                                        }
+                                    },
+                                    "Log time"
+                                }
+                            }
+
+                            div { class: "space-y-1 text-xs text-neutral-400",
+                                for (i, label, kind, value, target, broken) in links {
+                                    div { key: "{i}", class: "flex flex-wrap items-center gap-2",
+                                        span { class: "px-1 border border-neutral-800 rounded", "{kind}" }
+                                        if !label.is_empty() {
+                                            span { class: "text-neutral-200", "{label}" }
+                                        }
+                                        span { class: "font-mono break-all", "{value}" }
+                                        if broken {
+                                            span { class: "px-1 border border-red-600 rounded text-red-400", title: "The file, event or JaxBrain node this points at no longer exists", "missing" }
+                                        }
+                                        if let Some(target) = target.filter(|t| !matches!(t, LinkTarget::Note(_))) {
+                                            button {
+                                                class: "px-2 py-0.5 border rounded",
+                                                onclick: {
+                                                    let status = ctx.status.clone();
+                                                    move |_| follow_link(target.clone(), nav, status)
+                                                },
+                                                "Open"
+                                            }
+                                        }
+                                        button {
+                                            class: "px-2 py-0.5 border rounded",
+                                            title: "Remove link",
+                                            onclick: {
+                                                let mut goals_state = ctx.goals_state.clone();
+                                                move |_| {
+                                                    if let Some(n) = goals_state.write().find_mut(id) {
+                                                        if i < n.context_links.len() {
+                                                            n.context_links.remove(i);
+                                                            n.touch();
+                                                        }
+                                                    }
+                                                }
+                                            },
+                                            "✕"
+                                        }
+                                    }
+                                }
+                                div { class: "flex flex-wrap items-center gap-2",
+                                    select {
+                                        class: "border px-2 py-0.5 bg-transparent",
+                                        value: "{link_kind.read()}",
+                                        onchange: move |e| link_kind.set(e.value()),
+                                        option { value: "path", "path" }
+                                        option { value: "url", "url" }
+                                        option { value: "map", "map" }
+                                        option { value: "note", "note" }
+                                    }
+                                    input {
+                                        class: "border px-2 py-0.5 bg-transparent w-24",
+                                        placeholder: "label",
+                                        value: "{link_label.read()}",
+                                        oninput: move |e| link_label.set(e.value())
+                                    }
+                                    input {
+                                        class: "border px-2 py-0.5 bg-transparent flex-1 min-w-40",
+                                        placeholder: "file path, URL, jaxbrain:term:xyz or time:<event id>",
+                                        value: "{link_value.read()}",
+                                        oninput: move |e| link_value.set(e.value())
+                                    }
+                                    button {
+                                        class: "px-2 py-0.5 border rounded",
+                                        onclick: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            let mut jax_nodes = ctx.jax_nodes.clone();
+                                            move |_| {
+                                                let value = link_value.read().trim().to_string();
+                                                if value.is_empty() {
+                                                    return;
+                                                }
+                                                if value.starts_with(JAXBRAIN_PREFIX) && jax_nodes.peek().is_none() {
+                                                    jax_nodes.set(load_jax_nodes());
+                                                }
+                                                if let Some(n) = goals_state.write().find_mut(id) {
+                                                    n.context_links.push(ContextLink {
+                                                        label: link_label.read().trim().to_string(),
+                                                        kind: link_kind.read().clone(),
+                                                        value,
+                                                    });
+                                                    n.touch();
+                                                }
+                                                link_label.set("".into());
+                                                link_value.set("".into());
+                                            }
+                                        },
+                                        "Add link"
+                                    }
+                                }
+                            }
+
+                            div { class: "space-y-1 text-xs text-neutral-400",
+                                for (when, text) in recent_journal {
+                                    div { class: "flex gap-2",
+                                        span { class: "text-neutral-500 whitespace-nowrap", "{when}" }
+                                        span { "{text}" }
+                                    }
+                                }
+                                div { class: "flex items-center gap-2",
+                                    input {
+                                        class: "border px-2 py-0.5 bg-transparent flex-1",
+                                        placeholder: "Journal note: why it changed, what you learned",
+                                        value: "{note_value.read()}",
+                                        oninput: move |e| note_value.set(e.value())
+                                    }
+                                    button {
+                                        class: "px-2 py-0.5 border rounded",
+                                        onclick: {
+                                            let mut goals_state = ctx.goals_state.clone();
+                                            move |_| {
+                                                if let Some(n) = goals_state.write().find_mut(id) {
+                                                    n.add_note(&note_value.read(), time::OffsetDateTime::now_utc());
+                                                }
+                                                note_value.set("".into());
+                                            }
+                                        },
+                                        "Add note"
+                                    }
+                                }
+                            }
+
+                            if !child_items.is_empty() {
+                                div { class: "pt-2 border-t border-neutral-800 space-y-2 ",
+                                    for (cid, cidx) in child_items {
+                                        GoalTreeItem { key: "{cid}", id: cid, depth: depth + 1, index: cidx }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_done(node: &GoalNode) -> bool {
+    if node.completed {
+        return true;
+    }
+    match &node.metric {
+        Metric::Boolean { done } => *done,
+        Metric::Numeric { current, target, .. } => current >= target,
+        Metric::Finance { current, target, .. } => current >= target,
+        Metric::Habit { .. } => false,
+    }
+}
+
+fn set_done(node: &mut GoalNode, done: bool) {
+    node.completed = done;
+    match &mut node.metric {
+        Metric::Boolean { done: d } => *d = done,
+        Metric::Numeric { current, target, .. } => {
+            *current = if done { *target } else { 0.0 };
+        }
+        Metric::Finance { .. } | Metric::Habit { .. } => {}
+    }
+    node.touch();
+}
+
+fn find_node<'a>(nodes: &'a [GoalNode], id: Uuid) -> Option<&'a GoalNode> {
+    for n in nodes {
+        if n.id == id {
+            return Some(n);
+        }
+        if let Some(found) = find_node(&n.children, id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn subtree_size(node: &GoalNode) -> usize {
+    let mut total = 1;
+    for c in &node.children {
+        total += subtree_size(c);
+    }
+    total
+}
+
+/// Every node outside `skip`'s subtree, depth-first, for the "Move under" list.
+fn move_targets(nodes: &[GoalNode], skip: Uuid, depth: usize, out: &mut Vec<(Uuid, String)>) {
+    for n in nodes {
+        if n.id == skip {
+            continue;
+        }
+        out.push((n.id, format!("{}{}", "— ".repeat(depth), n.title)));
+        move_targets(&n.children, skip, depth + 1, out);
+    }
+}
+
+/// Files and URLs go to the OS; JaxBrain nodes and events open in their page.
+fn follow_link(target: LinkTarget, nav: Navigator, mut status: Signal<Option<String>>) {
+    let external = match target {
+        LinkTarget::Path(p) => p.to_string_lossy().to_string(),
+        LinkTarget::Url(u) => u,
+        LinkTarget::JaxBrain(focus) => {
+            let _ = nav.push(Route::JaxBrainView { focus });
+            return;
+        }
+        LinkTarget::Event(id) => {
+            let _ = nav.push(Route::TimeView { event: id.to_string() });
+            return;
+        }
+        LinkTarget::Note(_) => return,
+    };
+    if let Err(e) = open_external(&external) {
+        status.set(Some(format!("Couldn't open {}: {}", external, e)));
+    }
+}
@@ -42,11 +42,15 @@ pub fn GraphGenerator(
 
     #[props(default)]
     title: Option<String>,
+
+    /// Node to start pinned on (deep links).
+    #[props(default)]
+    focus: Option<String>,
 ) -> Element {
     // hovered node for preview
     let mut hovered_id = use_signal(|| None::<String>);
     // pinned node for edit mode
-    let mut selected_id = use_signal(|| None::<String>);
+    let mut selected_id = use_signal(|| focus.clone());
     // explicit edit mode toggle
     let mut edit_mode = use_signal(|| false);
 
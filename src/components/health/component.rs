@@ -10,15 +10,23 @@ use dioxus_primitives::checkbox::CheckboxState;
 
 // Import our new models and utils
 use crate::components::time::{use_time, Event, EventSource, Freq, Recurrence, When};
+use super::food_diary::FoodDiary;
+use super::session_log::SessionLog;
+use super::weight_log::WeightLog;
 use crate::models::health::health::{
     DietConfig, FoodGroup, FoodVariant, HealthFile, MacroMode, NutritionalInfo, DEFAULT_HEALTH_PATH,
 };
-use crate::utils::json_store::{err_to_string, load_json, save_json};
+use crate::models::health::diary::{diary_path_for, DayTarget, DiaryFile};
+use crate::models::health::history::{history_path_for, HistoryFile};
+use crate::models::health::sessions::{sessions_path_for, SessionsFile};
+use crate::models::health::tdee::{MIN_INTAKE_DAYS, TDEE_WINDOW_DAYS};
+use crate::models::health::workouts::{Exercise, SessionExercise, Workout};
+use crate::utils::json_store::{err_to_string, save_json, StoreResult};
 use uuid::Uuid;
 
 // --- CONSTANTS & LOGIC ---
 
-const LBS_TO_KG: f64 = 0.453592;
+pub(super) const LBS_TO_KG: f64 = 0.453592;
 const IN_TO_CM: f64 = 2.54;
 const CALS_PER_LB_FAT: f64 = 3500.0;
 const DAYS_OF_WEEK: [&str; 7] = [
@@ -51,6 +59,7 @@ impl ActivityLevel {
 #[derive(PartialEq)]
 struct SimulationRow {
     week: usize,
+    date: NaiveDate,
     date_display: String,
     weight: f64,
     maintenance_cals: f64,
@@ -62,93 +71,89 @@ struct SimulationRow {
     c_grams: f64,
     f_grams: f64,
     remaining_cals: f64,
+    /// The week's workout burn came from logged sessions, not the plan.
+    logged_burn: bool,
 }
 
 #[derive(Clone)]
-struct HealthCtx {
-    health_file: Signal<HealthFile>,
-    file_path: Signal<String>,
-    status: Signal<Option<String>>,
+pub(super) struct HealthCtx {
+    pub(super) health_file: Signal<HealthFile>,
+    pub(super) file_path: Signal<String>,
+    pub(super) status: Signal<Option<String>>,
+    /// The health file the logs below were read beside. They save there, not
+    /// wherever `file_path` has been edited to since.
+    pub(super) logs_path: Signal<String>,
+    /// Logged workouts, kept in `sessions.json` beside the health file.
+    pub(super) sessions: Signal<SessionsFile>,
+    /// Why `sessions.json` didn't load. Saving it is refused until it does.
+    pub(super) sessions_error: Signal<Option<String>>,
+    /// Weight and tape measurements, kept in `history.json` beside it.
+    pub(super) history: Signal<HistoryFile>,
+    /// Why `history.json` didn't load, as for `sessions_error`.
+    pub(super) history_error: Signal<Option<String>>,
+    /// Food eaten per day, kept in `diary.json` beside it.
+    pub(super) diary: Signal<DiaryFile>,
+    /// Why `diary.json` didn't load, as for `sessions_error`.
+    pub(super) diary_error: Signal<Option<String>>,
 }
 
 // --- HELPERS ---
 
+/// Put a log read beside the health file into `log`. One that didn't load is
+/// emptied and its error kept, so the unreadable file isn't saved over until
+/// a later load works. Returns that error.
+fn load_log<T: Default + 'static>(
+    loaded: StoreResult<T>,
+    mut log: Signal<T>,
+    mut error: Signal<Option<String>>,
+) -> Option<String> {
+    match loaded {
+        Ok(l) => {
+            log.set(l);
+            error.set(None);
+            None
+        }
+        Err(e) => {
+            let msg = err_to_string(e);
+            log.set(T::default());
+            error.set(Some(msg.clone()));
+            Some(msg)
+        }
+    }
+}
+
+/// Read the logs beside `path` and save them there from now on. Returns the
+/// ones that didn't load.
+fn load_logs(ctx: &HealthCtx, path: &str) -> Vec<String> {
+    let mut logs_path = ctx.logs_path;
+    logs_path.set(path.to_string());
+    let mut problems = vec![];
+    if let Some(e) = load_log(SessionsFile::load(sessions_path_for(path)), ctx.sessions, ctx.sessions_error) {
+        problems.push(format!("session log: {e}"));
+    }
+    if let Some(e) = load_log(HistoryFile::load(history_path_for(path)), ctx.history, ctx.history_error) {
+        problems.push(format!("weight history: {e}"));
+    }
+    if let Some(e) = load_log(DiaryFile::load(diary_path_for(path)), ctx.diary, ctx.diary_error) {
+        problems.push(format!("food diary: {e}"));
+    }
+    problems
+}
+
 /// Returns a tuple: (List of exercises to display, Average Burn Rate Per Hour for this specific session)
 /// Instead of a generic average, this calculates the burn rate based on the specific
-/// variations selected for this week's rotation.
+/// variations selected for this week's rotation. MET-rated exercises scale with `weight_lbs`.
 fn get_exercises_and_burn(
-    workouts_data: &HashMap<String, serde_json::Value>,
+    workouts_data: &HashMap<String, Workout>,
     root_workout: &str,
     week_index: usize,
-) -> (Vec<(String, String, Option<String>)>, f64) {
-    let mut exercises_list = Vec::new();
-    let mut total_burn_rate_sum = 0.0;
-    let mut exercise_count = 0.0;
-
-    if let Some(root_data) = workouts_data.get(root_workout) {
-        if let Some(focus_map) = root_data.get("focus").and_then(|f| f.as_object()) {
-            // 1. Get all muscle groups (e.g., "chest", "triceps", "shoulders")
-            let mut foci_keys: Vec<&String> = focus_map.keys().collect();
-            foci_keys.sort(); // Ensure deterministic order
-
-            // 2. Iterate EVERY muscle group to build a full routine
-            for focus_key in foci_keys {
-                if let Some(exercises_map) = focus_map.get(focus_key).and_then(|e| e.as_object()) {
-                    let mut exercise_names: Vec<&String> = exercises_map.keys().collect();
-                    if !exercise_names.is_empty() {
-                        exercise_names.sort();
-
-                        // 3. Select specific variation based on week index
-                        let ex_idx = week_index % exercise_names.len();
-                        let selected_exercise_name = exercise_names[ex_idx];
-
-                        if let Some(ex_data) = exercises_map.get(selected_exercise_name) {
-                            // Get Image
-                            let mut img_url = None;
-                            if let Some(examples) =
-                                ex_data.get("examples").and_then(|ex| ex.as_object())
-                            {
-                                if let Some((_, ex_details)) = examples.iter().next() {
-                                    if let Some(url) =
-                                        ex_details.get("fileLocation").and_then(|s| s.as_str())
-                                    {
-                                        img_url = Some(url.to_string());
-                                    }
-                                }
-                            }
-
-                            // Get Calories
-                            let burn_rate = ex_data
-                                .get("caloriesPerHour")
-                                .and_then(|c| c.as_f64())
-                                .unwrap_or(0.0);
-
-                            // Add to calculation
-                            if burn_rate > 0.0 {
-                                total_burn_rate_sum += burn_rate;
-                                exercise_count += 1.0;
-                            }
-
-                            exercises_list.push((
-                                focus_key.clone(),
-                                selected_exercise_name.clone(),
-                                img_url,
-                            ));
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    // Average the burn rate of the constituent exercises to get a "Session Intensity Rate"
-    let avg_session_burn = if exercise_count > 0.0 {
-        total_burn_rate_sum / exercise_count
-    } else {
-        0.0
-    };
-
-    (exercises_list, avg_session_burn)
+    weight_lbs: f64,
+) -> (Vec<SessionExercise>, f64) {
+    let weight_kg = (weight_lbs > 0.0).then_some(weight_lbs * LBS_TO_KG);
+    workouts_data
+        .get(root_workout)
+        .map(|w| w.session(week_index, weight_kg))
+        .unwrap_or_default()
 }
 
 fn calculate_bmr(weight_lbs: f64, height_in: f64, age: f64, gender: Gender) -> f64 {
@@ -186,8 +191,16 @@ fn run_simulation(
     percent_loss: f64,
     diet_config: DietConfig,
     avg_daily_workout_cals: f64,
+    sessions: &SessionsFile,
+    today: NaiveDate,
+    observed_tdee: Option<f64>,
 ) -> Vec<SimulationRow> {
     let mut rows = Vec::new();
+    // An observed maintenance replaces the formula at the start weight; the
+    // formula's slope still carries it as weight comes off.
+    let tdee_offset = observed_tdee.map_or(0.0, |observed| {
+        observed - (calculate_bmr(start_weight, height, age, gender) * 1.2 + avg_daily_workout_cals)
+    });
     let mut current_weight = start_weight;
     let mut week = 0;
 
@@ -196,8 +209,15 @@ fn run_simulation(
     }
 
     while current_weight > target_weight && week < 150 {
+        let row_date = start_date + Duration::weeks(week as i64);
+        // Finished weeks with logged sessions use what was actually burned.
+        let week_end = row_date + Duration::days(7);
+        let (logged_kcal, logged_count) = sessions.kcal_between(row_date, week_end);
+        let logged_burn = week_end <= today && logged_count > 0;
+        let workout_cals = if logged_burn { logged_kcal / 7.0 } else { avg_daily_workout_cals };
+
         let bmr = calculate_bmr(current_weight, height, age, gender);
-        let tdee = (bmr * 1.2) + avg_daily_workout_cals;
+        let tdee = (bmr * 1.2) + workout_cals + tdee_offset;
 
         let loss_lbs = current_weight * percent_loss;
         let actual_loss = if current_weight - loss_lbs < target_weight {
@@ -241,10 +261,10 @@ fn run_simulation(
         );
 
         let remaining = daily_intake - (p_c + c_c + f_c);
-        let row_date = start_date + Duration::weeks(week as i64);
 
         rows.push(SimulationRow {
             week,
+            date: row_date,
             date_display: row_date.format("%b %d").to_string(),
             weight: current_weight,
             maintenance_cals: tdee,
@@ -256,6 +276,7 @@ fn run_simulation(
             c_grams: c_g,
             f_grams: f_g,
             remaining_cals: remaining,
+            logged_burn,
         });
 
         current_weight -= actual_loss;
@@ -263,7 +284,7 @@ fn run_simulation(
     }
 
     let final_bmr = calculate_bmr(current_weight, height, age, gender);
-    let final_tdee = (final_bmr * 1.2) + avg_daily_workout_cals;
+    let final_tdee = (final_bmr * 1.2) + avg_daily_workout_cals + tdee_offset;
     let (p_g, p_c) = calc_macro_cals(
         diet_config.protein.mode,
         if diet_config.protein.mode == MacroMode::Percentage {
@@ -296,11 +317,11 @@ fn run_simulation(
     );
     let remaining = final_tdee - (p_c + c_c + f_c);
 
+    let end_date = start_date + Duration::weeks(week as i64);
     rows.push(SimulationRow {
         week,
-        date_display: (start_date + Duration::weeks(week as i64))
-            .format("%b %d")
-            .to_string(),
+        date: end_date,
+        date_display: end_date.format("%b %d").to_string(),
         weight: current_weight,
         maintenance_cals: final_tdee,
         goal_loss_lbs: 0.0,
@@ -311,57 +332,37 @@ fn run_simulation(
         c_grams: c_g,
         f_grams: f_g,
         remaining_cals: remaining,
+        logged_burn: false,
     });
 
     rows
 }
 
-/// Insert (or extend) a workout in the untyped workouts map. Building the nested
-/// {focus:{group:{exercise:{caloriesPerHour,examples}}}} shape on the fly means
-/// "add a workout" and "add an exercise" are the same operation.
-fn add_or_extend_workout(
-    workouts: &mut HashMap<String, serde_json::Value>,
-    workout: &str,
-    focus: &str,
-    exercise: &str,
-    calories_per_hour: f64,
-) {
-    let root = workouts
-        .entry(workout.to_string())
-        .or_insert_with(|| serde_json::json!({ "focus": {} }));
-    if !root.is_object() {
-        *root = serde_json::json!({ "focus": {} });
-    }
-    let root_obj = root.as_object_mut().unwrap();
-    let focus_v = root_obj
-        .entry("focus")
-        .or_insert_with(|| serde_json::json!({}));
-    if !focus_v.is_object() {
-        *focus_v = serde_json::json!({});
-    }
-    let focus_obj = focus_v.as_object_mut().unwrap();
-    let group = focus_obj
-        .entry(focus.to_string())
-        .or_insert_with(|| serde_json::json!({}));
-    if !group.is_object() {
-        *group = serde_json::json!({});
-    }
-    group.as_object_mut().unwrap().insert(
-        exercise.to_string(),
-        serde_json::json!({ "caloriesPerHour": calories_per_hour, "examples": {} }),
-    );
-}
-
 #[component]
 pub fn Health(#[props(default)] overview: bool) -> Element {
     let mut health_file = use_signal(HealthFile::default);
     let mut file_path = use_signal(|| DEFAULT_HEALTH_PATH.to_string());
     let mut status = use_signal(|| None::<String>);
+    // The logs are read once mounted, below.
+    let logs_path = use_signal(|| DEFAULT_HEALTH_PATH.to_string());
+    let sessions = use_signal(SessionsFile::default);
+    let sessions_error = use_signal(|| None::<String>);
+    let history = use_signal(HistoryFile::default);
+    let history_error = use_signal(|| None::<String>);
+    let diary = use_signal(DiaryFile::default);
+    let diary_error = use_signal(|| None::<String>);
 
     use_context_provider(|| HealthCtx {
         health_file,
         file_path,
         status,
+        logs_path,
+        sessions,
+        sessions_error,
+        history,
+        history_error,
+        diary,
+        diary_error,
     });
     let ctx = use_context::<HealthCtx>();
 
@@ -378,8 +379,14 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
     let mut nw_focus = use_signal(String::new);
     let mut nw_exercise = use_signal(String::new);
     let mut nw_cals = use_signal(String::new);
+    let mut nw_met = use_signal(String::new);
+    let mut nw_sets = use_signal(String::new);
+    let mut nw_reps = use_signal(String::new);
+    let mut nw_rest = use_signal(String::new);
+    let mut nw_equipment = use_signal(String::new);
 
     let mut start_date = use_signal(|| Some(OffsetDateTime::now_utc().date()));
+    // Moves to the smoothed trend of the weight log once there is one.
     let mut weight = use_signal(|| 215.0);
     let mut target = use_signal(|| 170.0);
     let mut height = use_signal(|| 70.0);
@@ -391,16 +398,42 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
     let mut expanded_week = use_signal(|| Option::<usize>::None);
     let mut daily_durations = use_signal(|| HashMap::<String, f64>::new());
 
+    // Read the logs beside the default health file once on mount (the
+    // effect reads no signals).
+    {
+        let ctx = ctx.clone();
+        use_effect(move || {
+            let problems = load_logs(&ctx, DEFAULT_HEALTH_PATH);
+            if let Some(trend) = ctx.history.peek().trend_weight() {
+                weight.set(trend);
+            }
+            if !problems.is_empty() {
+                status.set(Some(format!("Couldn't read the {}; it won't be saved over until it loads.", problems.join("; "))));
+            }
+        });
+    }
+
+    // A failed load leaves the logs where they were, still saving beside the
+    // file they came from.
     let on_load = {
+        let ctx = ctx.clone();
         let mut health_file = ctx.health_file.clone();
         let mut status = ctx.status.clone();
         let file_path = ctx.file_path.clone();
         move |_| {
             let path = file_path.read().clone();
-            match load_json::<HealthFile>(&path) {
+            match HealthFile::load(&path) {
                 Ok(f) => {
                     health_file.set(f);
-                    status.set(Some(format!("Loaded health data from {}", path)));
+                    let problems = load_logs(&ctx, &path);
+                    if let Some(trend) = ctx.history.peek().trend_weight() {
+                        weight.set(trend);
+                    }
+                    if problems.is_empty() {
+                        status.set(Some(format!("Loaded health data from {}", path)));
+                    } else {
+                        status.set(Some(format!("Loaded {}, but not its {}; those won't be saved over until they load.", path, problems.join("; "))));
+                    }
                 }
                 Err(e) => status.set(Some(err_to_string(e))),
             }
@@ -452,7 +485,7 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
 
             let hrs = durs.get(*day).cloned().unwrap_or(0.0);
             // Burn rate keyed off the primary (first) workout, matching daily_burn_map.
-            let (_, rate) = get_exercises_and_burn(&file.workouts, &roots[0], 0);
+            let (_, rate) = get_exercises_and_burn(&file.workouts, &roots[0], 0, *weight.read());
             let burn = (rate * hrs) as i64;
 
             let title = if hrs > 0.0 {
@@ -516,6 +549,7 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                             &file.workouts,
                             root,
                             current_week_for_projection,
+                            *weight.read(),
                         );
                         daily_cals = burn_rate_per_hour * hrs;
                     }
@@ -527,6 +561,20 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
         (map, total_weekly)
     });
 
+    // Maintenance two ways: the formula at today's weight, and what the
+    // intake and weight logs say it actually is.
+    let mut use_observed = use_signal(|| true);
+    let formula_tdee = use_memo(move || {
+        calculate_bmr(*weight.read(), *height.read(), *age.read(), *gender.read()) * 1.2
+            + daily_burn_map.read().1 / 7.0
+    });
+    let tdee_estimate = use_memo(move || {
+        let diary_intake = diary.read().daily_calories(&health_file.read());
+        history
+            .read()
+            .estimate_tdee(&diary_intake, chrono::Local::now().date_naive(), TDEE_WINDOW_DAYS)
+    });
+
     let simulation_data = use_memo(move || {
         let time_date = start_date
             .read()
@@ -551,6 +599,9 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
             *percent_mode.read(),
             current_diet,
             avg_burn,
+            &sessions.read(),
+            chrono::Local::now().date_naive(),
+            tdee_estimate().filter(|_| *use_observed.read()).map(|e| e.tdee),
         )
     });
 
@@ -637,10 +688,10 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                                                                 div { class: "flex flex-wrap gap-1 mt-1",
                                                                     {
                                                                         // Quick fetch just to show tags in the overview card
-                                                                        let (list, _) = get_exercises_and_burn(&health_file.read().workouts, root, 0);
+                                                                        let (list, _) = get_exercises_and_burn(&health_file.read().workouts, root, 0, 0.0);
                                                                         rsx! {
-                                                                            for (focus, _, _) in list {
-                                                                                span { class: "text-[8px] bg-neutral-800 px-1 rounded text-neutral-500 uppercase", "{focus}" }
+                                                                            for ex in list {
+                                                                                span { class: "text-[8px] bg-neutral-800 px-1 rounded text-neutral-500 uppercase", "{ex.focus}" }
                                                                             }
                                                                         }
                                                                     }
@@ -673,6 +724,20 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                                                 div { class: "flex flex-col gap-1", label { class: "text-xs text-neutral-400 font-mono uppercase", "Gender" } select { class: "bg-neutral-800 p-2 rounded border border-neutral-700 outline-none text-neutral-200", onchange: move |e| gender.set(if e.value() == "male" { Gender::Male } else { Gender::Female }), option { value: "male", "Male" } option { value: "female", "Female" } } }
                                                 div { class: "flex flex-col gap-1", label { class: "text-xs text-neutral-400 font-mono uppercase", "Base Activity" } select { class: "bg-neutral-800 p-2 rounded border border-neutral-700 outline-none text-neutral-200", disabled: true, option { value: "0", "Sedentary (1.2x)" } } }
                                             }
+                                            // Maintenance: formula vs observed
+                                            div { class: "flex flex-wrap items-center gap-4 bg-neutral-900/50 p-3 rounded-lg border border-neutral-800 text-sm font-mono",
+                                                span { class: "text-neutral-400", "Maintenance" }
+                                                span { title: "BMR × 1.2 plus the average scheduled workout burn", "Formula " span { class: "text-neutral-200 font-bold", "{formula_tdee:.0}" } " kcal" }
+                                                if let Some(est) = tdee_estimate() {
+                                                    span { title: "Average intake {est.avg_intake:.0} kcal, {est.intake_days} days logged, {est.from} to {est.to}", "Observed " span { class: "text-orange-300 font-bold", "{est.tdee:.0}" } " kcal ({est.lbs_per_week:+.1} lb/wk)" }
+                                                    label { class: "flex items-center gap-1 text-xs text-neutral-400 cursor-pointer",
+                                                        input { type: "checkbox", checked: *use_observed.read(), onchange: move |e| use_observed.set(e.checked()) }
+                                                        "Plan with observed"
+                                                    }
+                                                } else {
+                                                    span { class: "text-xs text-neutral-500", "Observed: log intake or diary days on {MIN_INTAKE_DAYS}+ of the last {TDEE_WINDOW_DAYS} days and weigh in over a week or more." }
+                                                }
+                                            }
 
                                             div { class: "overflow-hidden border border-neutral-700 rounded-lg bg-neutral-900 shadow-xl",
                                                 div { class: "overflow-x-auto",
@@ -698,7 +763,7 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                                                                         tr {
                                                                             class: "hover:bg-neutral-800/50 transition-colors cursor-pointer border-b border-neutral-800",
                                                                             onclick: move |_| { if *expanded_week.read() == Some(current_week) { expanded_week.set(None); } else { expanded_week.set(Some(current_week)); } },
-                                                                            td { class: "p-3", div { class: "text-neutral-300 font-mono font-bold flex items-center gap-2", if *expanded_week.read() == Some(row.week) { "▼" } else { "▶" } "{row.week}" } div { class: "text-neutral-600 text-[10px] uppercase font-mono mt-0.5 pl-4", "{row.date_display}" } }
+                                                                            td { class: "p-3", div { class: "text-neutral-300 font-mono font-bold flex items-center gap-2", if *expanded_week.read() == Some(row.week) { "▼" } else { "▶" } "{row.week}" if row.logged_burn { span { class: "text-[10px] text-orange-400", title: "Workout burn from logged sessions", "logged" } } } div { class: "text-neutral-600 text-[10px] uppercase font-mono mt-0.5 pl-4", "{row.date_display}" } }
                                                                             td { class: "p-3 font-bold text-neutral-200", "{row.weight:.1}" }
                                                                             td { class: "p-3 text-neutral-400", if row.goal_loss_lbs <= 0.05 { "✓" } else { "{row.goal_loss_lbs:.1}" } }
                                                                             td { class: "p-3 text-right font-mono text-xs text-green-400", "{row.p_grams as i32}g" }
@@ -872,7 +937,7 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                                                                                                         // Also returns average burn rate for the selected variations
                                                                                                         let (exercises, avg_burn_rate) = if let Some(r) = &maybe_root {
                                                                                                             if !r.is_empty() {
-                                                                                                                get_exercises_and_burn(&file.workouts, r, current_week)
+                                                                                                                get_exercises_and_burn(&file.workouts, r, current_week, row.weight)
                                                                                                             } else { (vec![], 0.0) }
                                                                                                         } else { (vec![], 0.0) };
 
@@ -907,16 +972,19 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                                                                                                                     // Exercises
                                                                                                                     if !is_empty {
                                                                                                                         div { class: "space-y-3",
-                                                                                                                            for (focus, ex_name, img) in exercises {
+                                                                                                                            for ex in exercises {
                                                                                                                                 div { class: "flex gap-3",
-                                                                                                                                    if let Some(url) = img {
+                                                                                                                                    if let Some(url) = &ex.image {
                                                                                                                                         div { class: "w-16 h-16 bg-black rounded overflow-hidden flex-shrink-0 border border-neutral-700",
                                                                                                                                             img { src: "{url}", class: "w-full h-full object-cover" }
                                                                                                                                         }
                                                                                                                                     }
                                                                                                                                     div {
-                                                                                                                                        div { class: "text-[10px] uppercase text-neutral-500 font-bold", "{focus}" }
-                                                                                                                                        div { class: "text-sm text-neutral-200", "{ex_name}" }
+                                                                                                                                        div { class: "text-[10px] uppercase text-neutral-500 font-bold", "{ex.focus}" }
+                                                                                                                                        div { class: "text-sm text-neutral-200", "{ex.name}" }
+                                                                                                                                        if !ex.prescription.is_empty() {
+                                                                                                                                            div { class: "text-[10px] text-neutral-400", "{ex.prescription}" }
+                                                                                                                                        }
                                                                                                                                     }
                                                                                                                                 }
                                                                                                                             }
@@ -948,6 +1016,29 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                                     }
                                 }
 
+                                AccordionItem { index: 4usize, AccordionTrigger { class:"flex justify-center w-full bg-neutral-900/30 p-2 rounded mb-1 hover:bg-neutral-800/50", span { class: "font-bold", "Session Log & Progress" } }
+                                    AccordionContent {
+                                        SessionLog { weight_lbs: *weight.read() }
+                                    }
+                                }
+
+                                AccordionItem { index: 5usize, AccordionTrigger { class:"flex justify-center w-full bg-neutral-900/30 p-2 rounded mb-1 hover:bg-neutral-800/50", span { class: "font-bold", "Body Measurements" } }
+                                    AccordionContent {
+                                        WeightLog {
+                                            projected: simulation_data.read().iter().map(|r| (r.date, r.weight)).collect::<Vec<_>>(),
+                                            on_trend: move |w| weight.set(w),
+                                        }
+                                    }
+                                }
+
+                                AccordionItem { index: 6usize, AccordionTrigger { class:"flex justify-center w-full bg-neutral-900/30 p-2 rounded mb-1 hover:bg-neutral-800/50", span { class: "font-bold", "Food Diary" } }
+                                    AccordionContent {
+                                        FoodDiary {
+                                            targets: simulation_data.read().iter().map(|r| (r.date, DayTarget { calories: r.daily_cals, protein: r.p_grams, carbs: r.c_grams, fat: r.f_grams })).collect::<Vec<_>>(),
+                                        }
+                                    }
+                                }
+
                                 AccordionItem { index: 3usize, AccordionTrigger { class:"flex justify-center w-full bg-neutral-900/30 p-2 rounded mb-1 hover:bg-neutral-800/50", span { class: "font-bold", "Library Editor (Add Pantry & Workouts)" } }
                                     AccordionContent {
                                         div { class: "grid grid-cols-1 lg:grid-cols-2 gap-6 p-4",
@@ -1008,6 +1099,11 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                                                     input { class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Focus (e.g. chest)", value: "{nw_focus}", oninput: move |e| nw_focus.set(e.value()) }
                                                     input { class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Exercise name", value: "{nw_exercise}", oninput: move |e| nw_exercise.set(e.value()) }
                                                     input { type: "number", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Cal / hour", value: "{nw_cals}", oninput: move |e| nw_cals.set(e.value()) }
+                                                    input { type: "number", step: "0.1", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "MET (optional)", value: "{nw_met}", oninput: move |e| nw_met.set(e.value()) }
+                                                    input { class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Equipment (comma separated)", value: "{nw_equipment}", oninput: move |e| nw_equipment.set(e.value()) }
+                                                    input { type: "number", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Sets", value: "{nw_sets}", oninput: move |e| nw_sets.set(e.value()) }
+                                                    input { type: "number", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Reps", value: "{nw_reps}", oninput: move |e| nw_reps.set(e.value()) }
+                                                    input { type: "number", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Rest (seconds)", value: "{nw_rest}", oninput: move |e| nw_rest.set(e.value()) }
                                                 }
                                                 button {
                                                     class: "w-full px-3 py-1 border border-blue-700 text-blue-300 rounded text-sm hover:bg-blue-900/30",
@@ -1016,17 +1112,30 @@ pub fn Health(#[props(default)] overview: bool) -> Element {
                                                         let fo = nw_focus.read().trim().to_string();
                                                         let ex = nw_exercise.read().trim().to_string();
                                                         if w.is_empty() || fo.is_empty() || ex.is_empty() { return; }
-                                                        let cals = nw_cals.read().trim().parse().unwrap_or(0.0);
-                                                        add_or_extend_workout(&mut health_file.write().workouts, &w, &fo, &ex, cals);
+                                                        let exercise = Exercise {
+                                                            calories_per_hour: nw_cals.read().trim().parse().unwrap_or(0.0),
+                                                            met: nw_met.read().trim().parse().ok().filter(|m: &f64| *m > 0.0),
+                                                            sets: nw_sets.read().trim().parse().ok().filter(|n: &u32| *n > 0),
+                                                            reps: nw_reps.read().trim().parse().ok().filter(|n: &u32| *n > 0),
+                                                            rest_seconds: nw_rest.read().trim().parse().ok(),
+                                                            equipment: nw_equipment.read().split(',').map(|e| e.trim().to_string()).filter(|e| !e.is_empty()).collect(),
+                                                            ..Default::default()
+                                                        };
+                                                        health_file.write().workouts.entry(w).or_default().add_exercise(&fo, &ex, exercise);
                                                         nw_exercise.set(String::new());
                                                         nw_cals.set(String::new());
+                                                        nw_met.set(String::new());
+                                                        nw_sets.set(String::new());
+                                                        nw_reps.set(String::new());
+                                                        nw_rest.set(String::new());
+                                                        nw_equipment.set(String::new());
                                                     },
                                                     "+ Add / Extend"
                                                 }
                                                 div { class: "border-t border-neutral-800 pt-2 space-y-1 max-h-64 overflow-y-auto",
                                                     for (wkey, wval) in health_file.read().workouts.iter() {
                                                         {
-                                                            let groups = wval.get("focus").and_then(|f| f.as_object()).map(|o| o.len()).unwrap_or(0);
+                                                            let groups = wval.focus.len();
                                                             rsx! {
                                                                 div { class: "flex items-center justify-between text-sm",
                                                                     span { class: "text-neutral-300", "{wkey} " span { class: "text-neutral-600 text-xs", "({groups} focus groups)" } }
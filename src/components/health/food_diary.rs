@@ -0,0 +1,214 @@
+//! The daily food diary, checked against the simulation's calorie and macro
+//! targets for that week.
+
+use chrono::{Duration, NaiveDate};
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::health::diary::{diary_path_for, parse_quantity, DayTarget, DiaryEntry, Meal};
+use crate::utils::json_store::err_to_string;
+
+use super::component::HealthCtx;
+
+/// Write the diary beside the health file it came from; failures go to the
+/// status line. Refused while the file on disk is one that didn't load.
+fn save_diary(ctx: &HealthCtx) {
+    let path = diary_path_for(ctx.logs_path.read().as_str());
+    let mut status = ctx.status;
+    if let Some(e) = ctx.diary_error.read().as_ref() {
+        status.set(Some(format!("Not saving the food diary: {} didn't load ({e}). Fix it, then Load again.", path.display())));
+        return;
+    }
+    if let Err(e) = ctx.diary.read().save(&path) {
+        status.set(Some(format!("Couldn't save food diary to {}: {}", path.display(), err_to_string(e))));
+    }
+}
+
+/// The target row covering `day`: rows are one week each, oldest first.
+fn target_for(targets: &[(NaiveDate, DayTarget)], day: NaiveDate) -> Option<DayTarget> {
+    targets
+        .iter()
+        .rev()
+        .find(|(start, _)| *start <= day && day < *start + Duration::days(7))
+        .map(|(_, t)| *t)
+}
+
+#[component]
+pub(super) fn FoodDiary(targets: Vec<(NaiveDate, DayTarget)>) -> Element {
+    let ctx = use_context::<HealthCtx>();
+
+    let mut day = use_signal(|| chrono::Local::now().date_naive());
+    let mut meal = use_signal(|| Meal::Breakfast);
+    // Index into `foods`
+    let mut food = use_signal(String::new);
+    let mut quantity = use_signal(|| "1".to_string());
+
+    let today = *day.read();
+    let foods = ctx.health_file.read().foods();
+    let unit = food
+        .read()
+        .parse::<usize>()
+        .ok()
+        .and_then(|i| foods.get(i))
+        .and_then(|f| ctx.health_file.read().food_info(f).map(|(unit, _)| unit.to_string()))
+        .unwrap_or_default();
+    let entries: Vec<DiaryEntry> = ctx.diary.read().entries(today).to_vec();
+    let totals = ctx.diary.read().day_totals(today, &ctx.health_file.read());
+    let target = target_for(&targets, today);
+
+    let on_add = {
+        let ctx = ctx.clone();
+        let foods = foods.clone();
+        move |_| {
+            let mut status = ctx.status;
+            let Some(picked) = food.read().parse::<usize>().ok().and_then(|i| foods.get(i)).cloned() else {
+                status.set(Some("Pick a food.".into()));
+                return;
+            };
+            let Some(qty) = parse_quantity(&quantity.read()) else {
+                status.set(Some("Quantity must be a positive number.".into()));
+                return;
+            };
+            let day = *day.read();
+            let mut diary = ctx.diary;
+            diary.write().add(day, DiaryEntry { id: Uuid::new_v4(), meal: *meal.read(), food: picked, quantity: qty });
+            save_diary(&ctx);
+            quantity.set("1".to_string());
+        }
+    };
+
+    // (label, eaten, target, unit, colour)
+    let macros: Vec<(&str, f64, Option<f64>, &str, &str)> = vec![
+        ("Calories", totals.total.calories, target.map(|t| t.calories), "kcal", "bg-neutral-300"),
+        ("Protein", totals.total.protein, target.map(|t| t.protein), "g", "bg-green-500"),
+        ("Carbs", totals.total.carbs, target.map(|t| t.carbs), "g", "bg-blue-500"),
+        ("Fats", totals.total.fat, target.map(|t| t.fat), "g", "bg-yellow-500"),
+    ];
+
+    rsx! {
+        div { class: "grid grid-cols-1 lg:grid-cols-2 gap-6 p-4",
+            // ---- DIARY ----
+            div { class: "border border-neutral-700 rounded-lg p-4 bg-neutral-900/30 space-y-3",
+                div { class: "flex items-center gap-2",
+                    button { class: "px-2 py-1 border border-neutral-700 rounded text-sm hover:bg-neutral-800", onclick: move |_| { let d = *day.read(); day.set(d - Duration::days(1)); }, "◀" }
+                    input {
+                        type: "date",
+                        class: "flex-1 bg-neutral-800 border border-neutral-700 rounded p-1 text-sm",
+                        value: "{today}",
+                        oninput: move |e| {
+                            if let Ok(d) = NaiveDate::parse_from_str(&e.value(), "%Y-%m-%d") {
+                                day.set(d);
+                            }
+                        }
+                    }
+                    button { class: "px-2 py-1 border border-neutral-700 rounded text-sm hover:bg-neutral-800", onclick: move |_| { let d = *day.read(); day.set(d + Duration::days(1)); }, "▶" }
+                }
+
+                div { class: "grid grid-cols-4 gap-2",
+                    select {
+                        class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm",
+                        onchange: move |e| {
+                            if let Some(m) = Meal::ALL.into_iter().find(|m| m.label() == e.value()) {
+                                meal.set(m);
+                            }
+                        },
+                        for m in Meal::ALL {
+                            option { value: "{m.label()}", selected: *meal.read() == m, "{m.label()}" }
+                        }
+                    }
+                    select {
+                        class: "col-span-3 bg-neutral-800 border border-neutral-700 rounded p-1 text-sm",
+                        value: "{food}",
+                        onchange: move |e| food.set(e.value()),
+                        option { value: "", "Food…" }
+                        for (i, f) in foods.iter().enumerate() {
+                            option { value: "{i}", "{f.label()}" }
+                        }
+                    }
+                    input { type: "number", class: "col-span-2 bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", value: "{quantity}", oninput: move |e| quantity.set(e.value()) }
+                    span { class: "self-center text-xs text-neutral-500", "{unit}" }
+                    button { class: "px-3 py-1 border border-blue-700 text-blue-300 rounded text-sm hover:bg-blue-900/30", onclick: on_add, "+ Add" }
+                }
+
+                if entries.is_empty() {
+                    p { class: "text-xs text-neutral-500", "Nothing logged for {today}." }
+                }
+                for m in Meal::ALL {
+                    if entries.iter().any(|e| e.meal == m) {
+                        div { class: "space-y-1",
+                            div { class: "flex justify-between text-[10px] uppercase text-neutral-500 border-b border-neutral-800",
+                                span { "{m.label()}" }
+                                span { {format!("{:.0} kcal", totals.by_meal.get(&m).map_or(0.0, |n| n.calories))} }
+                            }
+                            for e in entries.iter().filter(|e| e.meal == m).cloned() {
+                                {
+                                    let info = ctx.health_file.read().food_info(&e.food).map(|(unit, info)| (unit.to_string(), info.calories * e.quantity, info.protein * e.quantity));
+                                    let ctx = ctx.clone();
+                                    rsx! {
+                                        div { key: "{e.id}", class: "flex items-center justify-between text-sm",
+                                            span { class: "text-neutral-300",
+                                                "{e.food.label()} "
+                                                if let Some((unit, kcal, protein)) = info {
+                                                    span { class: "text-neutral-500 text-xs font-mono", "{e.quantity} {unit} · {kcal:.0} kcal · {protein:.0}g P" }
+                                                } else {
+                                                    span { class: "text-red-400 text-xs", title: "This food is no longer in the database", "unknown food" }
+                                                }
+                                            }
+                                            button {
+                                                class: "text-red-400 px-2 hover:text-red-300",
+                                                onclick: move |_| {
+                                                    let mut diary = ctx.diary;
+                                                    if diary.write().remove(today, e.id) {
+                                                        save_diary(&ctx);
+                                                    }
+                                                },
+                                                "\u{2715}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // ---- AGAINST TARGETS ----
+            div { class: "border border-neutral-700 rounded-lg p-4 bg-neutral-900/30 space-y-3",
+                h3 { class: "font-bold text-lg text-neutral-200", "Day vs Plan" }
+                if target.is_none() {
+                    p { class: "text-xs text-neutral-500", "{today} is outside the simulation; totals only." }
+                }
+                for (label, eaten, goal, unit, color) in macros {
+                    div { class: "space-y-1",
+                        div { class: "flex justify-between text-xs font-mono",
+                            span { class: "text-neutral-400 uppercase", "{label}" }
+                            if let Some(goal) = goal {
+                                span { class: (if eaten > goal * 1.05 { "text-red-400" } else { "text-neutral-300" }),
+                                    {format!("{eaten:.0} / {goal:.0} {unit} ({:+.0} left)", goal - eaten)}
+                                }
+                            } else {
+                                span { class: "text-neutral-300", "{eaten:.0} {unit}" }
+                            }
+                        }
+                        if let Some(goal) = goal.filter(|g| *g > 0.0) {
+                            div { class: "h-1.5 rounded bg-neutral-800 overflow-hidden",
+                                div { class: "h-full {color}", style: format!("width: {:.0}%;", (eaten / goal * 100.0).min(100.0)) }
+                            }
+                        }
+                    }
+                }
+                div { class: "grid grid-cols-2 gap-x-4 gap-y-1 pt-2 border-t border-neutral-800 text-xs font-mono",
+                    span { class: "text-neutral-500", "Saturated fat" } span { class: "text-right text-neutral-300", "{totals.total.saturated_fat:.1} g" }
+                    span { class: "text-neutral-500", "Mono fat" } span { class: "text-right text-neutral-300", "{totals.total.monounsaturated_fat:.1} g" }
+                    span { class: "text-neutral-500", "Poly fat" } span { class: "text-right text-neutral-300", "{totals.total.polyunsaturated_fat:.1} g" }
+                    span { class: "text-neutral-500", "Cholesterol" } span { class: "text-right text-neutral-300", "{totals.total.cholesterol:.0} mg" }
+                    span { class: "text-neutral-500", "Sodium" } span { class: "text-right text-neutral-300", "{totals.total.sodium:.0} mg" }
+                }
+                if totals.unknown > 0 {
+                    p { class: "text-xs text-red-400", "{totals.unknown} entr(ies) reference foods no longer in the database and aren't counted." }
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,5 @@
 mod component;
-pub use component::*;
\ No newline at end of file
+mod food_diary;
+mod session_log;
+mod weight_log;
+pub use component::*;
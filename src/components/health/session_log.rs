@@ -0,0 +1,265 @@
+//! Logging what was actually trained, and the progressive-overload numbers
+//! that come out of it: estimated 1RM over time, this week's volume per focus
+//! group and personal records.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, NaiveDate};
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::health::sessions::{
+    parse_sets, sessions_path_for, ExerciseLog, FocusVolume, PersonalRecord, WorkoutSession,
+};
+use crate::utils::json_store::err_to_string;
+
+use super::component::{HealthCtx, LBS_TO_KG};
+
+/// Write the log beside the health file it came from; failures go to the
+/// status line. Refused while the file on disk is one that didn't load.
+fn save_sessions(ctx: &HealthCtx) {
+    let path = sessions_path_for(ctx.logs_path.read().as_str());
+    let mut status = ctx.status;
+    if let Some(e) = ctx.sessions_error.read().as_ref() {
+        status.set(Some(format!("Not saving sessions: {} didn't load ({e}). Fix it, then Load again.", path.display())));
+        return;
+    }
+    if let Err(e) = ctx.sessions.read().save(&path) {
+        status.set(Some(format!("Couldn't save sessions to {}: {}", path.display(), err_to_string(e))));
+    }
+}
+
+#[component]
+pub(super) fn SessionLog(weight_lbs: f64) -> Element {
+    let ctx = use_context::<HealthCtx>();
+    let today = chrono::Local::now().date_naive();
+
+    // Log form drafts
+    let mut date = use_signal(|| today.to_string());
+    let mut workout = use_signal(String::new);
+    let mut minutes = use_signal(|| "60".to_string());
+    let mut effort = use_signal(|| "7".to_string());
+    let mut notes = use_signal(String::new);
+    // (focus, exercise) -> "3x10@135, 8@145"
+    let mut set_texts = use_signal(BTreeMap::<(String, String), String>::new);
+    let mut chart_exercise = use_signal(String::new);
+
+    let (workout_names, exercises) = {
+        let file = ctx.health_file.read();
+        let mut names: Vec<String> = file.workouts.keys().cloned().collect();
+        names.sort();
+        let exercises: Vec<(String, String)> = file
+            .workouts
+            .get(workout.read().as_str())
+            .map(|w| {
+                w.focus
+                    .iter()
+                    .flat_map(|(focus, g)| g.exercises.keys().map(move |n| (focus.clone(), n.clone())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        (names, exercises)
+    };
+
+    let week_start = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let (volume, records, names, recent) = {
+        let log = ctx.sessions.read();
+        let volume: Vec<(String, FocusVolume)> = log.weekly_volume(week_start).into_iter().collect();
+        let records: Vec<PersonalRecord> = log.personal_records();
+        let recent: Vec<WorkoutSession> = log.sessions.iter().rev().take(10).cloned().collect();
+        (volume, records, log.exercise_names(), recent)
+    };
+    let chart_name = match chart_exercise.read().as_str() {
+        "" => names.first().cloned().unwrap_or_default(),
+        name => name.to_string(),
+    };
+    let history = ctx.sessions.read().one_rm_history(&chart_name);
+    let history_max = history.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+
+    let on_log = {
+        let ctx = ctx.clone();
+        move |_| {
+            let mut status = ctx.status;
+            let Ok(day) = NaiveDate::parse_from_str(date.read().trim(), "%Y-%m-%d") else {
+                status.set(Some("Pick a session date.".into()));
+                return;
+            };
+            let name = workout.read().clone();
+            if name.is_empty() {
+                status.set(Some("Pick the workout you did.".into()));
+                return;
+            }
+            let mut logs = vec![];
+            for ((focus, ex), text) in set_texts.read().iter() {
+                match parse_sets(text) {
+                    Ok(sets) if sets.is_empty() => {}
+                    Ok(sets) => logs.push(ExerciseLog { name: ex.clone(), focus: focus.clone(), sets }),
+                    Err(e) => {
+                        status.set(Some(format!("{ex}: {e}")));
+                        return;
+                    }
+                }
+            }
+            let mut session = WorkoutSession {
+                id: Uuid::new_v4(),
+                date: day,
+                workout: name,
+                duration_minutes: minutes.read().trim().parse().unwrap_or(0),
+                effort: effort.read().trim().parse().ok().filter(|e: &u8| (1..=10).contains(e)),
+                kcal: 0.0,
+                exercises: logs,
+                notes: notes.read().trim().to_string(),
+            };
+            let weight_kg = (weight_lbs > 0.0).then_some(weight_lbs * LBS_TO_KG);
+            session.kcal = session.estimate_kcal(&ctx.health_file.read().workouts, weight_kg);
+            status.set(Some(format!("Logged {} on {} (~{:.0} kcal).", session.workout, day, session.kcal)));
+            let mut sessions = ctx.sessions;
+            sessions.write().add(session);
+            save_sessions(&ctx);
+            set_texts.write().clear();
+            notes.set(String::new());
+        }
+    };
+
+    rsx! {
+        div { class: "grid grid-cols-1 lg:grid-cols-2 gap-6 p-4",
+            // ---- LOG A SESSION ----
+            div { class: "border border-neutral-700 rounded-lg p-4 bg-neutral-900/30 space-y-3",
+                h3 { class: "font-bold text-lg text-neutral-200", "Log a Session" }
+                div { class: "grid grid-cols-2 gap-2",
+                    input { type: "date", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", value: "{date}", oninput: move |e| date.set(e.value()) }
+                    select {
+                        class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm",
+                        value: "{workout}",
+                        onchange: move |e| { workout.set(e.value()); set_texts.write().clear(); },
+                        option { value: "", "Workout…" }
+                        for name in workout_names {
+                            option { value: "{name}", "{name}" }
+                        }
+                    }
+                    input { type: "number", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Minutes", value: "{minutes}", oninput: move |e| minutes.set(e.value()) }
+                    input { type: "number", min: "1", max: "10", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Effort (1-10)", value: "{effort}", oninput: move |e| effort.set(e.value()) }
+                }
+                if !exercises.is_empty() {
+                    p { class: "text-xs text-neutral-500", "Sets as [sets x] reps [@ lb], e.g. \"3x10@135, 8@145\". Leave blank to skip; no weight means bodyweight." }
+                }
+                for (focus, ex) in exercises {
+                    {
+                        let key = (focus.clone(), ex.clone());
+                        let value = set_texts.read().get(&key).cloned().unwrap_or_default();
+                        rsx! {
+                            div { class: "flex items-center gap-2",
+                                div { class: "flex-1 min-w-0",
+                                    div { class: "text-[10px] uppercase text-neutral-500", "{focus}" }
+                                    div { class: "text-sm text-neutral-300 truncate", "{ex}" }
+                                }
+                                input {
+                                    class: "w-40 bg-neutral-800 border border-neutral-700 rounded p-1 text-sm font-mono",
+                                    placeholder: "3x10@135",
+                                    value: "{value}",
+                                    oninput: move |e| { set_texts.write().insert(key.clone(), e.value()); }
+                                }
+                            }
+                        }
+                    }
+                }
+                input { class: "w-full bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Notes", value: "{notes}", oninput: move |e| notes.set(e.value()) }
+                button { class: "w-full px-3 py-1 border border-blue-700 text-blue-300 rounded text-sm hover:bg-blue-900/30", onclick: on_log, "+ Log Session" }
+
+                div { class: "border-t border-neutral-800 pt-2 space-y-1 max-h-64 overflow-y-auto",
+                    for s in recent {
+                        div { key: "{s.id}", class: "flex items-center justify-between text-sm",
+                            span { class: "text-neutral-300",
+                                "{s.date} · {s.workout} "
+                                span { class: "text-neutral-600 text-xs",
+                                    "{s.duration_minutes} min · {s.kcal:.0} kcal · {s.volume():.0} lb"
+                                    if let Some(e) = s.effort { " · effort {e}" }
+                                }
+                            }
+                            button {
+                                class: "text-red-400 px-2 hover:text-red-300",
+                                onclick: {
+                                    let ctx = ctx.clone();
+                                    let id = s.id;
+                                    move |_| {
+                                        let mut sessions = ctx.sessions;
+                                        if sessions.write().remove(id) {
+                                            save_sessions(&ctx);
+                                        }
+                                    }
+                                },
+                                "\u{2715}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // ---- PROGRESS ----
+            div { class: "space-y-4",
+                div { class: "border border-neutral-700 rounded-lg p-4 bg-neutral-900/30",
+                    h3 { class: "font-bold text-lg mb-2 text-neutral-200", "Volume This Week" }
+                    if volume.is_empty() {
+                        p { class: "text-xs text-neutral-500", "Nothing logged since {week_start}." }
+                    }
+                    for (focus, v) in volume {
+                        div { class: "flex justify-between text-sm font-mono",
+                            span { class: "text-neutral-300 uppercase text-xs", "{focus}" }
+                            span { class: "text-neutral-400", "{v.sets} sets · {v.reps} reps · {v.load:.0} lb" }
+                        }
+                    }
+                }
+
+                div { class: "border border-neutral-700 rounded-lg p-4 bg-neutral-900/30",
+                    div { class: "flex items-center justify-between mb-2",
+                        h3 { class: "font-bold text-lg text-neutral-200", "Estimated 1RM" }
+                        select {
+                            class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm",
+                            value: "{chart_name}",
+                            onchange: move |e| chart_exercise.set(e.value()),
+                            for name in names {
+                                option { value: "{name}", "{name}" }
+                            }
+                        }
+                    }
+                    if history.is_empty() {
+                        p { class: "text-xs text-neutral-500", "Log weighted sets to see a trend." }
+                    }
+                    for (day, e1rm) in history {
+                        div { class: "flex items-center gap-2 text-xs font-mono",
+                            span { class: "w-20 text-neutral-500", "{day}" }
+                            div { class: "flex-1 h-1.5 rounded bg-neutral-800 overflow-hidden",
+                                div { class: "h-full bg-orange-500", style: format!("width: {:.0}%;", e1rm / history_max * 100.0) }
+                            }
+                            span { class: "w-16 text-right text-neutral-300", "{e1rm:.1}" }
+                        }
+                    }
+                }
+
+                div { class: "border border-neutral-700 rounded-lg p-4 bg-neutral-900/30",
+                    h3 { class: "font-bold text-lg mb-2 text-neutral-200", "Personal Records" }
+                    table { class: "w-full text-left text-xs font-mono",
+                        thead { class: "text-neutral-500 uppercase",
+                            tr {
+                                th { class: "py-1", "Exercise" }
+                                th { class: "py-1 text-right", "Best e1RM" }
+                                th { class: "py-1 text-right", "Heaviest" }
+                                th { class: "py-1 text-right", "Most reps" }
+                            }
+                        }
+                        tbody {
+                            for pr in records {
+                                tr { class: "border-t border-neutral-800",
+                                    td { class: "py-1 text-neutral-300", "{pr.exercise}" }
+                                    td { class: "py-1 text-right text-orange-300", title: "Set on {pr.best_1rm_date}", "{pr.best_1rm:.1}" }
+                                    td { class: "py-1 text-right", "{pr.heaviest:.1}" }
+                                    td { class: "py-1 text-right", "{pr.most_reps}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
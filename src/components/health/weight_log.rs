@@ -0,0 +1,261 @@
+//! Logging weight, body fat, tape measures and daily intake, and the chart of
+//! logged and trend weight against the simulation's projection.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::health::history::{history_path_for, parse_measure, parse_tape, Measurement, TrendPoint};
+use crate::utils::json_store::err_to_string;
+
+use super::component::HealthCtx;
+
+/// Write the log beside the health file it came from; failures go to the
+/// status line. Refused while the file on disk is one that didn't load.
+fn save_history(ctx: &HealthCtx) {
+    let path = history_path_for(ctx.logs_path.read().as_str());
+    let mut status = ctx.status;
+    if let Some(e) = ctx.history_error.read().as_ref() {
+        status.set(Some(format!("Not saving weight history: {} didn't load ({e}). Fix it, then Load again.", path.display())));
+        return;
+    }
+    if let Err(e) = ctx.history.read().save(&path) {
+        status.set(Some(format!("Couldn't save weight history to {}: {}", path.display(), err_to_string(e))));
+    }
+}
+
+fn measurement_from(at: NaiveDateTime, weight: &str, body_fat: &str, waist: &str, tape: &str) -> Result<Measurement, String> {
+    Ok(Measurement {
+        id: Uuid::new_v4(),
+        at,
+        weight: parse_measure("Weight", weight)?,
+        body_fat_pct: parse_measure("Body fat", body_fat)?,
+        waist: parse_measure("Waist", waist)?,
+        tape: parse_tape(tape)?,
+    })
+}
+
+#[component]
+pub(super) fn WeightLog(projected: Vec<(NaiveDate, f64)>, on_trend: EventHandler<f64>) -> Element {
+    let ctx = use_context::<HealthCtx>();
+
+    // Log form drafts
+    let mut at = use_signal(|| chrono::Local::now().naive_local().format("%Y-%m-%dT%H:%M").to_string());
+    let mut weight = use_signal(String::new);
+    let mut body_fat = use_signal(String::new);
+    let mut waist = use_signal(String::new);
+    let mut tape = use_signal(String::new);
+    let mut intake_date = use_signal(|| chrono::Local::now().date_naive().to_string());
+    let mut intake_kcal = use_signal(String::new);
+
+    let trend = ctx.history.read().weight_trend();
+    let recent: Vec<Measurement> = ctx.history.read().measurements.iter().rev().take(10).cloned().collect();
+    let recent_intake: Vec<(NaiveDate, f64)> =
+        ctx.history.read().intake.iter().rev().take(7).map(|(d, k)| (*d, *k)).collect();
+
+    let on_log = {
+        let ctx = ctx.clone();
+        move |_| {
+            let mut status = ctx.status;
+            let Ok(when) = NaiveDateTime::parse_from_str(at.read().trim(), "%Y-%m-%dT%H:%M") else {
+                status.set(Some("Pick when the measurement was taken.".into()));
+                return;
+            };
+            let parsed = measurement_from(when, &weight.read(), &body_fat.read(), &waist.read(), &tape.read());
+            let m = match parsed {
+                Ok(m) => m,
+                Err(e) => {
+                    status.set(Some(e));
+                    return;
+                }
+            };
+            if m.weight.is_none() && m.body_fat_pct.is_none() && m.waist.is_none() && m.tape.is_empty() {
+                status.set(Some("Enter at least one measurement.".into()));
+                return;
+            }
+            status.set(Some(format!("Logged measurement for {}.", when.format("%b %d %H:%M"))));
+            let mut history = ctx.history;
+            history.write().add(m);
+            save_history(&ctx);
+            if let Some(w) = history.read().trend_weight() {
+                on_trend.call(w);
+            }
+            weight.set(String::new());
+            body_fat.set(String::new());
+            waist.set(String::new());
+            tape.set(String::new());
+        }
+    };
+
+    let on_intake = {
+        let ctx = ctx.clone();
+        move |_| {
+            let mut status = ctx.status;
+            let Ok(day) = NaiveDate::parse_from_str(intake_date.read().trim(), "%Y-%m-%d") else {
+                status.set(Some("Pick the day the intake is for.".into()));
+                return;
+            };
+            // Blank or 0 clears the day.
+            let kcal = match intake_kcal.read().trim() {
+                "" => 0.0,
+                t => match t.parse::<f64>() {
+                    Ok(v) if v.is_finite() && v >= 0.0 => v,
+                    _ => {
+                        status.set(Some("Intake must be a number of calories.".into()));
+                        return;
+                    }
+                },
+            };
+            let mut history = ctx.history;
+            history.write().set_intake(day, kcal);
+            save_history(&ctx);
+            intake_kcal.set(String::new());
+        }
+    };
+
+    rsx! {
+        div { class: "grid grid-cols-1 lg:grid-cols-2 gap-6 p-4",
+            // ---- LOG A MEASUREMENT ----
+            div { class: "border border-neutral-700 rounded-lg p-4 bg-neutral-900/30 space-y-3",
+                h3 { class: "font-bold text-lg text-neutral-200", "Log Measurements" }
+                div { class: "grid grid-cols-2 gap-2",
+                    input { type: "datetime-local", class: "col-span-2 bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", value: "{at}", oninput: move |e| at.set(e.value()) }
+                    input { type: "number", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Weight (lb)", value: "{weight}", oninput: move |e| weight.set(e.value()) }
+                    input { type: "number", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Body fat %", value: "{body_fat}", oninput: move |e| body_fat.set(e.value()) }
+                    input { type: "number", class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Waist (in)", value: "{waist}", oninput: move |e| waist.set(e.value()) }
+                    input { class: "bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "Other: hips=38, neck=15", value: "{tape}", oninput: move |e| tape.set(e.value()) }
+                }
+                button { class: "w-full px-3 py-1 border border-blue-700 text-blue-300 rounded text-sm hover:bg-blue-900/30", onclick: on_log, "+ Log Measurement" }
+
+                h3 { class: "font-bold text-sm text-neutral-300 pt-2", "Daily Intake" }
+                div { class: "flex gap-2",
+                    input { type: "date", class: "flex-1 bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", value: "{intake_date}", oninput: move |e| intake_date.set(e.value()) }
+                    input { type: "number", class: "w-28 bg-neutral-800 border border-neutral-700 rounded p-1 text-sm", placeholder: "kcal", value: "{intake_kcal}", oninput: move |e| intake_kcal.set(e.value()) }
+                    button { class: "px-3 py-1 border border-neutral-700 rounded text-sm hover:bg-neutral-800", onclick: on_intake, "Set" }
+                }
+                div { class: "flex flex-wrap gap-2 text-xs font-mono text-neutral-500",
+                    for (day, kcal) in recent_intake {
+                        span { key: "{day}", {day.format("%b %d: ").to_string()} span { class: "text-neutral-300", "{kcal:.0}" } }
+                    }
+                }
+
+                div { class: "border-t border-neutral-800 pt-2 space-y-1 max-h-64 overflow-y-auto",
+                    for m in recent {
+                        div { key: "{m.id}", class: "flex items-center justify-between text-sm",
+                            span { class: "text-neutral-300",
+                                {m.at.format("%Y-%m-%d %H:%M ").to_string()}
+                                span { class: "text-neutral-500 text-xs font-mono",
+                                    if let Some(w) = m.weight { "{w:.1} lb " }
+                                    if let Some(bf) = m.body_fat_pct { "· {bf:.1}% " }
+                                    if let Some(w) = m.waist { "· waist {w:.1}\" " }
+                                    for (name, v) in m.tape.iter() { "· {name} {v:.1}\" " }
+                                }
+                            }
+                            button {
+                                class: "text-red-400 px-2 hover:text-red-300",
+                                onclick: {
+                                    let ctx = ctx.clone();
+                                    let id = m.id;
+                                    move |_| {
+                                        let mut history = ctx.history;
+                                        if history.write().remove(id) {
+                                            save_history(&ctx);
+                                        }
+                                    }
+                                },
+                                "\u{2715}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // ---- LOGGED vs PROJECTED ----
+            div { class: "border border-neutral-700 rounded-lg p-4 bg-neutral-900/30",
+                div { class: "flex items-center justify-between mb-2",
+                    h3 { class: "font-bold text-lg text-neutral-200", "Weight: Logged vs Projected" }
+                    if let Some(p) = trend.last() {
+                        span { class: "text-xs font-mono text-orange-300", "Trend {p.trend:.1} lb" }
+                    }
+                }
+                if trend.is_empty() && projected.is_empty() {
+                    p { class: "text-xs text-neutral-500", "Log a weight or set up the simulation to see a chart." }
+                } else {
+                    WeightChart { trend: trend.clone(), projected: projected.clone() }
+                    div { class: "flex gap-4 text-[10px] uppercase text-neutral-500 mt-1",
+                        span { span { class: "text-neutral-400", "● " } "Logged" }
+                        span { span { class: "text-orange-400", "━ " } "Trend" }
+                        span { span { class: "text-blue-400", "┅ " } "Projected" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Clone)]
+struct Dot {
+    cx: String,
+    cy: String,
+}
+
+/// Readings, trend and projection on one date axis.
+#[component]
+fn WeightChart(trend: Vec<TrendPoint>, projected: Vec<(NaiveDate, f64)>) -> Element {
+    let (left, right, top, bottom) = (46.0_f64, 312.0_f64, 12.0_f64, 128.0_f64);
+
+    let dates = trend.iter().map(|p| p.at.date()).chain(projected.iter().map(|(d, _)| *d));
+    let (Some(first), Some(last)) = (dates.clone().min(), dates.max()) else {
+        return rsx! {};
+    };
+    let mut mn = f64::INFINITY;
+    let mut mx = f64::NEG_INFINITY;
+    for v in trend.iter().flat_map(|p| [p.weight, p.trend]).chain(projected.iter().map(|(_, w)| *w)) {
+        mn = mn.min(v);
+        mx = mx.max(v);
+    }
+    if (mx - mn).abs() < 1e-9 {
+        mn -= 1.0;
+        mx += 1.0;
+    }
+    let span = (last - first).num_days().max(1) as f64;
+    let x_at = |d: NaiveDate| left + (d - first).num_days() as f64 / span * (right - left);
+    let y_at = |v: f64| bottom - (v - mn) / (mx - mn) * (bottom - top);
+    let line = |pts: &mut dyn Iterator<Item = (NaiveDate, f64)>| -> String {
+        pts.map(|(d, v)| format!("{:.1},{:.1}", x_at(d), y_at(v)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let trend_poly = line(&mut trend.iter().map(|p| (p.at.date(), p.trend)));
+    let projected_poly = line(&mut projected.iter().copied());
+    let dots: Vec<Dot> = trend
+        .iter()
+        .map(|p| Dot {
+            cx: format!("{:.1}", x_at(p.at.date())),
+            cy: format!("{:.1}", y_at(p.weight)),
+        })
+        .collect();
+    let x_first = first.format("%b %d %Y").to_string();
+    let x_last = last.format("%b %d %Y").to_string();
+
+    rsx! {
+        svg { width: "100%", height: "150", view_box: "0 0 320 160",
+            line { x1: "46", y1: "12", x2: "46", y2: "128", stroke: "#444", stroke_width: "1" }
+            line { x1: "46", y1: "128", x2: "312", y2: "128", stroke: "#444", stroke_width: "1" }
+            if !projected.is_empty() {
+                polyline { points: "{projected_poly}", fill: "none", stroke: "#60a5fa", stroke_width: "1", stroke_dasharray: "3 2", opacity: "0.8" }
+            }
+            if !trend.is_empty() {
+                polyline { points: "{trend_poly}", fill: "none", stroke: "#fb923c", stroke_width: "2" }
+            }
+            for (i, dot) in dots.iter().enumerate() {
+                circle { key: "{i}", cx: "{dot.cx}", cy: "{dot.cy}", r: "2.5", fill: "#a3a3a3" }
+            }
+            text { x: "4", y: "16", fill: "#888", font_size: "9", "{mx:.1}" }
+            text { x: "4", y: "128", fill: "#888", font_size: "9", "{mn:.1}" }
+            text { x: "46", y: "142", fill: "#888", font_size: "9", "{x_first}" }
+            text { x: "250", y: "142", fill: "#888", font_size: "9", "{x_last}" }
+        }
+    }
+}
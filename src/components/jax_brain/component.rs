@@ -24,8 +24,8 @@ use crate::models::jaxBrain::templates::dictionary::{
 use crate::models::jaxBrain::templates::dictionary::{DefinitionEntry};
 
 
-// Default dev path – adjust as needed
-const DEFAULT_DICT_PATH: &str = "assets/data/jaxbrain/blacksLaw/ninthEd.json";
+// Default dev path – adjust as needed. Goals check `jaxbrain:` links against it.
+pub(crate) const DEFAULT_DICT_PATH: &str = "assets/data/jaxbrain/blacksLaw/ninthEd.json";
 
 #[derive(Debug, Clone, Copy, PartialEq, EnumIter, Display)]
 enum TemplateKind {
@@ -46,10 +46,16 @@ impl TemplateKind {
 pub fn JaxBrain(
     #[props(default)]
     overview: bool,
+    /// Deep link: a node id ("term:xyz") to select once the graph is up.
+    #[props(default)]
+    focus: Option<String>,
 ) -> Element {
     let mut template_kind = use_signal(|| TemplateKind::Dictionary);
     let mut file_path     = use_signal(|| DEFAULT_DICT_PATH.to_string());
-    let mut dict_state    = use_signal(|| None::<DictionaryFile>);
+    // A deep link needs the graph, so load the default dictionary straight away.
+    let mut dict_state    = use_signal(|| {
+        focus.as_ref().and_then(|_| load_dictionary_from_path(DEFAULT_DICT_PATH).ok())
+    });
     let mut status        = use_signal(|| None::<String>);
 
     let graph: Option<Graph> = match *template_kind.read() {
@@ -335,6 +341,7 @@ pub fn JaxBrain(
 									status: status,
 									on_add_term: on_add_term,
 									on_edit_selected: on_edit_selected,
+									focus: focus.clone(),
 								}
 
 								if *edit_open.read() {
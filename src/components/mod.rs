@@ -19,8 +19,8 @@ pub use fin_calc::FinCalc;
 mod health;
 pub use health::Health;
 
-// mod goals;
-// pub use goals::Goals;
+pub mod goals;
+pub use goals::{GoalReview, Goals};
 
 mod jax_brain;
 pub use jax_brain::JaxBrain;
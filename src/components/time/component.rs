@@ -12,7 +12,13 @@ use std::collections::BTreeMap;
 use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use dioxus::prelude::*;
 
-use super::model::{Event, EventId, EventSource, Freq, Occurrence, Recurrence, When};
+use crate::models::goals::habit::parse_goal_link;
+use crate::models::goals::review::REVIEW_LINK;
+use crate::Route;
+
+use crate::models::schedule::events::{
+    Event, EventId, EventSource, Freq, Occurrence, Recurrence, When, PLAN_END_HOUR, PLAN_START_HOUR,
+};
 use super::store::{use_time, TimeStore};
 use super::view::{
     add_months, first_of_month, month_abbr, month_name, week_start, CalendarView, Season,
@@ -24,9 +30,7 @@ pub fn today_local() -> NaiveDate {
     chrono::Local::now().date_naive()
 }
 
-// Planner grid bounds.
-const PLAN_START_HOUR: u32 = 6;
-const PLAN_END_HOUR: u32 = 23; // exclusive end of the last start slot
+// Planner grid bounds (the day window lives in model.rs).
 const SLOTS_PER_HOUR: u32 = 2; // 30-minute granularity
 const PLAN_SLOTS: u32 = (PLAN_END_HOUR - PLAN_START_HOUR) * SLOTS_PER_HOUR;
 
@@ -71,15 +75,30 @@ impl Prefill {
 // ---------------------------------------------------------------------------
 
 #[component]
-pub fn Time(#[props(default)] overview: bool) -> Element {
+pub fn Time(
+    #[props(default)] overview: bool,
+    /// Deep link: open on this event's next occurrence with its editor up.
+    #[props(default)]
+    focus_event: Option<EventId>,
+) -> Element {
     let store = use_time();
     let today = today_local();
 
-    let mut current = use_signal(|| CalendarView::today_month(today));
+    let focus = use_hook(|| focus_event.and_then(|id| store.get(id)));
+    let focus_day = focus.as_ref().map(|e| {
+        e.occurrences(today, today + Duration::days(366))
+            .first()
+            .map_or(e.when.anchor_date(), |o| o.date())
+    });
+
+    let mut current = use_signal(|| match focus_day {
+        Some(d) => CalendarView::today_day(d),
+        None => CalendarView::today_month(today),
+    });
     // The active top-level mode: the calendar, or the weekly planner.
     let mut planner_mode = use_signal(|| false);
     // Editor modal target (None = closed).
-    let editing = use_signal(|| Option::<EditTarget>::None);
+    let editing = use_signal(|| focus.map(EditTarget::Edit));
 
     let open_editor = use_callback(move |t: EditTarget| {
         let mut editing = editing;
@@ -1008,6 +1027,14 @@ fn EventEditor(
             .unwrap_or_else(|| init_date.format("%Y-%m-%d").to_string())
     });
 
+    // Sub-app back-reference; kept through edits so re-syncs still match.
+    let init_link = match &target {
+        EditTarget::Edit(e) => e.link.clone(),
+        EditTarget::New(_) => None,
+    };
+    let goal_id = init_link.as_deref().and_then(parse_goal_link);
+    let is_review = init_link.as_deref() == Some(REVIEW_LINK);
+
     let close = move || {
         let mut editing = editing;
         editing.set(None);
@@ -1072,7 +1099,7 @@ fn EventEditor(
             when,
             source: *source.read(),
             recurrence,
-            link: None,
+            link: init_link.clone(),
         };
 
         if editing_id.is_some() {
@@ -1109,6 +1136,7 @@ fn EventEditor(
                                 "Health" => EventSource::Health,
                                 "Jax Brain" => EventSource::JaxBrain,
                                 "Finance" => EventSource::FinCalc,
+                                "Goals" => EventSource::Goals,
                                 _ => EventSource::Manual,
                             };
                             source.set(s);
@@ -1221,6 +1249,12 @@ fn EventEditor(
                     if editing_id.is_some() {
                         button { class: "sched-btn sched-danger", onclick: delete, "Delete" }
                     }
+                    if let Some(goal) = goal_id {
+                        Link { class: "sched-btn", to: Route::GoalView { focus: goal.to_string() }, "Open goal" }
+                    }
+                    if is_review {
+                        Link { class: "sched-btn", to: Route::GoalReviewView {}, "Start review" }
+                    }
                     div { class: "sched-spacer" }
                     button { class: "sched-btn", onclick: move |_| close(), "Cancel" }
                     button { class: "sched-btn sched-primary", onclick: save, "Save" }
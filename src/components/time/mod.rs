@@ -1,10 +1,12 @@
 mod component;
 pub use component::*;
-mod model;
 mod storage;
 mod store;
 mod view;
 
 pub use component::{today_local, Time};
-pub use model::{Event, EventId, EventSource, Freq, Occurrence, Recurrence, When};
+pub use crate::models::schedule::events::{
+    expand_dates, Event, EventId, EventSource, Freq, Occurrence, Recurrence, When, PLAN_END_HOUR,
+    PLAN_START_HOUR,
+};
 pub use store::{use_provide_time, use_time, TimeStore};
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::utils::json_store::{load_json, save_json};
 
-use super::model::Event;
+use crate::models::schedule::events::Event;
 
 /// On-disk shape. Versioned so the format can evolve without silent breakage.
 /// No `next_id` — event IDs are UUIDs generated at insert time.
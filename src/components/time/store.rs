@@ -9,7 +9,7 @@ use chrono::{NaiveDate, NaiveDateTime};
 use dioxus::prelude::*;
 use uuid::Uuid;
 
-use super::model::{Event, EventId, EventSource, Occurrence, Recurrence, When};
+use crate::models::schedule::events::{Event, EventId, EventSource, Occurrence, Recurrence, When};
 use super::storage::{self, SavedState};
 
 /// Cheap, `Copy` handle to the schedule. Hand it around freely.
@@ -85,6 +85,24 @@ impl TimeStore {
         events.write().retain(|e| e.source != source);
     }
 
+    /// Swap the events a sub-app derives from its own data for `fresh`,
+    /// keeping their ids. `owned` picks which stored events get replaced, so
+    /// hand-made events under the same source survive. No-op (and no
+    /// autosave) when nothing changed.
+    pub fn resync(&self, owned: impl Fn(&Event) -> bool, fresh: Vec<Event>) {
+        let mut events = self.events;
+        {
+            let current = events.peek();
+            let mine: Vec<&Event> = current.iter().filter(|e| owned(e)).collect();
+            if mine.len() == fresh.len() && mine.iter().zip(&fresh).all(|(a, b)| *a == b) {
+                return;
+            }
+        }
+        let mut guard = events.write();
+        guard.retain(|e| !owned(e));
+        guard.extend(fresh);
+    }
+
     pub fn get(&self, id: EventId) -> Option<Event> {
         self.events.read().iter().find(|e| e.id == id).cloned()
     }
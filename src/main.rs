@@ -1,5 +1,6 @@
 // The dioxus prelude contains a ton of common items used in dioxus apps. It's a good idea to import wherever you
 // need dioxus
+use crate::components::goals::use_provide_goals;
 use crate::components::time::use_provide_time;
 use dioxus::prelude::*;
 use views::*;
@@ -30,8 +31,12 @@ enum Route {
         #[route("/")]
         Overview {},
 
-		// #[route("/Goals")]
-  //       GoalView {},
+		// `focus` is a goal id to open on.
+		#[route("/Goals?:focus")]
+        GoalView { focus: String },
+
+		#[route("/Goals/Review")]
+        GoalReviewView {},
 
 		#[route("/Health")]
 		HealthView {},
@@ -39,11 +44,13 @@ enum Route {
 		#[route("/FinCalc")]
         FinCalcView {},
 
-		#[route("/JaxBrain")]
-        JaxBrainView {},
+		// `focus` is a graph node id ("term:xyz") to open on.
+		#[route("/JaxBrain?:focus")]
+        JaxBrainView { focus: String },
 
-        #[route("/Time")]
-        TimeView {},
+        // `event` is an event id to open on.
+        #[route("/Time?:event")]
+        TimeView { event: String },
 
 	//#[end_layout]
     //#[layout()]
@@ -75,6 +82,7 @@ fn main() {
 #[component]
 fn App() -> Element {
     use_provide_time();
+    use_provide_goals();
     // The `rsx!` macro lets us define HTML inside of rust. It expands to an Element with all of our HTML inside.
     rsx! {
         // In addition to element and text (which we will see later), rsx can contain other components. In this case,
@@ -0,0 +1,260 @@
+// src/models/finCalc/balances.rs
+//! Per-account balance history.
+//!
+//! Each asset and liability keeps dated values in its own currency, so editing
+//! a value no longer loses the old one. Reconciling records every account on
+//! the same day (like checking all statements at once). The allocation history
+//! reads those points back to show how the asset mix has drifted.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use super::finances::{money_cents, AssetEntry, AssetKind, FinancesFile, LiabilityEntry};
+
+/// An account's value on one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValuePoint {
+    pub date: Date,
+    #[serde(with = "money_cents")]
+    pub value: i64, // cents, entry's currency
+}
+
+/// Insert or overwrite the point on `date`, keeping `points` sorted.
+fn upsert(points: &mut Vec<ValuePoint>, date: Date, value: i64) {
+    match points.binary_search_by_key(&date, |p| p.date) {
+        Ok(i) => points[i].value = value,
+        Err(i) => points.insert(i, ValuePoint { date, value }),
+    }
+}
+
+/// Record `value` on `date`. Returns the value the entry should now show: the
+/// newest point's. The first update also keeps the pre-history value, dated
+/// when the entry was added, so it isn't lost.
+fn record(
+    points: &mut Vec<ValuePoint>,
+    current: i64,
+    created_at: Option<OffsetDateTime>,
+    date: Date,
+    value: i64,
+) -> i64 {
+    if points.is_empty() {
+        if let Some(since) = created_at.map(|t| t.date()).filter(|d| *d < date) {
+            points.push(ValuePoint {
+                date: since,
+                value: current,
+            });
+        }
+    }
+    upsert(points, date, value);
+    points.last().map_or(value, |p| p.value)
+}
+
+/// Latest value at or before `date`, if any point is that old.
+pub fn value_on(points: &[ValuePoint], date: Date) -> Option<i64> {
+    points
+        .iter()
+        .rev()
+        .find(|p| p.date <= date)
+        .map(|p| p.value)
+}
+
+pub fn remove_point(points: &mut Vec<ValuePoint>, date: Date) -> bool {
+    let before = points.len();
+    points.retain(|p| p.date != date);
+    points.len() != before
+}
+
+impl AssetEntry {
+    /// Record the value on `date`. Back-dated points don't change `value`
+    /// unless they're the newest.
+    pub fn record_value(&mut self, date: Date, value: i64) {
+        self.value = record(&mut self.balances, self.value, self.created_at, date, value);
+    }
+}
+
+impl LiabilityEntry {
+    pub fn record_balance(&mut self, date: Date, balance: i64) {
+        self.balance = record(
+            &mut self.balances,
+            self.balance,
+            self.created_at,
+            date,
+            balance,
+        );
+    }
+}
+
+/// Asset totals by kind on one day, base-currency cents, in `AssetKind::ALL`
+/// order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationPoint {
+    pub date: Date,
+    pub by_kind: [i64; AssetKind::ALL.len()],
+}
+
+impl AllocationPoint {
+    pub fn total(&self) -> i64 {
+        self.by_kind.iter().filter(|v| **v > 0).sum()
+    }
+
+    pub fn value(&self, kind: AssetKind) -> i64 {
+        let i = AssetKind::ALL.iter().position(|k| *k == kind).unwrap_or(0);
+        self.by_kind[i]
+    }
+
+    /// Percent of positive assets held in `kind`.
+    pub fn share(&self, kind: AssetKind) -> f64 {
+        let total = self.total();
+        if total <= 0 {
+            return 0.0;
+        }
+        self.value(kind).max(0) as f64 / total as f64 * 100.0
+    }
+}
+
+impl FinancesFile {
+    /// Record every account's balance on `date`. `values` overrides the
+    /// current figure for the accounts it names; the rest are recorded as
+    /// they stand.
+    pub fn reconcile_balances(&mut self, date: Date, values: &[(Uuid, i64)]) {
+        let given = |id: Uuid| values.iter().find(|(v, _)| *v == id).map(|(_, c)| *c);
+        for a in self.assets.iter_mut() {
+            let v = given(a.id).unwrap_or(a.value);
+            a.record_value(date, v);
+        }
+        for l in self.liabilities.iter_mut() {
+            let v = given(l.id).unwrap_or(l.balance);
+            l.record_balance(date, v);
+        }
+    }
+
+    /// Asset mix on every day some asset has a point, plus `today` from the
+    /// current values. Accounts count from their first point; ones without
+    /// history only show up today. Converted at today's rates.
+    pub fn allocation_history(&self, today: Date) -> Vec<AllocationPoint> {
+        let mut dates: BTreeSet<Date> = self
+            .assets
+            .iter()
+            .flat_map(|a| a.balances.iter().map(|p| p.date))
+            .filter(|d| *d < today)
+            .collect();
+        dates.insert(today);
+        dates
+            .into_iter()
+            .map(|date| {
+                let mut by_kind = [0; AssetKind::ALL.len()];
+                for a in self.assets.iter() {
+                    let v = if date == today {
+                        Some(a.value)
+                    } else {
+                        value_on(&a.balances, date)
+                    };
+                    let Some(v) = v else { continue };
+                    let i = AssetKind::ALL
+                        .iter()
+                        .position(|k| *k == a.kind)
+                        .unwrap_or(0);
+                    by_kind[i] += self.currencies.to_base(v, a.currency.as_deref()).unwrap_or(0);
+                }
+                AllocationPoint { date, by_kind }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    fn at(date: Date) -> Option<OffsetDateTime> {
+        Some(date.midnight().assume_utc())
+    }
+
+    #[test]
+    fn first_update_keeps_the_old_value() {
+        let mut a = AssetEntry {
+            value: 1000,
+            created_at: at(d(2026, 1, 1)),
+            ..AssetEntry::new()
+        };
+        a.record_value(d(2026, 3, 1), 1500);
+        a.record_value(d(2026, 3, 1), 1600);
+        assert_eq!(a.value, 1600);
+        assert_eq!(
+            a.balances,
+            vec![
+                ValuePoint {
+                    date: d(2026, 1, 1),
+                    value: 1000
+                },
+                ValuePoint {
+                    date: d(2026, 3, 1),
+                    value: 1600
+                },
+            ]
+        );
+
+        // Back-dated: history grows, the current value stays.
+        a.record_value(d(2026, 2, 1), 1200);
+        assert_eq!(a.value, 1600);
+        assert_eq!(value_on(&a.balances, d(2026, 2, 15)), Some(1200));
+        assert_eq!(value_on(&a.balances, d(2025, 12, 31)), None);
+    }
+
+    #[test]
+    fn reconcile_records_every_account() {
+        let mut f = FinancesFile::default();
+        let cash = AssetEntry {
+            value: 500,
+            ..AssetEntry::new()
+        };
+        let cash_id = cash.id;
+        f.assets.push(cash);
+        f.assets.push(AssetEntry {
+            value: 900,
+            ..AssetEntry::new()
+        });
+        f.liabilities.push(LiabilityEntry {
+            balance: 300,
+            ..LiabilityEntry::new()
+        });
+        let day = OffsetDateTime::now_utc().date();
+        f.reconcile_balances(day, &[(cash_id, 700)]);
+        assert_eq!(f.assets[0].value, 700);
+        assert_eq!(f.assets[1].balances.last().unwrap().value, 900);
+        assert_eq!(f.liabilities[0].balances.last().unwrap().value, 300);
+    }
+
+    #[test]
+    fn allocation_drifts_with_recorded_values() {
+        let mut f = FinancesFile::default();
+        let mut cash = AssetEntry {
+            kind: AssetKind::Cash,
+            created_at: None,
+            ..AssetEntry::new()
+        };
+        cash.record_value(d(2026, 1, 1), 7500);
+        let mut stocks = AssetEntry {
+            kind: AssetKind::Investment,
+            created_at: None,
+            ..AssetEntry::new()
+        };
+        stocks.record_value(d(2026, 1, 1), 2500);
+        stocks.record_value(d(2026, 6, 1), 7500);
+        f.assets.push(cash);
+        f.assets.push(stocks);
+
+        let pts = f.allocation_history(d(2026, 7, 1));
+        assert_eq!(pts.len(), 3);
+        assert_eq!(pts[0].share(AssetKind::Investment), 25.0);
+        assert_eq!(pts[1].share(AssetKind::Investment), 50.0);
+        assert_eq!(pts[2].total(), 15000);
+    }
+}
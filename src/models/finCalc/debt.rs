@@ -0,0 +1,299 @@
+// src/models/finCalc/debt.rs
+//! Loan amortization and multi-debt payoff planning for `LiabilityEntry`.
+//!
+//! Interest accrues monthly at `apr / 12` on the outstanding balance and is
+//! rounded to the cent each period. Payments land on the liability's
+//! `payment_day`.
+
+use time::Date;
+use uuid::Uuid;
+
+use super::finances::LiabilityEntry;
+use super::projection::add_months;
+
+/// Hard stop for schedules that never converge (payment ≤ interest). 100 years.
+const MAX_MONTHS: usize = 1200;
+
+/// First `day`-of-month on or after `from`.
+pub fn next_payment_date(from: Date, day: u8) -> Date {
+    let day = day.clamp(1, 28);
+    let this_month = from.replace_day(day).unwrap_or(from);
+    if this_month >= from {
+        this_month
+    } else {
+        add_months(this_month, 1)
+    }
+}
+
+fn monthly_interest(balance: i64, apr: f64) -> i64 {
+    (balance as f64 * apr / 100.0 / 12.0).round() as i64
+}
+
+// ---------------------------------------------------------------------------
+// Single-loan amortization
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmortizationRow {
+    pub date: Date,
+    pub payment: i64,
+    pub interest: i64,
+    pub principal: i64,
+    /// Balance left after this payment.
+    pub balance: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Amortization {
+    pub rows: Vec<AmortizationRow>,
+    /// `None` when the payment never outruns the interest.
+    pub payoff: Option<Date>,
+    pub total_interest: i64,
+}
+
+/// Month-by-month schedule for paying `l` off with its minimum payment plus
+/// `extra` cents, starting with the first payment date on or after `from`.
+pub fn amortize(l: &LiabilityEntry, from: Date, extra: i64) -> Amortization {
+    let payment = l.min_payment + extra.max(0);
+    let first = next_payment_date(from, l.payment_day);
+    let mut rows = Vec::new();
+    let mut balance = l.balance;
+    let mut total_interest = 0;
+
+    while balance > 0 && rows.len() < MAX_MONTHS {
+        let interest = monthly_interest(balance, l.apr);
+        if payment <= interest {
+            return Amortization {
+                rows,
+                payoff: None,
+                total_interest,
+            };
+        }
+        let pay = payment.min(balance + interest);
+        total_interest += interest;
+        balance = balance + interest - pay;
+        rows.push(AmortizationRow {
+            date: add_months(first, rows.len() as i32),
+            payment: pay,
+            interest,
+            principal: pay - interest,
+            balance,
+        });
+    }
+
+    let payoff = if balance <= 0 {
+        rows.last().map(|r| r.date).or(Some(from))
+    } else {
+        None
+    };
+    Amortization {
+        rows,
+        payoff,
+        total_interest,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Snowball vs avalanche
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayoffStrategy {
+    /// Smallest balance first.
+    Snowball,
+    /// Highest APR first.
+    Avalanche,
+}
+
+impl PayoffStrategy {
+    pub const ALL: [PayoffStrategy; 2] = [PayoffStrategy::Snowball, PayoffStrategy::Avalanche];
+    pub fn label(&self) -> &'static str {
+        match self {
+            PayoffStrategy::Snowball => "Snowball",
+            PayoffStrategy::Avalanche => "Avalanche",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebtPayoff {
+    pub id: Uuid,
+    pub name: String,
+    pub payoff: Option<Date>,
+    pub total_interest: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayoffPlan {
+    pub strategy: PayoffStrategy,
+    /// In the same order as the input liabilities.
+    pub debts: Vec<DebtPayoff>,
+    /// When the last debt is cleared; `None` if the budget never gets there.
+    pub payoff: Option<Date>,
+    pub total_interest: i64,
+}
+
+/// Pay every debt's minimum each month, and throw `extra` cents — plus the
+/// minimums freed up by debts already cleared — at one target at a time,
+/// chosen by `strategy`.
+pub fn plan_payoff(
+    liabilities: &[LiabilityEntry],
+    from: Date,
+    extra: i64,
+    strategy: PayoffStrategy,
+) -> PayoffPlan {
+    let budget: i64 = liabilities.iter().map(|l| l.min_payment).sum::<i64>() + extra.max(0);
+    let mut balances: Vec<i64> = liabilities.iter().map(|l| l.balance.max(0)).collect();
+    let mut interest = vec![0_i64; liabilities.len()];
+    let mut payoff: Vec<Option<Date>> =
+        balances.iter().map(|b| (*b == 0).then_some(from)).collect();
+
+    let mut month = 0;
+    while balances.iter().any(|b| *b > 0) && month < MAX_MONTHS {
+        let mut left = budget;
+
+        for (i, l) in liabilities.iter().enumerate() {
+            if balances[i] > 0 {
+                let accrued = monthly_interest(balances[i], l.apr);
+                interest[i] += accrued;
+                balances[i] += accrued;
+            }
+        }
+        for (i, l) in liabilities.iter().enumerate() {
+            let pay = l.min_payment.min(balances[i]).min(left);
+            balances[i] -= pay;
+            left -= pay;
+        }
+
+        let mut order: Vec<usize> = (0..liabilities.len())
+            .filter(|i| balances[*i] > 0)
+            .collect();
+        match strategy {
+            PayoffStrategy::Snowball => order.sort_by_key(|i| balances[*i]),
+            PayoffStrategy::Avalanche => order.sort_by(|a, b| {
+                liabilities[*b]
+                    .apr
+                    .total_cmp(&liabilities[*a].apr)
+                    .then(balances[*a].cmp(&balances[*b]))
+            }),
+        }
+        for i in order {
+            if left <= 0 {
+                break;
+            }
+            let pay = left.min(balances[i]);
+            balances[i] -= pay;
+            left -= pay;
+        }
+
+        for (i, l) in liabilities.iter().enumerate() {
+            if balances[i] == 0 && payoff[i].is_none() {
+                let first = next_payment_date(from, l.payment_day);
+                payoff[i] = Some(add_months(first, month as i32));
+            }
+        }
+        month += 1;
+    }
+
+    let debts: Vec<DebtPayoff> = liabilities
+        .iter()
+        .enumerate()
+        .map(|(i, l)| DebtPayoff {
+            id: l.id,
+            name: l.name.clone(),
+            payoff: payoff[i],
+            total_interest: interest[i],
+        })
+        .collect();
+    let overall = if payoff.iter().all(|p| p.is_some()) {
+        payoff.iter().flatten().max().copied()
+    } else {
+        None
+    };
+    PayoffPlan {
+        strategy,
+        total_interest: interest.iter().sum(),
+        debts,
+        payoff: overall,
+    }
+}
+
+/// Both strategies side by side, in `PayoffStrategy::ALL` order.
+pub fn compare_strategies(
+    liabilities: &[LiabilityEntry],
+    from: Date,
+    extra: i64,
+) -> Vec<PayoffPlan> {
+    PayoffStrategy::ALL
+        .iter()
+        .map(|s| plan_payoff(liabilities, from, extra, *s))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    fn loan(name: &str, balance: i64, apr: f64, min_payment: i64) -> LiabilityEntry {
+        LiabilityEntry {
+            name: name.into(),
+            balance,
+            apr,
+            min_payment,
+            payment_day: 1,
+            ..LiabilityEntry::new()
+        }
+    }
+
+    #[test]
+    fn zero_interest_pays_down_evenly() {
+        let l = loan("Car", 300000, 0.0, 100000);
+        let a = amortize(&l, d(2026, 1, 15), 0);
+        assert_eq!(a.rows.len(), 3);
+        assert_eq!(a.total_interest, 0);
+        assert_eq!(a.payoff, Some(d(2026, 4, 1)));
+    }
+
+    #[test]
+    fn interest_accrues_and_last_payment_is_partial() {
+        // $1,000 at 12% APR => 1%/mo; $500/mo clears it in 3 payments.
+        let l = loan("Card", 100000, 12.0, 50000);
+        let a = amortize(&l, d(2026, 1, 1), 0);
+        assert_eq!(a.rows[0].interest, 1000);
+        assert_eq!(a.rows[0].balance, 51000);
+        assert_eq!(a.rows.len(), 3);
+        let last = a.rows.last().unwrap();
+        assert_eq!(last.balance, 0);
+        assert!(last.payment < 50000);
+        assert_eq!(
+            a.total_interest,
+            a.rows.iter().map(|r| r.interest).sum::<i64>()
+        );
+    }
+
+    #[test]
+    fn payment_below_interest_never_pays_off() {
+        let l = loan("Bad", 1000000, 24.0, 10000);
+        let a = amortize(&l, d(2026, 1, 1), 0);
+        assert_eq!(a.payoff, None);
+    }
+
+    #[test]
+    fn avalanche_beats_snowball_on_interest() {
+        let debts = vec![
+            loan("Small low", 100000, 3.0, 2500),
+            loan("Big high", 500000, 24.0, 10000),
+        ];
+        let plans = compare_strategies(&debts, d(2026, 1, 1), 50000);
+        let (snow, aval) = (&plans[0], &plans[1]);
+        assert!(snow.payoff.is_some() && aval.payoff.is_some());
+        assert!(aval.total_interest < snow.total_interest);
+        // Snowball clears the small debt first.
+        assert!(snow.debts[0].payoff < snow.debts[1].payoff);
+    }
+}
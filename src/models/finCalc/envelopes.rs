@@ -0,0 +1,363 @@
+// src/models/finCalc/envelopes.rs
+//! Budget envelopes: dividing up what's left each month.
+//!
+//! Each envelope gets a monthly allocation, either fixed or a share of
+//! `envelope_base()`: take-home pay less the expenses no envelope pays for.
+//! Spending comes from the expense `CashFlow`s assigned to it
+//! (on the dates they land) plus manually entered amounts. Whatever isn't spent
+//! rolls over into the next month; overspending doesn't carry a debt forward.
+//!
+//! Rollover needs what each past month was given, not today's allocation, so
+//! closed months are recorded in `funded` before the allocation changes and
+//! whenever the file is saved. Months without a record use the current one.
+
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+use super::finances::{money_cents, monthly_total, CashFlow, FinancesFile};
+use super::projection::{add_months, flow_dates};
+
+/// Months walked when computing rollover, so a very old start date can't stall
+/// the UI. Ten years.
+const MAX_ROLLOVER_MONTHS: i32 = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Allocation {
+    /// Cents per month, in base currency.
+    Fixed(#[serde(with = "money_cents")] i64),
+    /// Percent of the envelope base: take-home income minus expenses not
+    /// assigned to an envelope. Assigned expenses are charged to their
+    /// envelope, so they aren't taken off twice.
+    Percent(f64),
+}
+
+impl Default for Allocation {
+    fn default() -> Self {
+        Allocation::Fixed(0)
+    }
+}
+
+impl Allocation {
+    pub const LABELS: [&'static str; 2] = ["Fixed", "% of net"];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Allocation::Fixed(_) => "Fixed",
+            Allocation::Percent(_) => "% of net",
+        }
+    }
+
+    /// This month's allocation in base cents. A negative base funds nothing.
+    pub fn cents(&self, base: i64) -> i64 {
+        match *self {
+            Allocation::Fixed(c) => c.max(0),
+            Allocation::Percent(p) => (base.max(0) as f64 * p / 100.0).round() as i64,
+        }
+    }
+}
+
+/// What a closed month was allocated, in base cents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FundedMonth {
+    /// First day of the month.
+    pub month: Date,
+    #[serde(with = "money_cents")]
+    pub cents: i64,
+}
+
+/// Spending entered by hand (cash, a purchase not covered by a flow).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManualSpend {
+    pub id: Uuid,
+    pub date: Date,
+    #[serde(with = "money_cents")]
+    pub amount: i64, // cents, base currency
+    #[serde(default)]
+    pub note: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Envelope {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub allocation: Allocation,
+    /// First month the envelope is funded; rollover accumulates from here.
+    pub start: Date,
+    #[serde(default)]
+    pub spending: Vec<ManualSpend>,
+    /// Recorded allocations of closed months, oldest first.
+    #[serde(default)]
+    pub funded: Vec<FundedMonth>,
+}
+
+impl Envelope {
+    pub fn new(name: &str, start: Date) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            allocation: Allocation::default(),
+            start: first_of_month(start),
+            spending: Vec::new(),
+            funded: Vec::new(),
+        }
+    }
+
+    /// What `month` was (or is) allocated: its record if it has one,
+    /// otherwise the current allocation of `base`.
+    pub fn allocated_in(&self, month: Date, base: i64) -> i64 {
+        self.funded
+            .iter()
+            .find(|f| f.month == month)
+            .map(|f| f.cents)
+            .unwrap_or_else(|| self.allocation.cents(base))
+    }
+
+    /// Record the current allocation for every month before `today`'s that
+    /// has no record yet. Called before the allocation changes, so past
+    /// months keep what they were given.
+    pub fn close_months(&mut self, base: i64, today: Date) {
+        let current = first_of_month(today);
+        let cents = self.allocation.cents(base);
+        let mut month = first_of_month(self.start).max(add_months(current, -MAX_ROLLOVER_MONTHS));
+        while month < current {
+            if !self.funded.iter().any(|f| f.month == month) {
+                self.funded.push(FundedMonth { month, cents });
+            }
+            month = add_months(month, 1);
+        }
+        self.funded.sort_by_key(|f| f.month);
+    }
+}
+
+fn first_of_month(d: Date) -> Date {
+    d.replace_day(1).unwrap_or(d)
+}
+
+fn last_of_month(d: Date) -> Date {
+    d.replace_day(d.month().length(d.year())).unwrap_or(d)
+}
+
+/// One envelope's standing for one month, in base cents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvelopeMonth {
+    /// First day of the month.
+    pub month: Date,
+    pub allocated: i64,
+    /// Unspent money carried in from earlier months.
+    pub rolled_in: i64,
+    pub spent: i64,
+}
+
+impl EnvelopeMonth {
+    pub fn available(&self) -> i64 {
+        self.allocated + self.rolled_in
+    }
+    pub fn left(&self) -> i64 {
+        self.available() - self.spent
+    }
+    pub fn is_over(&self) -> bool {
+        self.left() < 0
+    }
+}
+
+/// Spending charged to `env` in `[from, to]`: every landing of an assigned
+/// expense flow plus manual entries.
+pub fn spent_between(file: &FinancesFile, env: &Envelope, from: Date, to: Date) -> i64 {
+    let flows: i64 = file
+        .expenses
+        .iter()
+        .filter(|f| f.envelope == Some(env.id))
+        .filter_map(|f| {
+            let each = file.currencies.to_base(f.amount, f.currency.as_deref())?;
+            Some(each * flow_dates(f, from, to).len() as i64)
+        })
+        .sum();
+    let manual: i64 = env
+        .spending
+        .iter()
+        .filter(|s| s.date >= from && s.date <= to)
+        .map(|s| s.amount)
+        .sum();
+    flows + manual
+}
+
+/// The envelope's month containing `as_of`, with spending counted up to and
+/// including `as_of`. Earlier months count in full for rollover.
+pub fn envelope_month(file: &FinancesFile, env: &Envelope, as_of: Date) -> EnvelopeMonth {
+    let target = first_of_month(as_of);
+    let base = file.envelope_base();
+
+    let mut month = first_of_month(env.start).max(add_months(target, -MAX_ROLLOVER_MONTHS));
+    let mut rolled_in = 0;
+    while month < target {
+        let spent = spent_between(file, env, month, last_of_month(month));
+        rolled_in = (rolled_in + env.allocated_in(month, base) - spent).max(0);
+        month = add_months(month, 1);
+    }
+    if target < first_of_month(env.start) {
+        return EnvelopeMonth {
+            month: target,
+            allocated: 0,
+            rolled_in: 0,
+            spent: 0,
+        };
+    }
+    EnvelopeMonth {
+        month: target,
+        allocated: env.allocated_in(target, base),
+        rolled_in,
+        spent: spent_between(file, env, target, as_of),
+    }
+}
+
+impl FinancesFile {
+    /// Monthly take-home minus expenses that aren't charged to an envelope:
+    /// what envelopes have to divide up.
+    pub fn envelope_base(&self) -> i64 {
+        let unassigned: Vec<CashFlow> = self
+            .expenses
+            .iter()
+            .filter(|f| !f.envelope.is_some_and(|id| self.envelopes.iter().any(|e| e.id == id)))
+            .cloned()
+            .collect();
+        self.monthly_income() - self.monthly_withheld() - monthly_total(&unassigned, &self.currencies)
+    }
+
+    /// Envelope base not yet given to an envelope (negative = over-allocated).
+    pub fn unallocated(&self) -> i64 {
+        let base = self.envelope_base();
+        base - self
+            .envelopes
+            .iter()
+            .map(|e| e.allocation.cents(base))
+            .sum::<i64>()
+    }
+
+    /// Change an envelope's allocation from this month on; earlier months
+    /// keep what they were given.
+    pub fn set_envelope_allocation(&mut self, id: Uuid, allocation: Allocation, today: Date) {
+        let base = self.envelope_base();
+        if let Some(e) = self.envelopes.iter_mut().find(|e| e.id == id) {
+            e.close_months(base, today);
+            e.allocation = allocation;
+        }
+    }
+
+    /// Record closed months for every envelope (see `Envelope::close_months`).
+    pub fn close_envelope_months(&mut self, today: Date) {
+        let base = self.envelope_base();
+        for e in self.envelopes.iter_mut() {
+            e.close_months(base, today);
+        }
+    }
+
+    /// Envelopes whose spending this month has passed what's available.
+    pub fn overspent_envelopes(&self, as_of: Date) -> Vec<(&Envelope, EnvelopeMonth)> {
+        self.envelopes
+            .iter()
+            .map(|e| (e, envelope_month(self, e, as_of)))
+            .filter(|(_, m)| m.is_over())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::finCalc::finances::Frequency;
+    use time::Month;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    fn spend(date: Date, amount: i64) -> ManualSpend {
+        ManualSpend {
+            id: Uuid::new_v4(),
+            date,
+            amount,
+            note: String::new(),
+        }
+    }
+
+    #[test]
+    fn unspent_rolls_over_and_overspend_does_not() {
+        let mut file = FinancesFile::default();
+        let mut env = Envelope::new("Fun", d(2026, 1, 10));
+        env.allocation = Allocation::Fixed(10000);
+        env.spending.push(spend(d(2026, 1, 5), 4000)); // Jan: 60 left
+        env.spending.push(spend(d(2026, 2, 5), 25000)); // Feb: over by 90
+        env.spending.push(spend(d(2026, 3, 20), 3000));
+        file.envelopes.push(env.clone());
+
+        let feb = envelope_month(&file, &env, d(2026, 2, 28));
+        assert_eq!(
+            (feb.rolled_in, feb.available(), feb.left()),
+            (6000, 16000, -9000)
+        );
+        assert!(feb.is_over());
+        assert_eq!(file.overspent_envelopes(d(2026, 2, 28)).len(), 1);
+
+        // Spending is counted to date: the 20th hasn't happened on the 10th.
+        let mar = envelope_month(&file, &env, d(2026, 3, 10));
+        assert_eq!((mar.rolled_in, mar.spent, mar.left()), (0, 0, 10000));
+    }
+
+    #[test]
+    fn assigned_flows_and_percent_allocation() {
+        let mut file = FinancesFile::default();
+        let mut pay = CashFlow::new("Pay");
+        pay.amount = 400000;
+        file.income.push(pay);
+        let env = Envelope {
+            allocation: Allocation::Percent(10.0),
+            ..Envelope::new("Streaming", d(2026, 1, 1))
+        };
+        let mut netflix = CashFlow::new("Netflix");
+        netflix.amount = 1500;
+        netflix.frequency = Frequency::Weekly;
+        netflix.date = d(2026, 1, 1);
+        netflix.envelope = Some(env.id);
+        file.expenses.push(netflix);
+        let mut rent = CashFlow::new("Rent");
+        rent.amount = 150000;
+        file.expenses.push(rent);
+        file.envelopes.push(env.clone());
+
+        // Rent isn't in an envelope, so it comes off the base; Netflix is
+        // charged to its envelope instead of being taken off up front.
+        let base = file.envelope_base();
+        assert_eq!(base, file.monthly_income() - file.monthly_withheld() - 150000);
+        let m = envelope_month(&file, &env, d(2026, 1, 31));
+        assert_eq!(m.allocated, (base as f64 * 0.10).round() as i64);
+        // Thursdays in Jan 2026: 1, 8, 15, 22, 29.
+        assert_eq!(m.spent, 5 * 1500);
+        assert_eq!(file.unallocated(), base - m.allocated);
+    }
+
+    #[test]
+    fn allocation_change_keeps_past_months() {
+        let mut file = FinancesFile::default();
+        let mut env = Envelope::new("Fun", d(2026, 1, 1));
+        env.allocation = Allocation::Fixed(10000);
+        env.spending.push(spend(d(2026, 1, 5), 4000)); // Jan: 60 left
+        let id = env.id;
+        file.envelopes.push(env);
+
+        // Cut to 20 in March: Jan and Feb were still funded at 100.
+        file.set_envelope_allocation(id, Allocation::Fixed(2000), d(2026, 3, 2));
+        let env = file.envelopes[0].clone();
+        assert_eq!(env.funded.len(), 2);
+        let mar = envelope_month(&file, &env, d(2026, 3, 31));
+        assert_eq!((mar.allocated, mar.rolled_in), (2000, 6000 + 10000));
+
+        // Closing again doesn't overwrite what was recorded.
+        file.close_envelope_months(d(2026, 4, 1));
+        let env = &file.envelopes[0];
+        assert_eq!(env.allocated_in(d(2026, 1, 1), 0), 10000);
+        assert_eq!(env.allocated_in(d(2026, 3, 1), 0), 2000);
+        assert_eq!(env.funded.len(), 3);
+    }
+}
@@ -1,17 +1,29 @@
 // src/models/finCalc/finances.rs
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use time::{Date, OffsetDateTime};
 use uuid::Uuid;
 
+use super::balances::ValuePoint;
+use super::envelopes::{Allocation, Envelope};
+use super::history::HistoryRetention;
+use super::ledger::{CsvMapping, MatchRule, Transaction};
+use super::scenario::Scenario;
+use super::tax::{TaxBreakdown, TaxSettings};
+use crate::utils::json_store::{load_json, StoreResult};
+
 /// Seed/sample path (checked into the repo). The live app reads/writes a copy
 /// outside the project tree — see `default_finances_path()` in the component —
 /// so editing doesn't trigger `dx serve` rebuild loops.
 pub const DEFAULT_FIN_PATH: &str = "assets/data/finances.json";
 
+/// On-disk format version. Files without one are version 1; `migrate` brings
+/// them up to date on load.
+pub const FINANCES_VERSION: u32 = 2;
+
 // ---------------------------------------------------------------------------
-// Frequency — how often a cash flow repeats. Supersedes tranche.rs::Frequency
-// (this one is serde-friendly and carries the monthly-normalization math the
-// dashboard needs).
+// Frequency — how often a cash flow repeats. Carries the monthly-normalization
+// math the dashboard needs.
 // ---------------------------------------------------------------------------
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -21,7 +33,10 @@ pub enum Frequency {
     Weekly,
     Monthly,
     Yearly,
+    /// Also accepts the older `XDays` name.
+    #[serde(alias = "XDays")]
     EveryNDays(u32),
+    #[serde(alias = "XMonths")]
     EveryNMonths(u32),
 }
 
@@ -124,6 +139,37 @@ impl AssetKind {
             .find(|k| k.label() == s)
             .unwrap_or(AssetKind::Other)
     }
+    /// Class an asset of this kind falls in unless the entry overrides it.
+    pub fn default_class(&self) -> AssetClass {
+        match self {
+            AssetKind::Cash | AssetKind::Investment => AssetClass::Soft,
+            AssetKind::Property | AssetKind::Vehicle | AssetKind::Other => AssetClass::Hard,
+        }
+    }
+}
+
+/// Hard assets are physical things you'd have to sell (property, vehicles,
+/// collectibles); soft assets are financial and liquid (cash, investments).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AssetClass {
+    Hard,
+    Soft,
+}
+
+impl AssetClass {
+    pub const ALL: [AssetClass; 2] = [AssetClass::Hard, AssetClass::Soft];
+    pub fn label(&self) -> &'static str {
+        match self {
+            AssetClass::Hard => "Hard",
+            AssetClass::Soft => "Soft",
+        }
+    }
+    pub fn from_label(s: &str) -> AssetClass {
+        AssetClass::ALL
+            .into_iter()
+            .find(|k| k.label() == s)
+            .unwrap_or(AssetClass::Hard)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -168,7 +214,7 @@ impl LiabilityKind {
 // Always stored as integer cents.
 // ---------------------------------------------------------------------------
 
-mod money_cents {
+pub(crate) mod money_cents {
     use serde::{de, Deserialize, Deserializer, Serializer};
 
     pub fn serialize<S>(cents: &i64, s: S) -> Result<S::Ok, S::Error>
@@ -211,12 +257,98 @@ fn today() -> Date {
     OffsetDateTime::now_utc().date()
 }
 
+// ---------------------------------------------------------------------------
+// Currencies. Entries carry an optional ISO 4217 code; `None` means the file's
+// base currency, which is how files from before multi-currency load. Totals
+// are always converted to the base.
+// ---------------------------------------------------------------------------
+
+pub const DEFAULT_BASE_CURRENCY: &str = "USD";
+
+/// Upper-cased, trimmed currency code ("eur " -> "EUR").
+pub fn normalize_code(code: &str) -> String {
+    code.trim().to_ascii_uppercase()
+}
+
+/// Display prefix for a code. Unknown codes fall back to "XYZ ".
+pub fn currency_symbol(code: &str) -> String {
+    match code {
+        "USD" => "$".into(),
+        "EUR" => "€".into(),
+        "GBP" => "£".into(),
+        "JPY" | "CNY" => "¥".into(),
+        "INR" => "₹".into(),
+        "CAD" => "C$".into(),
+        "AUD" => "A$".into(),
+        other => format!("{other} "),
+    }
+}
+
+/// The base currency plus a user-maintained rate table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Currencies {
+    #[serde(default = "default_base_currency")]
+    pub base: String,
+    /// Units of base currency per one unit of each listed currency (with a USD
+    /// base, `"EUR": 1.08`). The base itself is implicitly 1.
+    #[serde(default)]
+    pub rates: BTreeMap<String, f64>,
+}
+
+fn default_base_currency() -> String {
+    DEFAULT_BASE_CURRENCY.into()
+}
+
+impl Default for Currencies {
+    fn default() -> Self {
+        Self {
+            base: default_base_currency(),
+            rates: BTreeMap::new(),
+        }
+    }
+}
+
+impl Currencies {
+    /// The code an entry is actually in.
+    pub fn resolve<'a>(&'a self, code: Option<&'a str>) -> &'a str {
+        code.unwrap_or(&self.base)
+    }
+
+    /// Base-currency units per unit of `code`. `None` if there's no rate yet.
+    pub fn rate(&self, code: &str) -> Option<f64> {
+        if code == self.base {
+            Some(1.0)
+        } else {
+            self.rates.get(code).copied().filter(|r| *r > 0.0)
+        }
+    }
+
+    /// Convert `cents` in `code` (or the base, for `None`) to base cents.
+    /// `None` when `code` has no rate: totals leave such amounts out rather
+    /// than guess, and `missing_rates` lists them.
+    pub fn to_base(&self, cents: i64, code: Option<&str>) -> Option<i64> {
+        match code {
+            None => Some(cents),
+            Some(c) => Some((cents as f64 * self.rate(c)?).round() as i64),
+        }
+    }
+
+    /// Every code in use (base first), for dropdowns.
+    pub fn codes(&self) -> Vec<String> {
+        std::iter::once(self.base.clone())
+            .chain(self.rates.keys().filter(|c| **c != self.base).cloned())
+            .collect()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // The persisted file
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FinancesFile {
+    #[serde(default = "default_version")]
+    pub version: u32,
     #[serde(default)]
     pub assets: Vec<AssetEntry>,
     #[serde(default)]
@@ -225,52 +357,292 @@ pub struct FinancesFile {
     pub income: Vec<CashFlow>,
     #[serde(default)]
     pub expenses: Vec<CashFlow>,
-    /// Dated snapshots for the trend chart. One point per calendar day.
+    /// Dated snapshots for the trend chart, sorted, at most one per day.
     #[serde(default)]
     pub history: Vec<Snapshot>,
+    #[serde(default)]
+    pub history_retention: HistoryRetention,
+    #[serde(default)]
+    pub currencies: Currencies,
+    /// Actual posted transactions (bank imports and manual entries).
+    #[serde(default)]
+    pub transactions: Vec<Transaction>,
+    #[serde(default)]
+    pub match_rules: Vec<MatchRule>,
+    /// Column mapping remembered from the last CSV import.
+    #[serde(default)]
+    pub csv_mapping: CsvMapping,
+    #[serde(default)]
+    pub envelopes: Vec<Envelope>,
+    /// Long-range simulator settings.
+    #[serde(default)]
+    pub scenario: Scenario,
+    #[serde(default)]
+    pub tax: TaxSettings,
+}
+
+fn default_version() -> u32 {
+    1
+}
+
+impl Default for FinancesFile {
+    fn default() -> Self {
+        Self {
+            version: FINANCES_VERSION,
+            assets: Vec::new(),
+            liabilities: Vec::new(),
+            income: Vec::new(),
+            expenses: Vec::new(),
+            history: Vec::new(),
+            history_retention: HistoryRetention::default(),
+            currencies: Currencies::default(),
+            transactions: Vec::new(),
+            match_rules: Vec::new(),
+            csv_mapping: CsvMapping::default(),
+            envelopes: Vec::new(),
+            scenario: Scenario::default(),
+            tax: TaxSettings::default(),
+        }
+    }
 }
 
 impl FinancesFile {
+    /// Load a file from disk and bring it up to the current format.
+    pub fn load(path: impl AsRef<std::path::Path>) -> StoreResult<FinancesFile> {
+        let mut file: FinancesFile = load_json(path)?;
+        file.migrate();
+        Ok(file)
+    }
+
+    /// Upgrade an older file in place. Safe to run on a current one.
+    pub fn migrate(&mut self) {
+        if self.version < 2 {
+            // v1 entries have no creation time; the first history point is the
+            // earliest the file is known to have existed.
+            let stamp = self
+                .history
+                .first()
+                .map(|s| s.date.midnight().assume_utc())
+                .unwrap_or_else(OffsetDateTime::now_utc);
+            let fill = |c: &mut Option<OffsetDateTime>| {
+                c.get_or_insert(stamp);
+            };
+            self.assets.iter_mut().for_each(|a| fill(&mut a.created_at));
+            self.liabilities
+                .iter_mut()
+                .for_each(|l| fill(&mut l.created_at));
+            self.income.iter_mut().for_each(|f| fill(&mut f.created_at));
+            self.expenses
+                .iter_mut()
+                .for_each(|f| fill(&mut f.created_at));
+        }
+        // Derived incomes follow their basis even if the amount was hand-edited.
+        self.income.iter_mut().for_each(CashFlow::apply_basis);
+        self.version = FINANCES_VERSION;
+    }
+
     pub fn total_assets(&self) -> i64 {
-        self.assets.iter().map(|a| a.value).sum()
+        self.assets
+            .iter()
+            .filter_map(|a| self.currencies.to_base(a.value, a.currency.as_deref()))
+            .sum()
     }
     pub fn total_liabilities(&self) -> i64 {
-        self.liabilities.iter().map(|l| l.balance).sum()
+        self.liabilities
+            .iter()
+            .filter_map(|l| self.currencies.to_base(l.balance, l.currency.as_deref()))
+            .sum()
     }
     pub fn net_worth(&self) -> i64 {
         self.total_assets() - self.total_liabilities()
     }
+    pub fn total_by_class(&self, class: AssetClass) -> i64 {
+        self.assets
+            .iter()
+            .filter(|a| a.class() == class)
+            .filter_map(|a| self.currencies.to_base(a.value, a.currency.as_deref()))
+            .sum()
+    }
     pub fn monthly_income(&self) -> i64 {
-        monthly_total(&self.income)
+        monthly_total(&self.income, &self.currencies)
     }
     pub fn monthly_expenses(&self) -> i64 {
-        monthly_total(&self.expenses)
+        monthly_total(&self.expenses, &self.currencies)
+    }
+    /// Tax and pre-tax contributions on `taxable` income, annualized.
+    pub fn tax_breakdown(&self) -> TaxBreakdown {
+        let gross: Vec<CashFlow> = self.income.iter().filter(|f| f.taxable).cloned().collect();
+        self.tax
+            .breakdown(monthly_total(&gross, &self.currencies) * 12)
+    }
+    /// Share of taxable gross that actually lands, 0..=1. Withholding is
+    /// spread over taxable flows in proportion to their gross.
+    pub fn take_home_ratio(&self) -> f64 {
+        let b = self.tax_breakdown();
+        if b.gross <= 0 {
+            return 1.0;
+        }
+        (b.net() as f64 / b.gross as f64).clamp(0.0, 1.0)
+    }
+    /// What comes out of gross pay each month before it lands.
+    pub fn monthly_withheld(&self) -> i64 {
+        (self.tax_breakdown().withheld() as f64 / 12.0).round() as i64
     }
     pub fn monthly_net(&self) -> i64 {
-        self.monthly_income() - self.monthly_expenses()
+        self.monthly_income() - self.monthly_withheld() - self.monthly_expenses()
     }
 
-    /// Capture today's figures as a history point. Upserts: if the most recent
-    /// point is already today's, it's overwritten, so we keep one point per day.
+    /// Capture today's figures as a history point. Upserts: if there's already a
+    /// point for today it's overwritten, so we keep one point per day. Older
+    /// history is thinned per `history_retention` at the same time.
     pub fn record_snapshot(&mut self) {
         let snap = Snapshot {
             date: today(),
             net_worth: self.net_worth(),
             monthly_income: self.monthly_income(),
             monthly_expenses: self.monthly_expenses(),
+            monthly_withheld: self.monthly_withheld(),
+            interpolated: false,
+        };
+        self.upsert_snapshot(snap);
+        self.compact_history(snap.date);
+        self.close_envelope_months(snap.date);
+    }
+
+    /// Liabilities with balances and minimums converted to the base currency,
+    /// for plans that pool payments across accounts. Ones without a rate are
+    /// left out.
+    pub fn liabilities_in_base(&self) -> Vec<LiabilityEntry> {
+        self.liabilities
+            .iter()
+            .filter_map(|l| {
+                Some(LiabilityEntry {
+                    balance: self.currencies.to_base(l.balance, l.currency.as_deref())?,
+                    min_payment: self
+                        .currencies
+                        .to_base(l.min_payment, l.currency.as_deref())?,
+                    currency: None,
+                    ..l.clone()
+                })
+            })
+            .collect()
+    }
+
+    /// Codes used by some entry that have no exchange rate.
+    pub fn missing_rates(&self) -> Vec<String> {
+        let used = self
+            .assets
+            .iter()
+            .map(|a| &a.currency)
+            .chain(self.liabilities.iter().map(|l| &l.currency))
+            .chain(self.income.iter().map(|f| &f.currency))
+            .chain(self.expenses.iter().map(|f| &f.currency))
+            .chain(self.transactions.iter().map(|t| &t.currency))
+            .flatten();
+        let mut out: Vec<String> = Vec::new();
+        for code in used {
+            if self.currencies.rate(code).is_none() && !out.contains(code) {
+                out.push(code.clone());
+            }
+        }
+        out
+    }
+
+    /// Switch the base currency. Entries that were implicitly in the old base
+    /// are pinned to it, the rate table is re-expressed against the new base,
+    /// and amounts that have no currency of their own (history, envelope
+    /// budgets and manual spending, tax settings, match-rule amounts) are
+    /// converted at its rate. Refused when the new base has no rate, since
+    /// nothing could be converted.
+    pub fn set_base_currency(&mut self, code: &str) -> Result<(), String> {
+        let new_base = normalize_code(code);
+        let old_base = self.currencies.base.clone();
+        if new_base.is_empty() || new_base == old_base {
+            return Ok(());
+        }
+        let Some(pivot) = self.currencies.rate(&new_base) else {
+            return Err(format!("Add a rate for {new_base} before making it the base currency."));
         };
-        match self.history.last_mut() {
-            Some(last) if last.date == snap.date => *last = snap,
-            _ => self.history.push(snap),
+
+        let pin = |c: &mut Option<String>| {
+            if c.is_none() {
+                *c = Some(old_base.clone());
+            }
+        };
+        self.assets.iter_mut().for_each(|a| pin(&mut a.currency));
+        self.liabilities
+            .iter_mut()
+            .for_each(|l| pin(&mut l.currency));
+        self.income.iter_mut().for_each(|f| pin(&mut f.currency));
+        self.expenses.iter_mut().for_each(|f| pin(&mut f.currency));
+        self.transactions
+            .iter_mut()
+            .for_each(|t| pin(&mut t.currency));
+
+        let mut rates: BTreeMap<String, f64> = self
+            .currencies
+            .rates
+            .iter()
+            .filter(|(c, _)| **c != new_base)
+            .map(|(c, r)| (c.clone(), r / pivot))
+            .collect();
+        rates.insert(old_base, 1.0 / pivot);
+        self.currencies.rates = rates;
+        let conv = |v: i64| (v as f64 / pivot).round() as i64;
+        for s in self.history.iter_mut() {
+            s.net_worth = conv(s.net_worth);
+            s.monthly_income = conv(s.monthly_income);
+            s.monthly_expenses = conv(s.monthly_expenses);
+            s.monthly_withheld = conv(s.monthly_withheld);
+        }
+        for e in self.envelopes.iter_mut() {
+            if let Allocation::Fixed(c) = &mut e.allocation {
+                *c = conv(*c);
+            }
+            e.spending.iter_mut().for_each(|s| s.amount = conv(s.amount));
+            e.funded.iter_mut().for_each(|f| f.cents = conv(f.cents));
         }
+        self.tax.pre_tax_annual = conv(self.tax.pre_tax_annual);
+        self.tax.table.standard_deduction = conv(self.tax.table.standard_deduction);
+        self.tax.table.brackets.iter_mut().for_each(|b| b.over = conv(b.over));
+        for r in self.match_rules.iter_mut() {
+            r.amount = r.amount.map(conv);
+            r.tolerance = conv(r.tolerance);
+        }
+        self.currencies.base = new_base;
+
+        // Entries now in the new base don't need an explicit code.
+        let unpin = |c: &mut Option<String>, base: &str| {
+            if c.as_deref() == Some(base) {
+                *c = None;
+            }
+        };
+        let base = self.currencies.base.clone();
+        self.assets
+            .iter_mut()
+            .for_each(|a| unpin(&mut a.currency, &base));
+        self.liabilities
+            .iter_mut()
+            .for_each(|l| unpin(&mut l.currency, &base));
+        self.income
+            .iter_mut()
+            .for_each(|f| unpin(&mut f.currency, &base));
+        self.expenses
+            .iter_mut()
+            .for_each(|f| unpin(&mut f.currency, &base));
+        self.transactions
+            .iter_mut()
+            .for_each(|t| unpin(&mut t.currency, &base));
+        Ok(())
     }
 }
 
-/// Sum a set of cash flows normalized to a per-month figure (in cents).
-pub fn monthly_total(flows: &[CashFlow]) -> i64 {
+/// Sum a set of cash flows normalized to a per-month figure, in base-currency
+/// cents.
+pub fn monthly_total(flows: &[CashFlow], currencies: &Currencies) -> i64 {
     flows
         .iter()
-        .map(|f| (f.amount as f64 * f.frequency.per_month()).round() as i64)
+        .filter_map(|f| currencies.to_base(f.per_month_cents(), f.currency.as_deref()))
         .sum()
 }
 
@@ -282,6 +654,26 @@ pub struct AssetEntry {
     pub kind: AssetKind,
     #[serde(with = "money_cents")]
     pub value: i64, // cents
+    /// ISO 4217 code; `None` = the file's base currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Investments only: expected annual return, percent.
+    #[serde(default)]
+    pub expected_return: f64,
+    /// Investments only: annual volatility (standard deviation), percent.
+    #[serde(default)]
+    pub volatility: f64,
+    /// Investments only: added every month.
+    #[serde(with = "money_cents", default)]
+    pub contribution: i64, // cents per month
+    /// Overrides `kind.default_class()`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<AssetClass>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub created_at: Option<OffsetDateTime>,
+    /// Dated values, oldest first, in the entry's own currency.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub balances: Vec<ValuePoint>,
 }
 
 impl AssetEntry {
@@ -291,8 +683,19 @@ impl AssetEntry {
             name: "New asset".into(),
             kind: AssetKind::Cash,
             value: 0,
+            currency: None,
+            expected_return: 0.0,
+            volatility: 0.0,
+            contribution: 0,
+            class: None,
+            created_at: Some(OffsetDateTime::now_utc()),
+            balances: Vec::new(),
         }
     }
+
+    pub fn class(&self) -> AssetClass {
+        self.class.unwrap_or(self.kind.default_class())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -303,6 +706,26 @@ pub struct LiabilityEntry {
     pub kind: LiabilityKind,
     #[serde(with = "money_cents")]
     pub balance: i64, // cents owed
+    /// Annual percentage rate, as a percent (6.5 = 6.5%).
+    #[serde(default)]
+    pub apr: f64,
+    #[serde(with = "money_cents", default)]
+    pub min_payment: i64, // cents per month
+    /// Day of month the payment is due. Kept to 1–28 so every month has one.
+    #[serde(default = "default_payment_day")]
+    pub payment_day: u8,
+    /// ISO 4217 code; `None` = the file's base currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub created_at: Option<OffsetDateTime>,
+    /// Dated balances, oldest first, in the entry's own currency.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub balances: Vec<ValuePoint>,
+}
+
+fn default_payment_day() -> u8 {
+    1
 }
 
 impl LiabilityEntry {
@@ -312,6 +735,12 @@ impl LiabilityEntry {
             name: "New debt".into(),
             kind: LiabilityKind::Other,
             balance: 0,
+            apr: 0.0,
+            min_payment: 0,
+            payment_day: default_payment_day(),
+            currency: None,
+            created_at: Some(OffsetDateTime::now_utc()),
+            balances: Vec::new(),
         }
     }
 }
@@ -329,6 +758,56 @@ pub struct CashFlow {
     /// Anchor / next occurrence date. Drives calendar projection.
     #[serde(default = "today")]
     pub date: Date,
+    /// ISO 4217 code; `None` = the file's base currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Budget envelope this expense draws from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub envelope: Option<Uuid>,
+    /// Income only: this is gross pay, taxed by the file's `TaxSettings`.
+    /// Off for older entries, which were entered as take-home.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub taxable: bool,
+    /// Income only: derive `amount` from an hourly rate or a salary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub basis: Option<IncomeBasis>,
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub created_at: Option<OffsetDateTime>,
+}
+
+/// Where a derived income's monthly amount comes from. Amounts are gross, in
+/// the flow's currency.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum IncomeBasis {
+    Hourly {
+        #[serde(with = "money_cents")]
+        rate: i64, // cents per hour
+        hours_per_week: f64,
+    },
+    Salary {
+        #[serde(with = "money_cents")]
+        yearly: i64, // cents
+    },
+}
+
+impl IncomeBasis {
+    pub fn label(&self) -> &'static str {
+        match self {
+            IncomeBasis::Hourly { .. } => "Hourly",
+            IncomeBasis::Salary { .. } => "Salary",
+        }
+    }
+
+    /// Average gross per month: 52 weeks of hours, or a twelfth of the salary.
+    pub fn monthly_cents(&self) -> i64 {
+        match *self {
+            IncomeBasis::Hourly {
+                rate,
+                hours_per_week,
+            } => (rate as f64 * hours_per_week * 52.0 / 12.0).round() as i64,
+            IncomeBasis::Salary { yearly } => (yearly as f64 / 12.0).round() as i64,
+        }
+    }
 }
 
 impl CashFlow {
@@ -339,27 +818,198 @@ impl CashFlow {
             amount: 0,
             frequency: Frequency::Monthly,
             date: today(),
+            currency: None,
+            envelope: None,
+            taxable: false,
+            basis: None,
+            created_at: Some(OffsetDateTime::now_utc()),
         }
     }
 
-    /// This flow's contribution to a monthly budget, in cents.
+    /// Gross hourly pay: `rate` cents × `hours_per_week`, as a monthly amount.
+    pub fn new_hourly(name: &str, rate: i64, hours_per_week: f64) -> Self {
+        Self::with_basis(
+            name,
+            IncomeBasis::Hourly {
+                rate,
+                hours_per_week,
+            },
+        )
+    }
+
+    /// Gross salary of `yearly` cents, as a monthly amount.
+    pub fn new_salary(name: &str, yearly: i64) -> Self {
+        Self::with_basis(name, IncomeBasis::Salary { yearly })
+    }
+
+    fn with_basis(name: &str, basis: IncomeBasis) -> Self {
+        let mut flow = Self {
+            basis: Some(basis),
+            taxable: true,
+            ..Self::new(name)
+        };
+        flow.apply_basis();
+        flow
+    }
+
+    /// Recompute a derived income's amount (monthly) from its basis. No-op for
+    /// flows entered directly.
+    pub fn apply_basis(&mut self) {
+        if let Some(b) = self.basis {
+            self.amount = b.monthly_cents();
+            self.frequency = Frequency::Monthly;
+        }
+    }
+
+    /// This flow's contribution to a monthly budget, in cents of its own
+    /// currency.
     pub fn per_month_cents(&self) -> i64 {
         (self.amount as f64 * self.frequency.per_month()).round() as i64
     }
 }
 
-/// A dated point for the trend chart. Money fields are plain cents (they're
-/// derived figures, not user-entered, so they skip the flexible money parser).
+/// A dated point for the trend chart. Money fields are plain base-currency cents
+/// (they're derived figures, not user-entered, so they skip the flexible money
+/// parser).
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Snapshot {
     pub date: Date,
     pub net_worth: i64,
     pub monthly_income: i64,
     pub monthly_expenses: i64,
+    /// Taxes and pre-tax contributions taken from gross income.
+    #[serde(default)]
+    pub monthly_withheld: i64,
+    /// Filled in by `backfill_history` rather than recorded.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub interpolated: bool,
 }
 
 impl Snapshot {
     pub fn take_home(&self) -> i64 {
-        self.monthly_income - self.monthly_expenses
+        self.monthly_income - self.monthly_withheld - self.monthly_expenses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::finCalc::envelopes::ManualSpend;
+    use crate::models::finCalc::tax::TaxBracket;
+
+    #[test]
+    fn hourly_and_salary_derive_monthly_amounts() {
+        let hourly = CashFlow::new_hourly("Shifts", 2500, 30.0);
+        assert_eq!(hourly.amount, 325000);
+        assert_eq!(hourly.frequency, Frequency::Monthly);
+        assert!(hourly.taxable);
+        let salary = CashFlow::new_salary("Job", 9000000);
+        assert_eq!(salary.per_month_cents(), 750000);
+    }
+
+    #[test]
+    fn v1_files_migrate() {
+        let json = r#"{
+            "assets": [{ "id": "357a300b-4909-463c-b95a-d30af91ab9e8", "name": "Car", "kind": "Vehicle", "value": 5000 }],
+            "income": [{
+                "id": "457a300b-4909-463c-b95a-d30af91ab9e8", "name": "Pay", "amount": 1,
+                "frequency": { "XMonths": 2 }, "basis": { "Salary": { "yearly": 120000.0 } }
+            }],
+            "expenses": [{
+                "id": "557a300b-4909-463c-b95a-d30af91ab9e8", "name": "Gym", "amount": 30.0,
+                "frequency": { "XDays": 14 }
+            }],
+            "history": [{ "date": [2025, 152], "net_worth": 0, "monthly_income": 0, "monthly_expenses": 0 }]
+        }"#;
+        let mut f: FinancesFile = serde_json::from_str(json).unwrap();
+        assert_eq!(f.version, 1);
+        f.migrate();
+        assert_eq!(f.version, FINANCES_VERSION);
+        assert_eq!(f.expenses[0].frequency, Frequency::EveryNDays(14));
+        assert_eq!(
+            f.assets[0].created_at.unwrap().date(),
+            Date::from_calendar_date(2025, time::Month::June, 1).unwrap()
+        );
+        assert_eq!(f.assets[0].class(), AssetClass::Hard);
+        assert_eq!(f.total_by_class(AssetClass::Hard), 5000);
+        assert_eq!(f.income[0].amount, 1000000);
+        assert_eq!(f.income[0].frequency, Frequency::Monthly);
+    }
+
+    #[test]
+    fn legacy_entries_load_in_base_currency() {
+        let json = r#"{
+            "assets": [{ "id": "357a300b-4909-463c-b95a-d30af91ab9e8", "name": "Checking", "value": 12.5 }],
+            "income": [], "expenses": []
+        }"#;
+        let f: FinancesFile = serde_json::from_str(json).unwrap();
+        assert_eq!(f.currencies.base, "USD");
+        assert_eq!(f.assets[0].currency, None);
+        assert_eq!(f.total_assets(), 1250);
+    }
+
+    #[test]
+    fn totals_convert_to_base() {
+        let mut f = FinancesFile::default();
+        f.currencies.rates.insert("EUR".into(), 1.10);
+        f.assets.push(AssetEntry {
+            value: 10000,
+            ..AssetEntry::new()
+        });
+        f.assets.push(AssetEntry {
+            value: 10000,
+            currency: Some("EUR".into()),
+            ..AssetEntry::new()
+        });
+        f.expenses.push(CashFlow {
+            amount: 1000,
+            currency: Some("GBP".into()),
+            ..CashFlow::new("Sub")
+        });
+        assert_eq!(f.total_assets(), 21000);
+        // No GBP rate: the expense is flagged and left out, not counted 1:1.
+        assert_eq!(f.missing_rates(), vec!["GBP".to_string()]);
+        assert_eq!(f.currencies.to_base(1000, Some("GBP")), None);
+        assert_eq!(f.monthly_expenses(), 0);
+    }
+
+    #[test]
+    fn rebase_keeps_totals() {
+        let mut f = FinancesFile::default();
+        f.currencies.rates.insert("EUR".into(), 1.25);
+        f.assets.push(AssetEntry {
+            value: 12500,
+            ..AssetEntry::new()
+        });
+        f.assets.push(AssetEntry {
+            value: 10000,
+            currency: Some("EUR".into()),
+            ..AssetEntry::new()
+        });
+        let mut env = Envelope::new("Food", today());
+        env.allocation = Allocation::Fixed(50000);
+        env.spending.push(ManualSpend { id: Uuid::new_v4(), date: today(), amount: 2500, note: String::new() });
+        f.envelopes.push(env);
+        f.tax.pre_tax_annual = 625000;
+        f.tax.table.standard_deduction = 1250000;
+        f.tax.table.brackets.push(TaxBracket { over: 5000000, rate: 22.0 });
+        f.match_rules.push(MatchRule { flow_id: Uuid::new_v4(), contains: None, amount: Some(1250), tolerance: 125 });
+
+        assert!(f.set_base_currency("gbp").is_err());
+        assert_eq!(f.currencies.base, "USD");
+        assert_eq!(f.assets[0].currency, None);
+
+        f.set_base_currency("eur").unwrap();
+        assert_eq!(f.currencies.base, "EUR");
+        assert_eq!(f.assets[0].currency.as_deref(), Some("USD"));
+        assert_eq!(f.assets[1].currency, None);
+        assert_eq!(f.total_assets(), 20000);
+        // Amounts with no currency of their own follow the base.
+        assert_eq!(f.envelopes[0].allocation, Allocation::Fixed(40000));
+        assert_eq!(f.envelopes[0].spending[0].amount, 2000);
+        assert_eq!(f.tax.pre_tax_annual, 500000);
+        assert_eq!(f.tax.table.standard_deduction, 1000000);
+        assert_eq!(f.tax.table.brackets[0].over, 4000000);
+        assert_eq!((f.match_rules[0].amount, f.match_rules[0].tolerance), (Some(1000), 100));
     }
 }
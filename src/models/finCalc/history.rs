@@ -0,0 +1,237 @@
+// src/models/finCalc/history.rs
+//! Editing, backfilling and thinning the net-worth history.
+//!
+//! `history` is kept sorted by date with at most one `Snapshot` per day.
+//! Gaps between recorded days can be filled by straight-line interpolation,
+//! and a retention policy thins old points (daily → weekly → monthly) so years
+//! of history stay small.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use time::{Date, Duration};
+
+use super::finances::{FinancesFile, Snapshot};
+
+/// How long history stays at each resolution. Points younger than
+/// `daily_days` are all kept; up to `weekly_days` one per ISO week survives;
+/// older than that, one per month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryRetention {
+    #[serde(default = "default_daily_days")]
+    pub daily_days: u32,
+    #[serde(default = "default_weekly_days")]
+    pub weekly_days: u32,
+}
+
+fn default_daily_days() -> u32 {
+    90
+}
+fn default_weekly_days() -> u32 {
+    730
+}
+
+impl Default for HistoryRetention {
+    fn default() -> Self {
+        Self {
+            daily_days: default_daily_days(),
+            weekly_days: default_weekly_days(),
+        }
+    }
+}
+
+/// Which bucket a point falls in under `policy`, as of `today`. Points sharing
+/// a bucket are collapsed to one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Bucket {
+    Month(i32, u8),
+    Week(i32, u8),
+    Day(Date),
+}
+
+fn bucket(date: Date, today: Date, policy: &HistoryRetention) -> Bucket {
+    let age = (today - date).whole_days();
+    if age < policy.daily_days as i64 {
+        Bucket::Day(date)
+    } else if age < policy.weekly_days.max(policy.daily_days) as i64 {
+        let (year, week, _) = date.to_iso_week_date();
+        Bucket::Week(year, week)
+    } else {
+        Bucket::Month(date.year(), u8::from(date.month()))
+    }
+}
+
+fn lerp(a: i64, b: i64, t: f64) -> i64 {
+    (a as f64 + (b - a) as f64 * t).round() as i64
+}
+
+impl FinancesFile {
+    /// Insert a point, replacing any existing one on the same day.
+    pub fn upsert_snapshot(&mut self, snap: Snapshot) {
+        match self.history.binary_search_by_key(&snap.date, |s| s.date) {
+            Ok(i) => self.history[i] = snap,
+            Err(i) => self.history.insert(i, snap),
+        }
+    }
+
+    /// Replace the point on `date` (which may move to another day). Returns
+    /// false if there was no point on `date`.
+    pub fn update_snapshot(&mut self, date: Date, snap: Snapshot) -> bool {
+        if !self.remove_snapshot(date) {
+            return false;
+        }
+        self.upsert_snapshot(snap);
+        true
+    }
+
+    pub fn remove_snapshot(&mut self, date: Date) -> bool {
+        match self.history.binary_search_by_key(&date, |s| s.date) {
+            Ok(i) => {
+                self.history.remove(i);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Fill every missing day between known points by linear interpolation.
+    /// Filled points are flagged `interpolated`. Returns how many were added.
+    pub fn backfill_history(&mut self) -> usize {
+        let mut filled = Vec::new();
+        for pair in self.history.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let span = (b.date - a.date).whole_days();
+            for k in 1..span {
+                let t = k as f64 / span as f64;
+                filled.push(Snapshot {
+                    date: a.date + Duration::days(k),
+                    net_worth: lerp(a.net_worth, b.net_worth, t),
+                    monthly_income: lerp(a.monthly_income, b.monthly_income, t),
+                    monthly_expenses: lerp(a.monthly_expenses, b.monthly_expenses, t),
+                    monthly_withheld: lerp(a.monthly_withheld, b.monthly_withheld, t),
+                    interpolated: true,
+                });
+            }
+        }
+        let n = filled.len();
+        self.history.extend(filled);
+        self.history.sort_by_key(|s| s.date);
+        n
+    }
+
+    /// Thin old history per `history_retention`, keeping the latest point in
+    /// each week/month bucket (a recorded one over an interpolated one).
+    /// Returns how many points were dropped.
+    pub fn compact_history(&mut self, today: Date) -> usize {
+        let policy = self.history_retention;
+        let mut keep: BTreeMap<Bucket, Snapshot> = BTreeMap::new();
+        for s in self.history.iter() {
+            let b = bucket(s.date, today, &policy);
+            match keep.get(&b) {
+                Some(kept) if s.interpolated && !kept.interpolated => {}
+                _ => {
+                    keep.insert(b, *s);
+                }
+            }
+        }
+        let before = self.history.len();
+        self.history = keep.into_values().collect();
+        self.history.sort_by_key(|s| s.date);
+        before - self.history.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    fn snap(date: Date, net_worth: i64) -> Snapshot {
+        Snapshot {
+            date,
+            net_worth,
+            monthly_income: 0,
+            monthly_expenses: 0,
+            monthly_withheld: 0,
+            interpolated: false,
+        }
+    }
+
+    #[test]
+    fn insert_edit_delete_keep_order() {
+        let mut f = FinancesFile::default();
+        f.upsert_snapshot(snap(d(2026, 3, 1), 3));
+        f.upsert_snapshot(snap(d(2026, 1, 1), 1));
+        f.upsert_snapshot(snap(d(2026, 2, 1), 2));
+        f.upsert_snapshot(snap(d(2026, 2, 1), 20));
+        let nw: Vec<i64> = f.history.iter().map(|s| s.net_worth).collect();
+        assert_eq!(nw, vec![1, 20, 3]);
+
+        assert!(f.update_snapshot(d(2026, 2, 1), snap(d(2026, 4, 1), 4)));
+        assert_eq!(f.history.last().unwrap().date, d(2026, 4, 1));
+        assert!(!f.update_snapshot(d(2026, 2, 1), snap(d(2026, 2, 1), 0)));
+        assert!(f.remove_snapshot(d(2026, 1, 1)));
+        assert_eq!(f.history.len(), 2);
+    }
+
+    #[test]
+    fn backfill_interpolates_gaps() {
+        let mut f = FinancesFile::default();
+        f.upsert_snapshot(snap(d(2026, 1, 1), 0));
+        f.upsert_snapshot(snap(d(2026, 1, 5), 400));
+        assert_eq!(f.backfill_history(), 3);
+        let nw: Vec<i64> = f.history.iter().map(|s| s.net_worth).collect();
+        assert_eq!(nw, vec![0, 100, 200, 300, 400]);
+        assert!(f.history[2].interpolated);
+        assert_eq!(f.backfill_history(), 0);
+    }
+
+    #[test]
+    fn compaction_thins_by_age() {
+        let mut f = FinancesFile {
+            history_retention: HistoryRetention {
+                daily_days: 10,
+                weekly_days: 60,
+            },
+            ..FinancesFile::default()
+        };
+        let today = d(2026, 6, 30);
+        let mut day = d(2026, 1, 1);
+        while day <= today {
+            f.upsert_snapshot(snap(day, 1));
+            day = day.next_day().unwrap();
+        }
+        f.compact_history(today);
+
+        let recent = f
+            .history
+            .iter()
+            .filter(|s| (today - s.date).whole_days() < 10)
+            .count();
+        assert_eq!(recent, 10);
+        // Jan–Mar are past 60 days: one point per month, the month's last day.
+        let old: Vec<Date> = f
+            .history
+            .iter()
+            .filter(|s| s.date < d(2026, 4, 1))
+            .map(|s| s.date)
+            .collect();
+        assert_eq!(old, vec![d(2026, 1, 31), d(2026, 2, 28), d(2026, 3, 31)]);
+        assert!(f.history.windows(2).all(|w| w[0].date < w[1].date));
+    }
+
+    #[test]
+    fn compaction_prefers_recorded_points() {
+        let mut f = FinancesFile::default();
+        f.upsert_snapshot(snap(d(2025, 1, 3), 7));
+        f.upsert_snapshot(snap(d(2025, 1, 20), 9));
+        f.backfill_history();
+        f.compact_history(d(2027, 6, 30));
+        assert_eq!(f.history.len(), 1);
+        assert_eq!(f.history[0].net_worth, 9);
+        assert!(!f.history[0].interpolated);
+    }
+}
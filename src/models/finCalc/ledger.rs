@@ -0,0 +1,651 @@
+// src/models/finCalc/ledger.rs
+//! Ledger of actual, dated transactions, plus importers for bank CSV exports
+//! and OFX/QFX statements.
+//!
+//! Imported rows are de-duplicated (by the bank's `FITID` when there is one,
+//! otherwise by date + amount + description) and matched to expense
+//! `CashFlow`s so budgeted and actual spending can be compared per month.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::{Date, Month};
+use uuid::Uuid;
+
+use super::finances::FinancesFile;
+
+/// One posted transaction. `amount` is signed cents in the account's
+/// currency: spending is negative, deposits positive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transaction {
+    pub id: Uuid,
+    pub date: Date,
+    pub description: String,
+    pub amount: i64, // cents
+    /// ISO 4217 code; `None` = the file's base currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// The expense `CashFlow` this spending counts against, if any.
+    #[serde(default)]
+    pub flow_id: Option<Uuid>,
+    /// Bank-assigned transaction id (OFX `FITID`), used for de-duplication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fit_id: Option<String>,
+}
+
+impl Transaction {
+    pub fn new(date: Date, description: impl Into<String>, amount: i64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            date,
+            description: description.into(),
+            amount,
+            currency: None,
+            flow_id: None,
+            fit_id: None,
+        }
+    }
+
+    /// Identity used to spot a row that was already imported.
+    fn dedup_key(&self) -> String {
+        match &self.fit_id {
+            Some(f) => format!("fit:{f}"),
+            None => format!(
+                "{}|{}|{}",
+                self.date,
+                self.amount,
+                self.description.trim().to_lowercase()
+            ),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Matching rules
+// ---------------------------------------------------------------------------
+
+/// Assigns spending to an expense flow. A rule matches when every condition it
+/// sets holds: `contains` is a case-insensitive substring of the description,
+/// `amount` is the spend in cents (positive) within `tolerance`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchRule {
+    pub flow_id: Uuid,
+    #[serde(default)]
+    pub contains: Option<String>,
+    #[serde(default)]
+    pub amount: Option<i64>,
+    #[serde(default)]
+    pub tolerance: i64,
+}
+
+impl MatchRule {
+    pub fn matches(&self, tx: &Transaction) -> bool {
+        if self.contains.is_none() && self.amount.is_none() {
+            return false;
+        }
+        let text_ok = self.contains.as_ref().is_none_or(|c| {
+            let c = c.trim().to_lowercase();
+            !c.is_empty() && tx.description.to_lowercase().contains(&c)
+        });
+        let amount_ok = self
+            .amount
+            .is_none_or(|a| (-tx.amount - a).abs() <= self.tolerance.max(0));
+        text_ok && amount_ok
+    }
+}
+
+/// The expense flow a spending transaction belongs to: explicit rules first,
+/// then an expense whose name appears in the description.
+pub fn match_flow(file: &FinancesFile, tx: &Transaction) -> Option<Uuid> {
+    if tx.amount >= 0 {
+        return None;
+    }
+    if let Some(rule) = file.match_rules.iter().find(|r| r.matches(tx)) {
+        return Some(rule.flow_id);
+    }
+    let desc = tx.description.to_lowercase();
+    file.expenses
+        .iter()
+        .find(|f| {
+            let name = f.name.trim().to_lowercase();
+            !name.is_empty() && desc.contains(&name)
+        })
+        .map(|f| f.id)
+}
+
+// ---------------------------------------------------------------------------
+// Import
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ImportReport {
+    pub added: usize,
+    pub duplicates: usize,
+    pub matched: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportError {
+    /// Row number (1-based, as in a spreadsheet) and what was wrong with it.
+    Row(usize, String),
+    Format(String),
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportError::Row(n, msg) => write!(f, "Row {n}: {msg}"),
+            ImportError::Format(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl FinancesFile {
+    /// Add parsed transactions, skipping ones already in the ledger and
+    /// auto-matching spending to expense flows. Identical rows are counted,
+    /// not collapsed: two same-day coffees in one statement both land, and
+    /// re-importing that statement adds neither.
+    pub fn import_transactions(&mut self, txs: Vec<Transaction>) -> ImportReport {
+        let mut report = ImportReport::default();
+        let mut existing: HashMap<String, usize> = HashMap::new();
+        for t in &self.transactions {
+            *existing.entry(t.dedup_key()).or_default() += 1;
+        }
+        for mut tx in txs {
+            if let Some(n) = existing.get_mut(&tx.dedup_key()).filter(|n| **n > 0) {
+                *n -= 1;
+                report.duplicates += 1;
+                continue;
+            }
+            if tx.flow_id.is_none() {
+                tx.flow_id = match_flow(self, &tx);
+            }
+            if tx.flow_id.is_some() {
+                report.matched += 1;
+            }
+            self.transactions.push(tx);
+            report.added += 1;
+        }
+        self.transactions.sort_by_key(|t| t.date);
+        report
+    }
+
+    /// Re-run matching over transactions that aren't assigned yet (e.g. after
+    /// adding a rule). Returns how many were newly matched.
+    pub fn rematch_transactions(&mut self) -> usize {
+        let mut n = 0;
+        for i in 0..self.transactions.len() {
+            if self.transactions[i].flow_id.is_none() {
+                if let Some(id) = match_flow(self, &self.transactions[i]) {
+                    self.transactions[i].flow_id = Some(id);
+                    n += 1;
+                }
+            }
+        }
+        n
+    }
+}
+
+/// Parse an import file, picking OFX/QFX or CSV from its content.
+pub fn parse_statement(text: &str, mapping: &CsvMapping) -> Result<Vec<Transaction>, ImportError> {
+    if text.contains("<OFX>") || text.contains("<ofx>") || text.contains("OFXHEADER") {
+        parse_ofx(text)
+    } else {
+        parse_csv(text, mapping)
+    }
+}
+
+/// Parse a money cell: "1,234.56", "-45", "$12.00", "(45.00)" (negative).
+pub fn parse_amount(s: &str) -> Option<i64> {
+    let t = s.trim();
+    let (neg_paren, t) = match t.strip_prefix('(').and_then(|x| x.strip_suffix(')')) {
+        Some(inner) => (true, inner),
+        None => (false, t),
+    };
+    let cleaned: String = t
+        .chars()
+        .filter(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+'))
+        .collect();
+    if cleaned.is_empty() {
+        return None;
+    }
+    let v = (cleaned.parse::<f64>().ok()? * 100.0).round() as i64;
+    Some(if neg_paren { -v.abs() } else { v })
+}
+
+// ---- CSV -------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DateFormat {
+    /// 2026-01-31
+    #[default]
+    Iso,
+    /// 01/31/2026 or 1/31/26
+    MonthDayYear,
+    /// 31/01/2026 or 31.01.26
+    DayMonthYear,
+}
+
+impl DateFormat {
+    pub const ALL: [DateFormat; 3] = [
+        DateFormat::Iso,
+        DateFormat::MonthDayYear,
+        DateFormat::DayMonthYear,
+    ];
+    pub fn label(&self) -> &'static str {
+        match self {
+            DateFormat::Iso => "YYYY-MM-DD",
+            DateFormat::MonthDayYear => "MM/DD/YYYY",
+            DateFormat::DayMonthYear => "DD/MM/YYYY",
+        }
+    }
+    pub fn from_label(s: &str) -> DateFormat {
+        DateFormat::ALL
+            .into_iter()
+            .find(|f| f.label() == s)
+            .unwrap_or_default()
+    }
+
+    pub fn parse(&self, s: &str) -> Option<Date> {
+        let parts: Vec<&str> = s.trim().split(['-', '/', '.']).map(str::trim).collect();
+        let [a, b, c] = parts.as_slice() else {
+            return None;
+        };
+        let (y, m, d) = match self {
+            DateFormat::Iso => (*a, *b, *c),
+            DateFormat::MonthDayYear => (*c, *a, *b),
+            DateFormat::DayMonthYear => (*c, *b, *a),
+        };
+        let mut year: i32 = y.parse().ok()?;
+        if y.len() <= 2 {
+            year += 2000;
+        }
+        let month = Month::try_from(m.parse::<u8>().ok()?).ok()?;
+        Date::from_calendar_date(year, month, d.parse().ok()?).ok()
+    }
+}
+
+/// How a bank's CSV export maps onto a `Transaction`. Columns are 0-based.
+/// Banks either use one signed `amount` column, or separate `debit`/`credit`
+/// columns (both positive).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CsvMapping {
+    #[serde(default = "yes")]
+    pub has_header: bool,
+    pub date_col: usize,
+    pub description_col: usize,
+    #[serde(default)]
+    pub amount_col: Option<usize>,
+    #[serde(default)]
+    pub debit_col: Option<usize>,
+    #[serde(default)]
+    pub credit_col: Option<usize>,
+    #[serde(default)]
+    pub date_format: DateFormat,
+    /// Some banks list spending as positive amounts; flip the sign on import.
+    #[serde(default)]
+    pub spending_positive: bool,
+}
+
+fn yes() -> bool {
+    true
+}
+
+impl Default for CsvMapping {
+    fn default() -> Self {
+        Self {
+            has_header: true,
+            date_col: 0,
+            description_col: 1,
+            amount_col: Some(2),
+            debit_col: None,
+            credit_col: None,
+            date_format: DateFormat::Iso,
+            spending_positive: false,
+        }
+    }
+}
+
+/// Split CSV text into records. Handles quoted fields with embedded commas,
+/// doubled quotes and newlines.
+pub fn split_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|f| !f.trim().is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                } else {
+                    row.clear();
+                }
+            }
+            _ => field.push(c),
+        }
+    }
+    row.push(field);
+    if row.iter().any(|f| !f.trim().is_empty()) {
+        rows.push(row);
+    }
+    rows
+}
+
+pub fn parse_csv(text: &str, mapping: &CsvMapping) -> Result<Vec<Transaction>, ImportError> {
+    let rows = split_csv(text);
+    let skip = usize::from(mapping.has_header);
+    let mut out = Vec::new();
+    for (i, row) in rows.iter().enumerate().skip(skip) {
+        let line = i + 1;
+        let cell = |col: usize| row.get(col).map(|s| s.trim()).unwrap_or("");
+
+        let date = mapping
+            .date_format
+            .parse(cell(mapping.date_col))
+            .ok_or_else(|| {
+                ImportError::Row(
+                    line,
+                    format!("can't read date \"{}\"", cell(mapping.date_col)),
+                )
+            })?;
+
+        let amount = if let Some(col) = mapping.amount_col {
+            parse_amount(cell(col)).ok_or_else(|| {
+                ImportError::Row(line, format!("can't read amount \"{}\"", cell(col)))
+            })?
+        } else {
+            let debit = mapping
+                .debit_col
+                .and_then(|c| parse_amount(cell(c)))
+                .unwrap_or(0);
+            let credit = mapping
+                .credit_col
+                .and_then(|c| parse_amount(cell(c)))
+                .unwrap_or(0);
+            if mapping.debit_col.is_none() && mapping.credit_col.is_none() {
+                return Err(ImportError::Format(
+                    "Mapping needs an amount column or debit/credit columns".into(),
+                ));
+            }
+            credit.abs() - debit.abs()
+        };
+        let amount = if mapping.spending_positive && mapping.amount_col.is_some() {
+            -amount
+        } else {
+            amount
+        };
+
+        out.push(Transaction::new(
+            date,
+            cell(mapping.description_col),
+            amount,
+        ));
+    }
+    Ok(out)
+}
+
+// ---- OFX / QFX -------------------------------------------------------------
+
+/// Value of an OFX element. OFX 1.x is SGML, so leaf tags usually aren't
+/// closed: the value runs to the next `<`. Tags are found in `upper` (an
+/// upper-cased copy of `text`) and the value is read from `text`, so names
+/// keep their case.
+fn ofx_field(text: &str, upper: &str, tag: &str) -> Option<String> {
+    let open = format!("<{tag}>");
+    let start = upper.find(&open)? + open.len();
+    let rest = &text[start..];
+    let end = rest.find('<').unwrap_or(rest.len());
+    let v = rest[..end].trim();
+    (!v.is_empty()).then(|| v.to_string())
+}
+
+/// `20260105` or `20260105120000[-5:EST]` -> 2026-01-05.
+fn ofx_date(s: &str) -> Option<Date> {
+    let digits = s.get(..8).filter(|d| d.bytes().all(|b| b.is_ascii_digit()))?;
+    let year: i32 = digits[..4].parse().ok()?;
+    let month = Month::try_from(digits[4..6].parse::<u8>().ok()?).ok()?;
+    Date::from_calendar_date(year, month, digits[6..8].parse().ok()?).ok()
+}
+
+pub fn parse_ofx(text: &str) -> Result<Vec<Transaction>, ImportError> {
+    let upper = text.to_ascii_uppercase();
+    let currency = ofx_field(text, &upper, "CURDEF");
+    let mut out = Vec::new();
+    let mut pos = 0;
+    let mut n = 0;
+    while let Some(rel) = upper[pos..].find("<STMTTRN>") {
+        n += 1;
+        let start = pos + rel + "<STMTTRN>".len();
+        // A transaction ends at its closing tag or, when the tag is left open,
+        // at the next transaction.
+        let end = [
+            upper[start..].find("</STMTTRN>"),
+            upper[start..].find("<STMTTRN>"),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .map(|e| start + e)
+        .unwrap_or(upper.len());
+        let (block, block_upper) = (&text[start..end], &upper[start..end]);
+        let field = |tag: &str| ofx_field(block, block_upper, tag);
+
+        let date = field("DTPOSTED")
+            .as_deref()
+            .and_then(ofx_date)
+            .ok_or_else(|| ImportError::Row(n, "missing or bad DTPOSTED".into()))?;
+        let amount = field("TRNAMT")
+            .as_deref()
+            .and_then(parse_amount)
+            .ok_or_else(|| ImportError::Row(n, "missing or bad TRNAMT".into()))?;
+        let description = match (field("NAME"), field("MEMO")) {
+            (Some(name), _) => name,
+            (None, Some(memo)) => memo,
+            (None, None) => String::new(),
+        };
+
+        let mut tx = Transaction::new(date, description, amount);
+        tx.fit_id = field("FITID");
+        tx.currency = currency.clone();
+        out.push(tx);
+        pos = end;
+    }
+    if n == 0 {
+        return Err(ImportError::Format(
+            "No <STMTTRN> transactions found".into(),
+        ));
+    }
+    Ok(out)
+}
+
+// ---------------------------------------------------------------------------
+// Budget vs actual
+// ---------------------------------------------------------------------------
+
+/// One expense flow's budget and actual spending for a month, in base cents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetLine {
+    pub flow_id: Option<Uuid>,
+    pub name: String,
+    pub budgeted: i64,
+    pub actual: i64,
+}
+
+impl BudgetLine {
+    pub fn remaining(&self) -> i64 {
+        self.budgeted - self.actual
+    }
+}
+
+/// Budgeted (monthly-normalized) against actual spending for every expense
+/// flow in `year`/`month`. Spending not matched to a flow is collected in a
+/// final "Unassigned" line (`flow_id: None`) when there is any.
+pub fn budget_vs_actual(file: &FinancesFile, year: i32, month: Month) -> Vec<BudgetLine> {
+    let spent_in_month = file
+        .transactions
+        .iter()
+        .filter(|t| t.date.year() == year && t.date.month() == month && t.amount < 0);
+
+    let mut lines: Vec<BudgetLine> = file
+        .expenses
+        .iter()
+        .map(|f| BudgetLine {
+            flow_id: Some(f.id),
+            name: f.name.clone(),
+            // Left at 0 without a rate, as in the totals.
+            budgeted: file
+                .currencies
+                .to_base(f.per_month_cents(), f.currency.as_deref())
+                .unwrap_or(0),
+            actual: 0,
+        })
+        .collect();
+    let mut unassigned = 0;
+    for t in spent_in_month {
+        let Some(spent) = file.currencies.to_base(-t.amount, t.currency.as_deref()) else {
+            continue;
+        };
+        match lines
+            .iter_mut()
+            .find(|l| l.flow_id.is_some() && l.flow_id == t.flow_id)
+        {
+            Some(line) => line.actual += spent,
+            None => unassigned += spent,
+        }
+    }
+    if unassigned > 0 {
+        lines.push(BudgetLine {
+            flow_id: None,
+            name: "Unassigned".into(),
+            budgeted: 0,
+            actual: unassigned,
+        });
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::finCalc::finances::CashFlow;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    #[test]
+    fn csv_with_quotes_and_debit_credit() {
+        let text = "Date,Description,Debit,Credit\n\
+                    01/05/2026,\"Grocer, Inc.\",45.10,\n\
+                    01/06/2026,Paycheck,,\"1,200.00\"\n";
+        let mapping = CsvMapping {
+            amount_col: None,
+            debit_col: Some(2),
+            credit_col: Some(3),
+            date_format: DateFormat::MonthDayYear,
+            ..CsvMapping::default()
+        };
+        let txs = parse_csv(text, &mapping).unwrap();
+        assert_eq!(txs.len(), 2);
+        assert_eq!(txs[0].description, "Grocer, Inc.");
+        assert_eq!(txs[0].amount, -4510);
+        assert_eq!(txs[0].date, d(2026, 1, 5));
+        assert_eq!(txs[1].amount, 120000);
+    }
+
+    #[test]
+    fn csv_bad_date_reports_row() {
+        let text = "date,desc,amount\nnot-a-date,x,1\n";
+        let err = parse_csv(text, &CsvMapping::default()).unwrap_err();
+        assert!(matches!(err, ImportError::Row(2, _)));
+    }
+
+    #[test]
+    fn ofx_sgml_transactions() {
+        let text = "OFXHEADER:100\n<OFX><BANKMSGSRSV1><STMTTRNRS><STMTRS><CURDEF>EUR\n\
+                    <BANKTRANLIST>\n\
+                    <STMTTRN><TRNTYPE>DEBIT<DTPOSTED>20260105120000[-5:EST]<TRNAMT>-12.50<FITID>A1<NAME>Netflix.com\n\
+                    <STMTTRN><TRNTYPE>CREDIT<DTPOSTED>20260107<TRNAMT>100.00<FITID>A2<MEMO>Refund</STMTTRN>\n\
+                    </BANKTRANLIST></STMTRS></STMTTRNRS></BANKMSGSRSV1></OFX>";
+        let txs = parse_statement(text, &CsvMapping::default()).unwrap();
+        assert_eq!(txs.len(), 2);
+        assert_eq!(txs[0].description, "Netflix.com");
+        assert_eq!(txs[0].amount, -1250);
+        assert_eq!(txs[0].fit_id.as_deref(), Some("A1"));
+        assert_eq!(txs[0].currency.as_deref(), Some("EUR"));
+        assert_eq!(txs[1].description, "Refund");
+        assert_eq!(txs[1].date, d(2026, 1, 7));
+    }
+
+    #[test]
+    fn ofx_non_ascii_date_is_a_row_error() {
+        let text = "<OFX><STMTTRN><DTPOSTED>202é0105<TRNAMT>-1.00</STMTTRN></OFX>";
+        let err = parse_statement(text, &CsvMapping::default()).unwrap_err();
+        assert!(matches!(err, ImportError::Row(1, _)));
+    }
+
+    #[test]
+    fn import_dedups_and_matches() {
+        let mut file = FinancesFile::default();
+        let mut netflix = CashFlow::new("Netflix");
+        netflix.amount = 1550;
+        let netflix_id = netflix.id;
+        let rent = CashFlow::new("Rent");
+        let rent_id = rent.id;
+        file.expenses.push(netflix);
+        file.expenses.push(rent);
+        file.match_rules.push(MatchRule {
+            flow_id: rent_id,
+            contains: Some("property mgmt".into()),
+            amount: None,
+            tolerance: 0,
+        });
+
+        let batch = vec![
+            Transaction::new(d(2026, 1, 3), "NETFLIX.COM 866-579", -1550),
+            Transaction::new(d(2026, 1, 1), "ACME Property Mgmt", -150000),
+            Transaction::new(d(2026, 1, 9), "Coffee", -450),
+        ];
+        let report = file.import_transactions(batch.clone());
+        assert_eq!((report.added, report.matched), (3, 2));
+
+        let again = file.import_transactions(batch);
+        assert_eq!((again.added, again.duplicates), (0, 3));
+
+        let lines = budget_vs_actual(&file, 2026, Month::January);
+        let nf = lines
+            .iter()
+            .find(|l| l.flow_id == Some(netflix_id))
+            .unwrap();
+        assert_eq!((nf.budgeted, nf.actual), (1550, 1550));
+        let un = lines.last().unwrap();
+        assert_eq!((un.flow_id, un.actual), (None, 450));
+    }
+
+    #[test]
+    fn identical_rows_in_one_import_are_all_kept() {
+        let mut file = FinancesFile::default();
+        let coffee = Transaction::new(d(2026, 1, 9), "Coffee", -450);
+        let report = file.import_transactions(vec![coffee.clone(), coffee.clone()]);
+        assert_eq!((report.added, report.duplicates), (2, 0));
+
+        // Re-importing the statement adds nothing; a longer one adds the third.
+        let again = file.import_transactions(vec![coffee.clone(), coffee.clone()]);
+        assert_eq!((again.added, again.duplicates), (0, 2));
+        let more = file.import_transactions(vec![coffee.clone(), coffee.clone(), coffee]);
+        assert_eq!((more.added, more.duplicates), (1, 2));
+        assert_eq!(file.transactions.len(), 3);
+    }
+}
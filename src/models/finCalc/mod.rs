@@ -1,3 +1,17 @@
-pub mod tranche;
+pub mod finances;
 
-pub mod finances;
\ No newline at end of file
+pub mod projection;
+
+pub mod debt;
+
+pub mod ledger;
+
+pub mod envelopes;
+
+pub mod scenario;
+
+pub mod tax;
+
+pub mod history;
+
+pub mod balances;
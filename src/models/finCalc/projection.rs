@@ -0,0 +1,334 @@
+// src/models/finCalc/projection.rs
+//! Day-by-day cash-flow projection for a `FinancesFile`.
+//!
+//! Every income and expense is expanded into dated occurrences from its anchor
+//! `date` and `Frequency`, using the scheduler's recurrence engine
+//! (`schedule::events::expand_dates`) so the chart and the calendar agree on when a
+//! flow lands. The occurrences are then folded into a running balance that
+//! starts from today's net worth.
+
+use chrono::{Datelike, NaiveDate};
+use time::{Date, Month};
+use uuid::Uuid;
+
+use crate::models::schedule::events::{expand_dates, Freq, Recurrence};
+
+use super::finances::{CashFlow, FinancesFile, Frequency};
+
+// ---------------------------------------------------------------------------
+// Date plumbing: the finance model is `time::Date`, the scheduler is chrono.
+// ---------------------------------------------------------------------------
+
+pub fn to_naive(d: Date) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(d.year(), u8::from(d.month()) as u32, d.day() as u32)
+}
+
+pub fn from_naive(d: NaiveDate) -> Option<Date> {
+    let month = Month::try_from(d.month() as u8).ok()?;
+    Date::from_calendar_date(d.year(), month, d.day() as u8).ok()
+}
+
+/// Add `months` calendar months, clamping the day to the end of the target
+/// month (Jan 31 + 1 month = Feb 28/29). Used for horizons, where "a year from
+/// today" must always exist.
+pub fn add_months(d: Date, months: i32) -> Date {
+    let total = d.year() * 12 + (u8::from(d.month()) as i32 - 1) + months;
+    let year = total.div_euclid(12);
+    let month = Month::try_from((total.rem_euclid(12) + 1) as u8).unwrap_or(Month::January);
+    let day = d.day().min(month.length(year));
+    Date::from_calendar_date(year, month, day).unwrap_or(d)
+}
+
+fn last_of_month(d: Date) -> Date {
+    d.replace_day(d.month().length(d.year())).unwrap_or(d)
+}
+
+/// The scheduler rule for a cash-flow frequency. `OneTime` has none.
+pub fn recurrence_for(f: Frequency) -> Option<Recurrence> {
+    let (freq, interval) = match f {
+        Frequency::OneTime => return None,
+        Frequency::Daily => (Freq::Daily, 1),
+        Frequency::Weekly => (Freq::Weekly, 1),
+        Frequency::Monthly => (Freq::Monthly, 1),
+        Frequency::Yearly => (Freq::Yearly, 1),
+        Frequency::EveryNDays(n) => (Freq::Daily, n.max(1)),
+        Frequency::EveryNMonths(n) => (Freq::Monthly, n.max(1)),
+    };
+    Some(Recurrence {
+        freq,
+        interval,
+        by_weekday: Vec::new(),
+        count: None,
+        until: None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Occurrences
+// ---------------------------------------------------------------------------
+
+/// Every date `flow` lands on within `[from, to]` inclusive.
+///
+/// One deliberate difference from the calendar: money doesn't skip a month
+/// because it has no 31st. Monthly/yearly flows anchored past the 28th land on
+/// the last day of short months instead (a Jan 31 bill is paid Feb 28, a
+/// Feb 29 renewal on Feb 28 in common years). The series is expanded from the
+/// 1st of the anchor month, which never skips, and each date is clamped back to
+/// the anchor's day.
+pub fn flow_dates(flow: &CashFlow, from: Date, to: Date) -> Vec<Date> {
+    if from > to {
+        return Vec::new();
+    }
+    let Some(rec) = recurrence_for(flow.frequency) else {
+        return if flow.date >= from && flow.date <= to {
+            vec![flow.date]
+        } else {
+            Vec::new()
+        };
+    };
+    let (Some(anchor), Some(win_from), Some(win_to)) =
+        (to_naive(flow.date), to_naive(from), to_naive(to))
+    else {
+        return Vec::new();
+    };
+
+    match rec.freq {
+        Freq::Daily | Freq::Weekly => expand_dates(anchor, &rec, win_from, win_to)
+            .into_iter()
+            .filter_map(from_naive)
+            .collect(),
+        Freq::Monthly | Freq::Yearly => {
+            let day = flow.date.day();
+            let first = anchor.with_day(1).unwrap_or(anchor);
+            let first_from = win_from.with_day(1).unwrap_or(win_from);
+            expand_dates(first, &rec, first_from, win_to)
+                .into_iter()
+                .filter_map(from_naive)
+                .filter_map(|d| d.replace_day(day.min(d.month().length(d.year()))).ok())
+                .filter(|d| *d >= from && *d <= to && *d >= flow.date)
+                .collect()
+        }
+    }
+}
+
+/// A single dated cash movement, in base-currency cents. `amount` is signed:
+/// income positive, expenses negative.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowOccurrence {
+    pub flow_id: Uuid,
+    pub name: String,
+    pub date: Date,
+    pub amount: i64, // cents
+}
+
+/// All income and expense occurrences in `[from, to]`, sorted by date.
+/// Taxable income lands net of its share of the withholding, matching
+/// `monthly_net`.
+pub fn occurrences(file: &FinancesFile, from: Date, to: Date) -> Vec<FlowOccurrence> {
+    let mut out = Vec::new();
+    let take_home = file.take_home_ratio();
+    for (flow, sign) in file
+        .income
+        .iter()
+        .map(|f| (f, 1))
+        .chain(file.expenses.iter().map(|f| (f, -1)))
+    {
+        if flow.amount == 0 {
+            continue;
+        }
+        let Some(mut amount) = file
+            .currencies
+            .to_base(flow.amount, flow.currency.as_deref())
+        else {
+            continue;
+        };
+        if sign > 0 && flow.taxable {
+            amount = (amount as f64 * take_home).round() as i64;
+        }
+        for date in flow_dates(flow, from, to) {
+            out.push(FlowOccurrence {
+                flow_id: flow.id,
+                name: flow.name.clone(),
+                date,
+                amount: sign * amount,
+            });
+        }
+    }
+    out.sort_by(|a, b| a.date.cmp(&b.date).then(a.name.cmp(&b.name)));
+    out
+}
+
+// ---------------------------------------------------------------------------
+// Running balance
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    Daily,
+    Monthly,
+}
+
+/// Balance at the close of `date` (a day, or the last day of a month bucket).
+/// `inflow`/`outflow` are the totals for that bucket; `outflow` is positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalancePoint {
+    pub date: Date,
+    pub inflow: i64,
+    pub outflow: i64,
+    pub balance: i64,
+}
+
+/// Project the running balance over `[from, to]`, starting from the file's
+/// current net worth. Flows dated `from` are counted (the opening balance is
+/// taken before that day's movements). Monthly buckets close on the last day of
+/// each month, except the final one, which closes on `to`.
+pub fn project(
+    file: &FinancesFile,
+    from: Date,
+    to: Date,
+    granularity: Granularity,
+) -> Vec<BalancePoint> {
+    let mut out = Vec::new();
+    if from > to {
+        return out;
+    }
+    let occ = occurrences(file, from, to);
+    let mut idx = 0;
+    let mut balance = file.net_worth();
+    let mut bucket_start = from;
+
+    loop {
+        let bucket_end = match granularity {
+            Granularity::Daily => bucket_start,
+            Granularity::Monthly => last_of_month(bucket_start).min(to),
+        };
+        let (mut inflow, mut outflow) = (0_i64, 0_i64);
+        while idx < occ.len() && occ[idx].date <= bucket_end {
+            let amt = occ[idx].amount;
+            if amt >= 0 {
+                inflow += amt;
+            } else {
+                outflow -= amt;
+            }
+            idx += 1;
+        }
+        balance += inflow - outflow;
+        out.push(BalancePoint {
+            date: bucket_end,
+            inflow,
+            outflow,
+            balance,
+        });
+        match bucket_end.next_day() {
+            Some(next) if next <= to => bucket_start = next,
+            _ => break,
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    fn flow(amount: i64, frequency: Frequency, date: Date) -> CashFlow {
+        CashFlow {
+            amount,
+            frequency,
+            date,
+            ..CashFlow::new("test")
+        }
+    }
+
+    #[test]
+    fn month_end_anchor_clamps_to_short_months() {
+        let rent = flow(10000, Frequency::Monthly, d(2026, 1, 31));
+        let dates = flow_dates(&rent, d(2026, 1, 1), d(2026, 4, 30));
+        assert_eq!(
+            dates,
+            vec![
+                d(2026, 1, 31),
+                d(2026, 2, 28),
+                d(2026, 3, 31),
+                d(2026, 4, 30)
+            ]
+        );
+        // Leap year: February has a 29th.
+        let dates = flow_dates(&rent, d(2028, 2, 1), d(2028, 2, 29));
+        assert_eq!(dates, vec![d(2028, 2, 29)]);
+    }
+
+    #[test]
+    fn leap_day_anchor_yearly() {
+        let renewal = flow(5000, Frequency::Yearly, d(2024, 2, 29));
+        let dates = flow_dates(&renewal, d(2024, 1, 1), d(2028, 12, 31));
+        assert_eq!(
+            dates,
+            vec![
+                d(2024, 2, 29),
+                d(2025, 2, 28),
+                d(2026, 2, 28),
+                d(2027, 2, 28),
+                d(2028, 2, 29),
+            ]
+        );
+    }
+
+    #[test]
+    fn nothing_before_the_anchor() {
+        let bonus = flow(100000, Frequency::Monthly, d(2026, 3, 15));
+        let dates = flow_dates(&bonus, d(2026, 3, 1), d(2026, 4, 30));
+        assert_eq!(dates, vec![d(2026, 3, 15), d(2026, 4, 15)]);
+
+        let once = flow(100000, Frequency::OneTime, d(2026, 3, 15));
+        assert!(flow_dates(&once, d(2026, 3, 16), d(2026, 12, 31)).is_empty());
+    }
+
+    #[test]
+    fn running_balance_daily_and_monthly() {
+        let mut file = FinancesFile::default();
+        file.income
+            .push(flow(200000, Frequency::Monthly, d(2026, 1, 1)));
+        file.expenses
+            .push(flow(1000, Frequency::Weekly, d(2026, 1, 5)));
+
+        let daily = project(&file, d(2026, 1, 1), d(2026, 1, 7), Granularity::Daily);
+        assert_eq!(daily.len(), 7);
+        assert_eq!(daily[0].balance, 200000);
+        assert_eq!(daily[4].outflow, 1000);
+        assert_eq!(daily.last().unwrap().balance, 199000);
+
+        let monthly = project(&file, d(2026, 1, 1), d(2026, 3, 15), Granularity::Monthly);
+        let ends: Vec<Date> = monthly.iter().map(|p| p.date).collect();
+        assert_eq!(ends, vec![d(2026, 1, 31), d(2026, 2, 28), d(2026, 3, 15)]);
+        // 3 paychecks; Mondays: Jan 5..26 (4), Feb 2..23 (4), Mar 2, 9 (2).
+        assert_eq!(monthly.last().unwrap().balance, 600000 - 10 * 1000);
+    }
+
+    #[test]
+    fn taxable_pay_lands_net_of_withholding() {
+        let mut file = FinancesFile::default();
+        file.tax.flat_rate = 20.0;
+        let mut pay = flow(500000, Frequency::Monthly, d(2026, 1, 1));
+        pay.taxable = true;
+        file.income.push(pay);
+        file.income.push(flow(10000, Frequency::Monthly, d(2026, 1, 1)));
+
+        let monthly = project(&file, d(2026, 1, 1), d(2026, 3, 31), Granularity::Monthly);
+        assert_eq!(monthly[0].inflow, 400000 + 10000);
+        // The chart agrees with the Overview's net.
+        assert_eq!(monthly[0].inflow, file.monthly_net());
+        assert_eq!(monthly.last().unwrap().balance, 3 * 410000);
+    }
+
+    #[test]
+    fn add_months_clamps() {
+        assert_eq!(add_months(d(2026, 1, 31), 1), d(2026, 2, 28));
+        assert_eq!(add_months(d(2026, 10, 17), 12), d(2027, 10, 17));
+        assert_eq!(add_months(d(2026, 3, 31), -1), d(2026, 2, 28));
+    }
+}
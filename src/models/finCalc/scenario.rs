@@ -0,0 +1,260 @@
+// src/models/finCalc/scenario.rs
+//! Long-range net-worth scenarios driven by investment growth.
+//!
+//! Investment assets compound monthly at their expected return and take their
+//! monthly contribution; everything else (other assets, liabilities) is held
+//! flat. Two views come out of the same inputs: a deterministic compound path,
+//! and a Monte Carlo band where each month's return is drawn from a log-normal
+//! with the asset's volatility. The random stream is seeded, so the same file
+//! always draws the same band.
+
+use serde::{Deserialize, Serialize};
+
+use super::finances::{AssetKind, FinancesFile};
+
+pub const MIN_YEARS: u32 = 10;
+pub const MAX_YEARS: u32 = 40;
+pub const MAX_RUNS: u32 = 5000;
+
+/// Simulator settings, persisted in `finances.json`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Scenario {
+    #[serde(default = "default_years")]
+    pub years: u32,
+    #[serde(default = "default_runs")]
+    pub runs: u32,
+    #[serde(default = "default_seed")]
+    pub seed: u64,
+}
+
+fn default_years() -> u32 {
+    30
+}
+fn default_runs() -> u32 {
+    500
+}
+fn default_seed() -> u64 {
+    42
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self {
+            years: default_years(),
+            runs: default_runs(),
+            seed: default_seed(),
+        }
+    }
+}
+
+/// Net worth at the end of a simulated year, in base cents. Year 0 is today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScenarioPoint {
+    pub year: u32,
+    pub deterministic: i64,
+    pub p10: i64,
+    pub p50: i64,
+    pub p90: i64,
+}
+
+// ---------------------------------------------------------------------------
+// Seeded randomness (SplitMix64 + Box–Muller) — no RNG crate needed.
+// ---------------------------------------------------------------------------
+
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in (0, 1].
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+
+    fn standard_normal(&mut self) -> f64 {
+        let (u1, u2) = (self.next_f64(), self.next_f64());
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Simulation
+// ---------------------------------------------------------------------------
+
+struct Holding {
+    value: f64,
+    contribution: f64,
+    /// Deterministic monthly growth factor.
+    growth: f64,
+    /// Log-normal parameters for one month.
+    mu: f64,
+    sigma: f64,
+}
+
+fn holdings(file: &FinancesFile) -> (Vec<Holding>, i64) {
+    let mut flat = -file.total_liabilities();
+    let mut out = Vec::new();
+    for a in file.assets.iter() {
+        let Some(value) = file.currencies.to_base(a.value, a.currency.as_deref()) else {
+            continue;
+        };
+        if a.kind != AssetKind::Investment {
+            flat += value;
+            continue;
+        }
+        let annual = 1.0 + a.expected_return / 100.0;
+        let growth = annual.max(0.0).powf(1.0 / 12.0);
+        let sigma = (a.volatility / 100.0).max(0.0) / 12f64.sqrt();
+        out.push(Holding {
+            value: value as f64,
+            contribution: file
+                .currencies
+                .to_base(a.contribution, a.currency.as_deref())
+                .unwrap_or(0) as f64,
+            growth,
+            // E[exp(N(mu, sigma²))] = growth, so the band centres on the
+            // deterministic path's rate.
+            mu: growth.max(f64::MIN_POSITIVE).ln() - sigma * sigma / 2.0,
+            sigma,
+        });
+    }
+    (out, flat)
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let idx = ((sorted.len() - 1) as f64 * p).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+/// Year-by-year net worth for `scenario.years` years (clamped to 10–40).
+pub fn simulate(file: &FinancesFile, scenario: &Scenario) -> Vec<ScenarioPoint> {
+    let years = scenario.years.clamp(MIN_YEARS, MAX_YEARS);
+    let runs = scenario.runs.clamp(1, MAX_RUNS) as usize;
+    let (start, flat) = holdings(file);
+    let months = years as usize * 12;
+
+    // Deterministic path.
+    let mut det = vec![flat + start.iter().map(|h| h.value).sum::<f64>() as i64];
+    let mut values: Vec<f64> = start.iter().map(|h| h.value).collect();
+    for m in 1..=months {
+        for (v, h) in values.iter_mut().zip(start.iter()) {
+            *v = *v * h.growth + h.contribution;
+        }
+        if m % 12 == 0 {
+            det.push(flat + values.iter().sum::<f64>() as i64);
+        }
+    }
+
+    // Monte Carlo: by_year[y][run] = invested total at the end of year y.
+    let mut rng = SplitMix64(scenario.seed);
+    let mut by_year = vec![Vec::with_capacity(runs); years as usize + 1];
+    for _ in 0..runs {
+        let mut values: Vec<f64> = start.iter().map(|h| h.value).collect();
+        by_year[0].push(values.iter().sum::<f64>());
+        for m in 1..=months {
+            for (v, h) in values.iter_mut().zip(start.iter()) {
+                let r = (h.mu + h.sigma * rng.standard_normal()).exp();
+                *v = (*v * r + h.contribution).max(0.0);
+            }
+            if m % 12 == 0 {
+                by_year[m / 12].push(values.iter().sum::<f64>());
+            }
+        }
+    }
+
+    by_year
+        .into_iter()
+        .enumerate()
+        .map(|(y, mut totals)| {
+            totals.sort_by(f64::total_cmp);
+            ScenarioPoint {
+                year: y as u32,
+                deterministic: det[y],
+                p10: flat + percentile(&totals, 0.10) as i64,
+                p50: flat + percentile(&totals, 0.50) as i64,
+                p90: flat + percentile(&totals, 0.90) as i64,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::finCalc::finances::{AssetEntry, LiabilityEntry};
+
+    fn file_with(ret: f64, vol: f64, contribution: i64) -> FinancesFile {
+        let mut f = FinancesFile::default();
+        f.assets.push(AssetEntry {
+            kind: AssetKind::Investment,
+            value: 1000000,
+            expected_return: ret,
+            volatility: vol,
+            contribution,
+            ..AssetEntry::new()
+        });
+        f.assets.push(AssetEntry {
+            value: 50000,
+            ..AssetEntry::new()
+        });
+        f.liabilities.push(LiabilityEntry {
+            balance: 20000,
+            ..LiabilityEntry::new()
+        });
+        f
+    }
+
+    #[test]
+    fn deterministic_compounds_annually_at_expected_return() {
+        let f = file_with(7.0, 0.0, 0);
+        let pts = simulate(&f, &Scenario::default());
+        assert_eq!(pts.len(), 31);
+        assert_eq!(pts[0].deterministic, 1030000);
+        let expected = 30000.0 + 1000000.0 * 1.07_f64.powi(10);
+        assert!((pts[10].deterministic as f64 - expected).abs() < 2.0);
+        // No volatility: every run is the deterministic path.
+        assert!((pts[10].p10 - pts[10].p90).abs() <= 1);
+    }
+
+    #[test]
+    fn contributions_add_up_without_growth() {
+        let f = file_with(0.0, 0.0, 10000);
+        let pts = simulate(&f, &Scenario::default());
+        assert_eq!(pts[1].deterministic, 1030000 + 12 * 10000);
+    }
+
+    #[test]
+    fn band_is_ordered_and_seeded() {
+        let f = file_with(6.0, 15.0, 5000);
+        let s = Scenario {
+            years: 20,
+            runs: 400,
+            seed: 7,
+        };
+        let a = simulate(&f, &s);
+        assert_eq!(a, simulate(&f, &s));
+        let last = a.last().unwrap();
+        assert!(last.p10 < last.p50 && last.p50 < last.p90);
+        let other = simulate(&f, &Scenario { seed: 8, ..s });
+        assert_ne!(a, other);
+    }
+
+    #[test]
+    fn years_are_clamped() {
+        let f = file_with(5.0, 0.0, 0);
+        let short = Scenario {
+            years: 3,
+            ..Scenario::default()
+        };
+        assert_eq!(simulate(&f, &short).len(), MIN_YEARS as usize + 1);
+    }
+}
@@ -0,0 +1,239 @@
+// src/models/finCalc/tax.rs
+//! Income tax estimate for gross pay.
+//!
+//! Income flows marked `taxable` are gross pay. Their annualized total goes
+//! through pre-tax contributions (401k and the like), the standard deduction and
+//! a `TaxModel`, plus a flat percentage for everything a bracket table doesn't
+//! cover (state, payroll). The result is what comes out of the paycheck each
+//! month, which `monthly_net()` and the take-home history subtract.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::finances::money_cents;
+use crate::utils::json_store::{load_json, StoreError};
+
+/// Sample federal table shipped with the app.
+pub const DEFAULT_TAX_TABLE_PATH: &str = "assets/data/tax/us_federal_2025_single.json";
+
+/// Anything that can turn taxable income into tax owed.
+pub trait TaxModel {
+    fn name(&self) -> &str;
+    /// Taken off gross (after pre-tax contributions) before `tax_on`.
+    fn standard_deduction(&self) -> i64;
+    /// Annual tax on `taxable` cents.
+    fn tax_on(&self, taxable: i64) -> i64;
+    /// Rate applied to the last dollar of `taxable`, percent.
+    fn marginal_rate(&self, taxable: i64) -> f64;
+}
+
+// ---------------------------------------------------------------------------
+// Progressive bracket table (loaded from JSON)
+// ---------------------------------------------------------------------------
+
+/// `rate` percent applies to each dollar above `over`, up to the next
+/// bracket's `over`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TaxBracket {
+    #[serde(with = "money_cents")]
+    pub over: i64,
+    pub rate: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct BracketTable {
+    #[serde(default)]
+    pub name: String,
+    #[serde(with = "money_cents", default)]
+    pub standard_deduction: i64,
+    #[serde(default)]
+    pub brackets: Vec<TaxBracket>,
+}
+
+impl BracketTable {
+    /// Load a table from JSON and sort its brackets by threshold.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StoreError> {
+        let mut table: BracketTable = load_json(path)?;
+        table.brackets.sort_by_key(|b| b.over);
+        Ok(table)
+    }
+}
+
+impl TaxModel for BracketTable {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn standard_deduction(&self) -> i64 {
+        self.standard_deduction
+    }
+
+    fn tax_on(&self, taxable: i64) -> i64 {
+        let mut tax = 0.0;
+        for (i, b) in self.brackets.iter().enumerate() {
+            if taxable <= b.over {
+                break;
+            }
+            let top = self
+                .brackets
+                .get(i + 1)
+                .map_or(taxable, |next| next.over.min(taxable));
+            tax += (top - b.over) as f64 * b.rate / 100.0;
+        }
+        tax.round() as i64
+    }
+
+    fn marginal_rate(&self, taxable: i64) -> f64 {
+        self.brackets
+            .iter()
+            .rev()
+            .find(|b| taxable > b.over)
+            .map_or(0.0, |b| b.rate)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Settings persisted in finances.json
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct TaxSettings {
+    /// Empty until a table is loaded; an empty table taxes nothing.
+    #[serde(default)]
+    pub table: BracketTable,
+    /// Fixed pre-tax contributions per year (401k, HSA...).
+    #[serde(with = "money_cents", default)]
+    pub pre_tax_annual: i64,
+    /// Pre-tax contributions as a percent of gross.
+    #[serde(default)]
+    pub pre_tax_percent: f64,
+    /// Flat percent of gross for state/payroll taxes.
+    #[serde(default)]
+    pub flat_rate: f64,
+}
+
+/// Annual figures, base-currency cents.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TaxBreakdown {
+    pub gross: i64,
+    pub pre_tax: i64,
+    pub deduction: i64,
+    pub taxable: i64,
+    pub income_tax: i64,
+    pub flat_tax: i64,
+    pub marginal_rate: f64,
+}
+
+impl TaxBreakdown {
+    /// Everything that comes out of gross pay.
+    pub fn withheld(&self) -> i64 {
+        self.pre_tax + self.income_tax + self.flat_tax
+    }
+    pub fn net(&self) -> i64 {
+        self.gross - self.withheld()
+    }
+    /// Taxes (not contributions) as a percent of gross.
+    pub fn effective_rate(&self) -> f64 {
+        if self.gross <= 0 {
+            return 0.0;
+        }
+        (self.income_tax + self.flat_tax) as f64 / self.gross as f64 * 100.0
+    }
+}
+
+impl TaxSettings {
+    /// Break down `gross` annual cents using `model` for the income tax.
+    pub fn breakdown_with(&self, model: &dyn TaxModel, gross: i64) -> TaxBreakdown {
+        let gross = gross.max(0);
+        let pre_tax = (self.pre_tax_annual.max(0)
+            + (gross as f64 * self.pre_tax_percent.max(0.0) / 100.0).round() as i64)
+            .min(gross);
+        let deduction = model.standard_deduction().clamp(0, gross - pre_tax);
+        let taxable = gross - pre_tax - deduction;
+        TaxBreakdown {
+            gross,
+            pre_tax,
+            deduction,
+            taxable,
+            income_tax: model.tax_on(taxable),
+            flat_tax: (gross as f64 * self.flat_rate.max(0.0) / 100.0).round() as i64,
+            marginal_rate: model.marginal_rate(taxable),
+        }
+    }
+
+    pub fn breakdown(&self, gross: i64) -> TaxBreakdown {
+        self.breakdown_with(&self.table, gross)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> BracketTable {
+        BracketTable {
+            name: "Test".into(),
+            standard_deduction: 1000000,
+            brackets: vec![
+                TaxBracket {
+                    over: 0,
+                    rate: 10.0,
+                },
+                TaxBracket {
+                    over: 2000000,
+                    rate: 20.0,
+                },
+                TaxBracket {
+                    over: 5000000,
+                    rate: 30.0,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn progressive_brackets() {
+        let t = table();
+        assert_eq!(t.tax_on(0), 0);
+        assert_eq!(t.tax_on(1000000), 100000);
+        // 10% of 20k + 20% of 30k + 30% of 10k.
+        assert_eq!(t.tax_on(6000000), 200000 + 600000 + 300000);
+        assert_eq!(t.marginal_rate(6000000), 30.0);
+    }
+
+    #[test]
+    fn pre_tax_and_deduction_reduce_taxable() {
+        let settings = TaxSettings {
+            table: table(),
+            pre_tax_annual: 500000,
+            pre_tax_percent: 0.0,
+            flat_rate: 5.0,
+        };
+        let b = settings.breakdown(6000000);
+        assert_eq!(b.taxable, 6000000 - 500000 - 1000000);
+        assert_eq!(b.income_tax, 200000 + 500000);
+        assert_eq!(b.flat_tax, 300000);
+        assert_eq!(b.net(), 6000000 - 500000 - 700000 - 300000);
+    }
+
+    #[test]
+    fn low_income_is_not_negative() {
+        let settings = TaxSettings {
+            table: table(),
+            ..TaxSettings::default()
+        };
+        let b = settings.breakdown(400000);
+        assert_eq!((b.deduction, b.taxable, b.income_tax), (400000, 0, 0));
+        assert_eq!(b.net(), 400000);
+    }
+
+    #[test]
+    fn shipped_table_parses() {
+        let json = include_str!("../../../assets/data/tax/us_federal_2025_single.json");
+        let t: BracketTable = serde_json::from_str(json).unwrap();
+        // Whole dollars must be written with a decimal point; bare integers
+        // are read as cents.
+        assert_eq!(t.standard_deduction, 1575000);
+        assert!(t.brackets.windows(2).all(|w| w[0].over < w[1].over));
+    }
+}
@@ -0,0 +1,301 @@
+// src/models/goals/forecast.rs
+//! Are we on track? Progress logging and completion forecasts.
+//!
+//! Numeric and finance goals keep a dated log of their value. A least-squares
+//! line through the recent log predicts when the target is reached, which is
+//! compared against the goal's `deadline`. Parents take the worst status in
+//! their subtree, so one slipping subgoal shows up at the root.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use time::{Date, Duration};
+use uuid::Uuid;
+
+use super::goals::{GoalNode, GoalsFile};
+
+/// Only log entries this close to the newest one feed the trend, so an old
+/// plateau doesn't drag the forecast.
+pub const TREND_WINDOW_DAYS: i64 = 90;
+
+/// Forecasts further out than this past the newest entry are treated as
+/// "not getting there"; a near-flat trend would otherwise land centuries out.
+pub const FORECAST_HORIZON_DAYS: i64 = 5 * 365;
+
+/// A forecast landing at least this early counts as ahead.
+pub const AHEAD_MARGIN_DAYS: i64 = 7;
+
+/// A metric value on one day.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ProgressEntry {
+    pub date: Date,
+    pub value: f64,
+}
+
+/// Ordered best to worst, so a roll-up is the max over a subtree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GoalStatus {
+    Done,
+    /// No deadline, or not enough history to say.
+    Unknown,
+    Ahead,
+    OnTrack,
+    AtRisk,
+    Overdue,
+}
+
+impl GoalStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GoalStatus::Done => "Done",
+            GoalStatus::Unknown => "No forecast",
+            GoalStatus::Ahead => "Ahead",
+            GoalStatus::OnTrack => "On track",
+            GoalStatus::AtRisk => "At risk",
+            GoalStatus::Overdue => "Overdue",
+        }
+    }
+}
+
+/// Trend through the progress log.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Forecast {
+    /// Metric units per day.
+    pub slope_per_day: f64,
+    /// When the trend reaches the target; `None` if it's flat, heading away
+    /// or too slow to get there within [`FORECAST_HORIZON_DAYS`].
+    pub predicted: Option<Date>,
+}
+
+/// Least-squares `(slope, intercept)` of value against days since `origin`.
+fn fit(points: &[ProgressEntry], origin: Date) -> Option<(f64, f64)> {
+    let n = points.len() as f64;
+    let xs: Vec<f64> = points
+        .iter()
+        .map(|p| (p.date - origin).whole_days() as f64)
+        .collect();
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.value).sum::<f64>() / n;
+    let (mut sxy, mut sxx) = (0.0, 0.0);
+    for (x, p) in xs.iter().zip(points) {
+        sxy += (x - mean_x) * (p.value - mean_y);
+        sxx += (x - mean_x) * (x - mean_x);
+    }
+    if sxx == 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    Some((slope, mean_y - slope * mean_x))
+}
+
+impl GoalNode {
+    /// Record `value` on `date` (one entry per day) without touching the
+    /// metric.
+    pub(crate) fn record_progress(&mut self, date: Date, value: f64) {
+        match self.progress.binary_search_by_key(&date, |p| p.date) {
+            Ok(i) => self.progress[i].value = value,
+            Err(i) => self.progress.insert(i, ProgressEntry { date, value }),
+        }
+    }
+
+    /// Log a value and make the newest entry the metric's `current`.
+    pub fn log_progress(&mut self, date: Date, value: f64) {
+        self.record_progress(date, value);
+        let latest = self.progress.last().map_or(value, |p| p.value);
+        if let Some(current) = self.metric.current_mut() {
+            *current = latest;
+        }
+        self.sync_completed_from_metric();
+    }
+
+    pub fn remove_progress(&mut self, date: Date) {
+        self.progress.retain(|p| p.date != date);
+        self.touch();
+    }
+
+    /// Needs a target and at least two logged days in the trend window.
+    pub fn forecast(&self) -> Option<Forecast> {
+        let (_, target) = self.metric.values()?;
+        let latest = self.progress.last()?;
+        let window: Vec<ProgressEntry> = self
+            .progress
+            .iter()
+            .filter(|p| (latest.date - p.date).whole_days() <= TREND_WINDOW_DAYS)
+            .copied()
+            .collect();
+        let origin = window.first()?.date;
+        let (slope, intercept) = fit(&window, origin)?;
+
+        // Which way counts as progress comes from where the log started.
+        let rising = target >= self.progress[0].value;
+        let heading_there = if rising { slope > 1e-9 } else { slope < -1e-9 };
+        // Bounded in days before any date arithmetic, so a near-flat slope
+        // can't overflow `Duration` or `Date`.
+        let max_days = ((latest.date - origin).whole_days() + FORECAST_HORIZON_DAYS) as f64;
+        let predicted = heading_there
+            .then(|| ((target - intercept) / slope).ceil().max(0.0))
+            .filter(|days| *days <= max_days)
+            .and_then(|days| origin.checked_add(Duration::days(days as i64)))
+            .map(|p| p.max(latest.date));
+        Some(Forecast {
+            slope_per_day: slope,
+            predicted,
+        })
+    }
+
+    /// This goal's own status, ignoring children.
+    pub fn status(&self, today: Date) -> GoalStatus {
+        if self.is_done() {
+            return GoalStatus::Done;
+        }
+        let Some(deadline) = self.deadline else {
+            return GoalStatus::Unknown;
+        };
+        if today > deadline {
+            return GoalStatus::Overdue;
+        }
+        match self.forecast() {
+            None => GoalStatus::Unknown,
+            Some(Forecast {
+                predicted: None, ..
+            }) => GoalStatus::AtRisk,
+            Some(Forecast {
+                predicted: Some(p), ..
+            }) => {
+                if p + Duration::days(AHEAD_MARGIN_DAYS) <= deadline {
+                    GoalStatus::Ahead
+                } else if p <= deadline {
+                    GoalStatus::OnTrack
+                } else {
+                    GoalStatus::AtRisk
+                }
+            }
+        }
+    }
+
+    /// Worst status among this goal and its unarchived subgoals. A finished
+    /// goal is done regardless of its children.
+    pub fn rolled_up_status(&self, today: Date) -> GoalStatus {
+        let own = self.status(today);
+        if own == GoalStatus::Done {
+            return own;
+        }
+        self.children
+            .iter()
+            .filter(|c| !c.archived)
+            .map(|c| c.rolled_up_status(today))
+            .fold(own, GoalStatus::max)
+    }
+}
+
+impl GoalsFile {
+    /// Rolled-up status of every node, computed in one pass.
+    pub fn statuses(&self, today: Date) -> HashMap<Uuid, GoalStatus> {
+        fn walk(n: &GoalNode, today: Date, out: &mut HashMap<Uuid, GoalStatus>) -> GoalStatus {
+            let own = n.status(today);
+            let mut worst = own;
+            for c in &n.children {
+                let s = walk(c, today, out);
+                if !c.archived {
+                    worst = worst.max(s);
+                }
+            }
+            let rolled = if own == GoalStatus::Done { own } else { worst };
+            out.insert(n.id, rolled);
+            rolled
+        }
+        let mut out = HashMap::new();
+        for r in &self.roots {
+            walk(r, today, &mut out);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::goals::goals::{GoalCategory, Metric, Smart};
+    use time::Month;
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    fn goal(target: f64) -> GoalNode {
+        let smart = Smart::default();
+        let metric = Metric::Numeric {
+            unit: "pages".into(),
+            current: 0.0,
+            target,
+            clamp_0_100: false,
+        };
+        GoalNode::new("Read", GoalCategory::Research, smart, metric)
+    }
+
+    #[test]
+    fn linear_log_predicts_completion() {
+        let mut g = goal(100.0);
+        g.log_progress(d(2026, 1, 1), 0.0);
+        g.log_progress(d(2026, 1, 11), 10.0);
+        g.log_progress(d(2026, 1, 21), 20.0);
+        assert_eq!(g.metric.values(), Some((20.0, 100.0)));
+
+        let f = g.forecast().unwrap();
+        assert!((f.slope_per_day - 1.0).abs() < 1e-9);
+        assert_eq!(f.predicted, Some(d(2026, 4, 11)));
+
+        let today = d(2026, 1, 21);
+        g.deadline = Some(d(2026, 6, 1));
+        assert_eq!(g.status(today), GoalStatus::Ahead);
+        g.deadline = Some(d(2026, 4, 14));
+        assert_eq!(g.status(today), GoalStatus::OnTrack);
+        g.deadline = Some(d(2026, 3, 1));
+        assert_eq!(g.status(today), GoalStatus::AtRisk);
+        assert_eq!(g.status(d(2026, 3, 2)), GoalStatus::Overdue);
+    }
+
+    #[test]
+    fn stalled_or_reversing_goals_are_at_risk() {
+        let mut g = goal(100.0);
+        g.deadline = Some(d(2026, 12, 31));
+        g.log_progress(d(2026, 1, 1), 30.0);
+        assert_eq!(g.status(d(2026, 1, 2)), GoalStatus::Unknown);
+        g.log_progress(d(2026, 2, 1), 20.0);
+        assert_eq!(g.forecast().unwrap().predicted, None);
+        assert_eq!(g.status(d(2026, 2, 2)), GoalStatus::AtRisk);
+    }
+
+    #[test]
+    fn slow_trends_are_at_risk_instead_of_overflowing() {
+        let mut g = goal(1_000_000.0);
+        g.deadline = Some(d(2026, 12, 31));
+        g.log_progress(d(2026, 1, 1), 100.0);
+        g.log_progress(d(2026, 1, 31), 101.0);
+        assert_eq!(g.forecast().unwrap().predicted, None);
+        assert_eq!(g.status(d(2026, 2, 1)), GoalStatus::AtRisk);
+        assert_eq!(g.rolled_up_status(d(2026, 2, 1)), GoalStatus::AtRisk);
+    }
+
+    #[test]
+    fn parents_take_the_worst_child_status() {
+        let today = d(2026, 5, 1);
+        let mut root = goal(10.0);
+        let mut late = goal(10.0);
+        late.deadline = Some(d(2026, 4, 1));
+        let late_id = late.id;
+        let mut done = goal(1.0);
+        done.log_progress(d(2026, 1, 1), 1.0);
+        root.children.push(done);
+        root.children.push(late);
+
+        assert_eq!(root.rolled_up_status(today), GoalStatus::Overdue);
+        let mut file = GoalsFile::default();
+        file.roots.push(root.clone());
+        let all = file.statuses(today);
+        assert_eq!(all[&root.id], GoalStatus::Overdue);
+        assert_eq!(all[&late_id], GoalStatus::Overdue);
+
+        root.children[1].archived = true;
+        assert_eq!(root.rolled_up_status(today), GoalStatus::Unknown);
+    }
+}
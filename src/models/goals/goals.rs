@@ -1,18 +1,31 @@
 use serde::{Deserialize, Serialize};
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 use uuid::Uuid;
 
+use super::forecast::ProgressEntry;
+use super::journal::JournalEntry;
+use super::review::ReviewSchedule;
+use super::timeblock::{TimeBudget, TimeEntry};
+use crate::models::finCalc::finances::FinancesFile;
+use crate::models::schedule::events::Recurrence;
+
 pub const DEFAULT_GOALS_PATH: &str = "assets/data/goals/goals.json";
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GoalsFile {
     pub version: u32,
     pub roots: Vec<GoalNode>,
+    /// Mark a parent complete once every subgoal is (see `apply_auto_complete`).
+    #[serde(default)]
+    pub auto_complete_parents: bool,
+    /// When to walk through the goals; `None` means no reviews.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<ReviewSchedule>,
 }
 
 impl Default for GoalsFile {
     fn default() -> Self {
-        Self { version: 1, roots: vec![] }
+        Self { version: 1, roots: vec![], auto_complete_parents: false, review: None }
     }
 }
 
@@ -51,6 +64,77 @@ pub enum Metric {
         #[serde(default)]
         clamp_0_100: bool,
     },
+
+    /// Money goal read live from a FinCalc file, in its base currency (whole
+    /// units, like `Numeric`). `current` is the last value seen, so progress
+    /// still shows when the file isn't loaded.
+    Finance {
+        source: FinanceSource,
+        #[serde(default)]
+        current: f64,
+        target: f64,
+    },
+
+    /// Recurring habit ("lift 3x/week"): scheduled by `cadence` from `start`,
+    /// with the days it was actually done. Never done by itself; see
+    /// `habit.rs` for streaks.
+    Habit {
+        cadence: Recurrence,
+        start: Date,
+        #[serde(default)]
+        checkins: Vec<Date>,
+    },
+}
+
+/// What a `Metric::Finance` tracks.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FinanceSource {
+    /// One asset's value.
+    Asset { id: Uuid },
+    /// Assets minus liabilities.
+    NetWorth,
+    /// A debt paid down to zero. `current` is how much has been paid off and
+    /// `target` the balance when the goal was set, so done still means
+    /// `current >= target`.
+    DebtPaidOff { id: Uuid },
+}
+
+impl FinanceSource {
+    /// Base-currency cents: the asset's value, net worth, or the debt's
+    /// remaining balance. `None` if the entry no longer exists or its
+    /// currency has no rate.
+    pub fn read_cents(&self, fin: &FinancesFile) -> Option<i64> {
+        match self {
+            FinanceSource::Asset { id } => fin
+                .assets
+                .iter()
+                .find(|a| a.id == *id)
+                .and_then(|a| fin.currencies.to_base(a.value, a.currency.as_deref())),
+            FinanceSource::NetWorth => Some(fin.net_worth()),
+            FinanceSource::DebtPaidOff { id } => fin
+                .liabilities
+                .iter()
+                .find(|l| l.id == *id)
+                .and_then(|l| fin.currencies.to_base(l.balance, l.currency.as_deref())),
+        }
+    }
+
+    pub fn label(&self, fin: &FinancesFile) -> String {
+        let name = |found: Option<&String>| {
+            found.cloned().unwrap_or_else(|| "missing entry".to_string())
+        };
+        match self {
+            FinanceSource::Asset { id } => {
+                name(fin.assets.iter().find(|a| a.id == *id).map(|a| &a.name))
+            }
+            FinanceSource::NetWorth => "Net worth".to_string(),
+            FinanceSource::DebtPaidOff { id } => format!(
+                "Pay off {}",
+                name(fin.liabilities.iter().find(|l| l.id == *id).map(|l| &l.name))
+            ),
+        }
+    }
 }
 
 impl Metric {
@@ -58,6 +142,8 @@ impl Metric {
         match self {
             Metric::Boolean { done } => *done,
             Metric::Numeric { current, target, .. } => current >= target,
+            Metric::Finance { current, target, .. } => current >= target,
+            Metric::Habit { .. } => false,
         }
     }
 
@@ -68,12 +154,69 @@ impl Metric {
                 // For numeric metrics, "done" snaps current to target (or resets to 0).
                 *current = if done { *target } else { 0.0 };
             }
+            // Live balances and habits can't be checked off; only
+            // `completed` changes.
+            Metric::Finance { .. } | Metric::Habit { .. } => {}
         }
     }
+
+    /// `(current, target)` for numeric and finance metrics.
+    pub fn values(&self) -> Option<(f64, f64)> {
+        match self {
+            Metric::Boolean { .. } | Metric::Habit { .. } => None,
+            Metric::Numeric { current, target, .. } | Metric::Finance { current, target, .. } => {
+                Some((*current, *target))
+            }
+        }
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut f64> {
+        match self {
+            Metric::Boolean { .. } | Metric::Habit { .. } => None,
+            Metric::Numeric { current, .. } | Metric::Finance { current, .. } => Some(current),
+        }
+    }
+
+    /// Finance goal for paying off `debt`, whose balance today is the target.
+    pub fn debt_payoff(fin: &FinancesFile, debt: Uuid) -> Self {
+        let source = FinanceSource::DebtPaidOff { id: debt };
+        let balance = source.read_cents(fin).unwrap_or(0);
+        Metric::Finance {
+            source,
+            current: 0.0,
+            target: balance as f64 / 100.0,
+        }
+    }
+
+    /// Re-read a `Finance` metric's value. Returns true if `current` changed;
+    /// other metrics and missing sources are left alone.
+    pub fn refresh(&mut self, fin: &FinancesFile) -> bool {
+        let Metric::Finance {
+            source,
+            current,
+            target,
+        } = self
+        else {
+            return false;
+        };
+        let Some(cents) = source.read_cents(fin) else {
+            return false;
+        };
+        let value = cents as f64 / 100.0;
+        let next = match source {
+            FinanceSource::DebtPaidOff { .. } => *target - value,
+            _ => value,
+        };
+        if (next - *current).abs() < 0.005 {
+            return false;
+        }
+        *current = next;
+        true
+    }
 }
 
 /// SMART fields. Keep them short but required.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct Smart {
     pub specific: String,
     pub measurable: String,
@@ -110,6 +253,30 @@ pub struct GoalNode {
     #[serde(default)]
     pub completed: bool,
 
+    /// Structured due date; `smart.time_bound` stays free text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<Date>,
+
+    /// Dated values of a numeric/finance metric, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub progress: Vec<ProgressEntry>,
+
+    /// Share of the parent's progress relative to siblings (default 1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+
+    /// Calendar time to block out each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_budget: Option<TimeBudget>,
+
+    /// Time spent, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub time_log: Vec<TimeEntry>,
+
+    /// Notes, reviews and recorded changes, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub journal: Vec<JournalEntry>,
+
     /// Subgoals
     #[serde(default)]
     pub children: Vec<GoalNode>,
@@ -137,6 +304,12 @@ impl GoalNode {
             updated_at: now,
             archived: false,
             completed,
+            deadline: None,
+            progress: vec![],
+            weight: None,
+            time_budget: None,
+            time_log: vec![],
+            journal: vec![],
             children: vec![],
         }
     }
@@ -180,6 +353,22 @@ impl GoalNode {
         self.completed = self.metric.is_done();
         self.touch();
     }
+
+    /// Refresh finance metrics in this subtree. Returns how many changed.
+    pub fn refresh_from_finances(&mut self, fin: &FinancesFile) -> usize {
+        let mut changed = 0;
+        if self.metric.refresh(fin) {
+            if let Some((current, _)) = self.metric.values() {
+                self.record_progress(OffsetDateTime::now_utc().date(), current);
+            }
+            self.sync_completed_from_metric();
+            changed += 1;
+        }
+        for c in &mut self.children {
+            changed += c.refresh_from_finances(fin);
+        }
+        changed
+    }
 }
 
 impl GoalsFile {
@@ -191,4 +380,88 @@ impl GoalsFile {
         }
         None
     }
+
+    /// Pull live values into every finance-bound goal and flip `completed`
+    /// where they cross the target. Returns how many goals changed.
+    pub fn refresh_from_finances(&mut self, fin: &FinancesFile) -> usize {
+        self.roots
+            .iter_mut()
+            .map(|r| r.refresh_from_finances(fin))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::finCalc::finances::{AssetEntry, LiabilityEntry};
+
+    #[test]
+    fn finance_goal_completes_when_balance_reaches_target() {
+        let mut fin = FinancesFile::default();
+        let savings = AssetEntry {
+            value: 400000,
+            ..AssetEntry::new()
+        };
+        let id = savings.id;
+        fin.assets.push(savings);
+
+        let mut goals = GoalsFile::default();
+        let metric = Metric::Finance {
+            source: FinanceSource::Asset { id },
+            current: 0.0,
+            target: 5000.0,
+        };
+        goals.roots.push(GoalNode::new(
+            "Emergency fund",
+            GoalCategory::Wealth,
+            Smart::default(),
+            metric,
+        ));
+
+        assert_eq!(goals.refresh_from_finances(&fin), 1);
+        assert!(!goals.roots[0].completed);
+        assert_eq!(goals.refresh_from_finances(&fin), 0);
+
+        fin.assets[0].value = 500000;
+        goals.refresh_from_finances(&fin);
+        assert!(goals.roots[0].completed);
+    }
+
+    #[test]
+    fn debt_goal_counts_paid_down_amount() {
+        let mut fin = FinancesFile::default();
+        let card = LiabilityEntry {
+            balance: 120000,
+            ..LiabilityEntry::new()
+        };
+        let id = card.id;
+        fin.liabilities.push(card);
+
+        let mut node = GoalNode::new(
+            "Clear the card",
+            GoalCategory::Wealth,
+            Smart::default(),
+            Metric::debt_payoff(&fin, id),
+        );
+        fin.liabilities[0].balance = 20000;
+        node.refresh_from_finances(&fin);
+        assert_eq!(
+            node.metric,
+            Metric::Finance {
+                source: FinanceSource::DebtPaidOff { id },
+                current: 1000.0,
+                target: 1200.0,
+            }
+        );
+        assert!(!node.completed);
+
+        fin.liabilities[0].balance = 0;
+        node.refresh_from_finances(&fin);
+        assert!(node.completed);
+
+        // A deleted debt keeps the last value.
+        fin.liabilities.clear();
+        assert!(!node.metric.refresh(&fin));
+    }
 }
@@ -0,0 +1,383 @@
+// src/models/goals/habit.rs
+//! Recurring habit goals: cadence, check-ins and streaks.
+//!
+//! A habit's `cadence` is a scheduler `Recurrence` anchored at `start`, so
+//! "lift Mon/Wed/Fri" is a weekly rule with three weekdays. Time is cut into
+//! periods of `interval` cadence units (days, Monday-based weeks, calendar
+//! months or years); a period is complete when it has at least as many
+//! check-ins as scheduled days. Check-ins on unscheduled days still count, so
+//! a session moved to Tuesday keeps the week.
+//!
+//! The same rule is pushed to the `TimeStore` as one recurring all-day event
+//! per habit, linked back with [`goal_link`].
+
+use chrono::NaiveDate;
+use time::{Date, Duration, Month};
+use uuid::Uuid;
+
+use crate::models::finCalc::projection::{from_naive, to_naive};
+use crate::models::schedule::events::{expand_dates, Event, EventSource, Freq, Recurrence, When};
+
+use super::goals::{GoalNode, GoalsFile, Metric};
+
+/// Scheme for `Event::link` values that point at a goal.
+pub const GOAL_LINK_PREFIX: &str = "goal:";
+
+/// `goal:<id>`, the calendar's way back to a goal.
+pub fn goal_link(id: Uuid) -> String {
+    format!("{GOAL_LINK_PREFIX}{id}")
+}
+
+/// The goal id in a `goal:<id>` link, if that's what it is.
+pub fn parse_goal_link(link: &str) -> Option<Uuid> {
+    Uuid::parse_str(link.strip_prefix(GOAL_LINK_PREFIX)?).ok()
+}
+
+/// Whether `e` is the calendar copy of a habit, as opposed to a time block
+/// or something filed under Goals by hand. Habit events share their goal's id.
+pub fn is_habit_event(e: &Event) -> bool {
+    e.source == EventSource::Goals && e.link.as_deref().and_then(parse_goal_link) == Some(e.id)
+}
+
+/// One cadence period and how it went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HabitPeriod {
+    pub start: Date,
+    /// Inclusive.
+    pub end: Date,
+    pub scheduled: u32,
+    pub done: u32,
+}
+
+impl HabitPeriod {
+    pub fn complete(&self) -> bool {
+        self.done >= self.scheduled
+    }
+
+    /// Check-ins over scheduled days, capped at 1.
+    pub fn rate(&self) -> f64 {
+        if self.scheduled == 0 {
+            return 1.0;
+        }
+        (self.done as f64 / self.scheduled as f64).min(1.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HabitStats {
+    /// Complete periods in a row, ending with the latest finished one (or
+    /// the current one once it's complete).
+    pub current_streak: u32,
+    pub longest_streak: u32,
+    /// Share of finished periods that were complete; 0 before the first
+    /// period ends.
+    pub completion_rate: f64,
+    /// Every period with something scheduled, oldest first, up to the one
+    /// holding `today`.
+    pub periods: Vec<HabitPeriod>,
+}
+
+/// Months since year 0, for month arithmetic.
+fn month_index(d: Date) -> i32 {
+    d.year() * 12 + d.month() as i32 - 1
+}
+
+fn first_of_month(index: i32) -> Date {
+    let month = Month::try_from((index.rem_euclid(12) + 1) as u8).unwrap_or(Month::January);
+    Date::from_calendar_date(index.div_euclid(12), month, 1).unwrap_or(Date::MIN)
+}
+
+/// `[start, end]` of the period holding `date`.
+fn period_bounds(cadence: &Recurrence, start: Date, date: Date) -> (Date, Date) {
+    let n = cadence.interval.max(1) as i64;
+    match cadence.freq {
+        Freq::Daily => {
+            let idx = (date - start).whole_days().div_euclid(n);
+            let from = start + Duration::days(idx * n);
+            (from, from + Duration::days(n - 1))
+        }
+        Freq::Weekly => {
+            let monday = start - Duration::days(start.weekday().number_days_from_monday() as i64);
+            let idx = (date - monday).whole_weeks().div_euclid(n);
+            let from = monday + Duration::weeks(idx * n);
+            (from, from + Duration::days(7 * n - 1))
+        }
+        Freq::Monthly | Freq::Yearly => {
+            let (base, months) = match cadence.freq {
+                Freq::Monthly => (month_index(start), n as i32),
+                _ => (start.year() * 12, 12 * n as i32),
+            };
+            let idx = (month_index(date) - base).div_euclid(months);
+            let from = base + idx * months;
+            (
+                first_of_month(from),
+                first_of_month(from + months) - Duration::days(1),
+            )
+        }
+    }
+}
+
+/// Periods from the one holding `start` through the one holding `today`.
+pub fn habit_periods(
+    cadence: &Recurrence,
+    start: Date,
+    checkins: &[Date],
+    today: Date,
+) -> Vec<HabitPeriod> {
+    if today < start {
+        return vec![];
+    }
+    let (_, last_end) = period_bounds(cadence, start, today);
+    let (Some(from), Some(to)) = (to_naive(start), to_naive(last_end)) else {
+        return vec![];
+    };
+    let scheduled: Vec<Date> = expand_dates(from, cadence, from, to)
+        .into_iter()
+        .filter_map(from_naive)
+        .collect();
+
+    let mut out = vec![];
+    let mut cursor = start;
+    while cursor <= today {
+        let (p_start, p_end) = period_bounds(cadence, start, cursor);
+        let within = |d: &&Date| **d >= p_start && **d <= p_end;
+        let planned = scheduled.iter().filter(within).count() as u32;
+        if planned > 0 {
+            out.push(HabitPeriod {
+                start: p_start,
+                end: p_end,
+                scheduled: planned,
+                done: checkins.iter().filter(within).count() as u32,
+            });
+        }
+        cursor = p_end + Duration::days(1);
+    }
+    out
+}
+
+/// Streaks and completion rate as of `today`. The period holding `today` only
+/// counts once it's complete, so an unfinished week doesn't break a streak.
+pub fn habit_stats(
+    cadence: &Recurrence,
+    start: Date,
+    checkins: &[Date],
+    today: Date,
+) -> HabitStats {
+    let periods = habit_periods(cadence, start, checkins, today);
+    let counted: &[HabitPeriod] = match periods.last() {
+        Some(p) if p.end >= today && !p.complete() => &periods[..periods.len() - 1],
+        _ => &periods,
+    };
+
+    let (mut run, mut longest) = (0u32, 0u32);
+    for p in counted {
+        run = if p.complete() { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    let finished: Vec<&HabitPeriod> = periods.iter().filter(|p| p.end < today).collect();
+    let completion_rate = if finished.is_empty() {
+        0.0
+    } else {
+        finished.iter().filter(|p| p.complete()).count() as f64 / finished.len() as f64
+    };
+    HabitStats {
+        current_streak: run,
+        longest_streak: longest,
+        completion_rate,
+        periods,
+    }
+}
+
+impl Metric {
+    /// Streaks for a habit; `None` for other metrics.
+    pub fn habit_stats(&self, today: Date) -> Option<HabitStats> {
+        let Metric::Habit {
+            cadence,
+            start,
+            checkins,
+        } = self
+        else {
+            return None;
+        };
+        Some(habit_stats(cadence, *start, checkins, today))
+    }
+}
+
+impl GoalNode {
+    /// Mark a habit done on `date`, or undo it if it already was. Returns
+    /// whether the day is now checked in; always false for other metrics.
+    pub fn toggle_check_in(&mut self, date: Date) -> bool {
+        let Metric::Habit { checkins, .. } = &mut self.metric else {
+            return false;
+        };
+        let now_in = match checkins.binary_search(&date) {
+            Ok(i) => {
+                checkins.remove(i);
+                false
+            }
+            Err(i) => {
+                checkins.insert(i, date);
+                true
+            }
+        };
+        self.touch();
+        now_in
+    }
+
+    /// The calendar event for an active habit: all-day on each scheduled day.
+    pub fn habit_event(&self) -> Option<Event> {
+        let Metric::Habit { cadence, start, .. } = &self.metric else {
+            return None;
+        };
+        if self.archived || self.is_done() {
+            return None;
+        }
+        let date: NaiveDate = to_naive(*start)?;
+        Some(Event {
+            // Stable per goal, so re-syncing doesn't churn ids.
+            id: self.id,
+            title: format!("Habit: {}", self.title),
+            notes: String::new(),
+            when: When::AllDay { date },
+            source: EventSource::Goals,
+            recurrence: Some(cadence.clone()),
+            link: Some(goal_link(self.id)),
+        })
+    }
+}
+
+impl GoalsFile {
+    /// One event per active habit anywhere in the tree (archived subtrees
+    /// are skipped whole).
+    pub fn habit_events(&self) -> Vec<Event> {
+        fn walk(nodes: &[GoalNode], out: &mut Vec<Event>) {
+            for n in nodes.iter().filter(|n| !n.archived) {
+                out.extend(n.habit_event());
+                walk(&n.children, out);
+            }
+        }
+        let mut out = vec![];
+        walk(&self.roots, &mut out);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::goals::goals::{GoalCategory, Smart};
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    /// Mon/Wed/Fri.
+    fn three_a_week() -> Recurrence {
+        Recurrence {
+            freq: Freq::Weekly,
+            interval: 1,
+            by_weekday: vec![0, 2, 4],
+            count: None,
+            until: None,
+        }
+    }
+
+    fn habit(start: Date) -> GoalNode {
+        let smart = Smart::default();
+        let metric = Metric::Habit {
+            cadence: three_a_week(),
+            start,
+            checkins: vec![],
+        };
+        GoalNode::new("Lift", GoalCategory::Health, smart, metric)
+    }
+
+    #[test]
+    fn weekly_periods_count_scheduled_days_and_check_ins() {
+        // Wed 2026-01-07: the first week only has Wed and Fri left.
+        let mut g = habit(d(2026, 1, 7));
+        g.toggle_check_in(d(2026, 1, 7));
+        g.toggle_check_in(d(2026, 1, 9));
+        // A Tuesday session still counts towards the week.
+        for day in [12, 13, 16] {
+            g.toggle_check_in(d(2026, 1, day));
+        }
+        let stats = g.metric.habit_stats(d(2026, 1, 21)).unwrap();
+        let p = &stats.periods;
+        assert_eq!(p.len(), 3);
+        assert_eq!(
+            (p[0].start, p[0].scheduled, p[0].done),
+            (d(2026, 1, 5), 2, 2)
+        );
+        assert_eq!((p[1].scheduled, p[1].done), (3, 3));
+        assert_eq!((p[2].end, p[2].done), (d(2026, 1, 25), 0));
+
+        // The week in progress doesn't break the streak yet.
+        assert_eq!(stats.current_streak, 2);
+        assert_eq!(stats.longest_streak, 2);
+        assert_eq!(stats.completion_rate, 1.0);
+
+        // Un-checking the Friday breaks week one.
+        assert!(!g.toggle_check_in(d(2026, 1, 9)));
+        let stats = g.metric.habit_stats(d(2026, 1, 26)).unwrap();
+        assert_eq!(stats.current_streak, 0);
+        assert_eq!(stats.longest_streak, 1);
+        assert!((stats.completion_rate - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn daily_and_monthly_periods() {
+        let daily = Recurrence {
+            freq: Freq::Daily,
+            interval: 1,
+            ..Recurrence::default()
+        };
+        let start = d(2026, 3, 1);
+        let checkins = [d(2026, 3, 1), d(2026, 3, 3), d(2026, 3, 4), d(2026, 3, 5)];
+        let s = habit_stats(&daily, start, &checkins, d(2026, 3, 5));
+        assert_eq!(s.periods.len(), 5);
+        assert_eq!((s.current_streak, s.longest_streak), (3, 3));
+        assert_eq!(s.completion_rate, 0.75);
+
+        let monthly = Recurrence {
+            freq: Freq::Monthly,
+            interval: 1,
+            ..Recurrence::default()
+        };
+        let s = habit_stats(&monthly, d(2026, 1, 31), &[d(2026, 3, 2)], d(2026, 4, 10));
+        // February has no 31st, so nothing is scheduled there.
+        let starts: Vec<Date> = s.periods.iter().map(|p| p.start).collect();
+        assert_eq!(starts, [d(2026, 1, 1), d(2026, 3, 1)]);
+        assert_eq!(s.periods[1].end, d(2026, 3, 31));
+        assert_eq!(s.longest_streak, 1);
+        assert_eq!(s.current_streak, 1);
+    }
+
+    #[test]
+    fn active_habits_become_linked_events() {
+        let mut file = GoalsFile::default();
+        let g = habit(d(2026, 1, 5));
+        let id = g.id;
+        let mut shelved = habit(d(2026, 1, 5));
+        shelved.archived = true;
+        file.roots.push(g);
+        file.roots.push(shelved);
+
+        let events = file.habit_events();
+        assert_eq!(events.len(), 1);
+        let link = events[0].link.as_deref().unwrap();
+        assert_eq!(parse_goal_link(link), Some(id));
+        assert_eq!(events[0].id, id);
+        assert!(is_habit_event(&events[0]));
+        let days: Vec<NaiveDate> = events[0]
+            .occurrences(
+                NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 1, 11).unwrap(),
+            )
+            .iter()
+            .map(|o| o.date())
+            .collect();
+        assert_eq!(days.len(), 3);
+        assert_eq!(parse_goal_link("jaxbrain:abc"), None);
+    }
+}
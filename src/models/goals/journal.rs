@@ -0,0 +1,244 @@
+// src/models/goals/journal.rs
+//! A per-goal journal: dated notes plus the changes worth remembering.
+//!
+//! Notes and reviews are written by hand. Metric values, targets, completion
+//! and archiving are recorded by [`GoalsFile::journaled`]: it notes what each
+//! goal looked like before an edit and diffs only those fields afterwards, so
+//! an edit path just has to go through it rather than log each change.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::goals::{GoalNode, GoalsFile};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JournalEntry {
+    pub at: OffsetDateTime,
+    pub kind: JournalKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalKind {
+    Note { text: String },
+    Value { from: f64, to: f64 },
+    Target { from: f64, to: f64 },
+    Completed { done: bool },
+    Archived { archived: bool },
+    /// A pass through the review screen; `note` may be empty.
+    Reviewed { note: String },
+}
+
+impl JournalKind {
+    pub fn describe(&self) -> String {
+        match self {
+            JournalKind::Note { text } => text.clone(),
+            JournalKind::Value { from, to } => format!("Value {} → {}", from, to),
+            JournalKind::Target { from, to } => format!("Target {} → {}", from, to),
+            JournalKind::Completed { done: true } => "Marked complete".into(),
+            JournalKind::Completed { done: false } => "Reopened".into(),
+            JournalKind::Archived { archived: true } => "Archived".into(),
+            JournalKind::Archived { archived: false } => "Restored from archive".into(),
+            JournalKind::Reviewed { note } if note.is_empty() => "Reviewed".into(),
+            JournalKind::Reviewed { note } => format!("Reviewed: {}", note),
+        }
+    }
+}
+
+impl GoalNode {
+    /// Append a hand-written note; blank text is ignored.
+    pub fn add_note(&mut self, text: &str, at: OffsetDateTime) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        self.journal.push(JournalEntry {
+            at,
+            kind: JournalKind::Note { text: text.to_string() },
+        });
+        self.touch();
+    }
+
+    /// The fields the journal watches, to diff after an edit.
+    pub fn watched(&self) -> Watched {
+        Watched {
+            values: self.metric.values(),
+            done: self.is_done(),
+            archived: self.archived,
+        }
+    }
+
+    /// What changed on this goal alone since `before` (not its subgoals).
+    pub fn changes_since(&self, before: &Watched) -> Vec<JournalKind> {
+        let mut out = vec![];
+        if let (Some((was, was_target)), Some((now, now_target))) =
+            (before.values, self.metric.values())
+        {
+            if (now - was).abs() > f64::EPSILON {
+                out.push(JournalKind::Value { from: was, to: now });
+            }
+            if (now_target - was_target).abs() > f64::EPSILON {
+                out.push(JournalKind::Target { from: was_target, to: now_target });
+            }
+        }
+        if self.is_done() != before.done {
+            out.push(JournalKind::Completed { done: self.is_done() });
+        }
+        if self.archived != before.archived {
+            out.push(JournalKind::Archived { archived: self.archived });
+        }
+        out
+    }
+}
+
+/// What the journal tracks on one goal: `(current, target)`, completion and
+/// archiving. Small and `Copy`, so taking one per goal before an edit is cheap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Watched {
+    values: Option<(f64, f64)>,
+    done: bool,
+    archived: bool,
+}
+
+impl GoalsFile {
+    /// [`GoalNode::watched`] for every goal in the tree.
+    pub fn watched(&self) -> HashMap<Uuid, Watched> {
+        fn walk(nodes: &[GoalNode], out: &mut HashMap<Uuid, Watched>) {
+            for n in nodes {
+                out.insert(n.id, n.watched());
+                walk(&n.children, out);
+            }
+        }
+        let mut out = HashMap::new();
+        walk(&self.roots, &mut out);
+        out
+    }
+
+    /// Journal entries for every goal that changed since `before`. Goals that
+    /// are new since then get none; their creation is `created_at`.
+    pub fn changes_since(&self, before: &HashMap<Uuid, Watched>, at: OffsetDateTime) -> Vec<(Uuid, JournalEntry)> {
+        fn walk(
+            nodes: &[GoalNode],
+            before: &HashMap<Uuid, Watched>,
+            at: OffsetDateTime,
+            out: &mut Vec<(Uuid, JournalEntry)>,
+        ) {
+            for n in nodes {
+                if let Some(prev) = before.get(&n.id) {
+                    out.extend(n.changes_since(prev).into_iter().map(|kind| (n.id, JournalEntry { at, kind })));
+                }
+                walk(&n.children, before, at, out);
+            }
+        }
+
+        let mut out = vec![];
+        walk(&self.roots, before, at, &mut out);
+        out
+    }
+
+    /// Append entries from [`GoalsFile::changes_since`]. Doesn't touch
+    /// `updated_at`; the change itself already did.
+    pub fn record_changes(&mut self, changes: Vec<(Uuid, JournalEntry)>) {
+        for (id, entry) in changes {
+            if let Some(n) = self.find_mut(id) {
+                n.journal.push(entry);
+            }
+        }
+    }
+
+    /// Run an edit and journal what it changed. Edits that can move a value,
+    /// a target, completion or archiving go through here.
+    pub fn journaled<R>(&mut self, at: OffsetDateTime, edit: impl FnOnce(&mut GoalsFile) -> R) -> R {
+        let before = self.watched();
+        let out = edit(self);
+        let changes = self.changes_since(&before, at);
+        self.record_changes(changes);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::goals::goals::{GoalCategory, Metric, Smart};
+
+    fn goal(current: f64) -> GoalNode {
+        let smart = Smart::default();
+        let metric = Metric::Numeric {
+            unit: "km".into(),
+            current,
+            target: 10.0,
+            clamp_0_100: true,
+        };
+        GoalNode::new("Run", GoalCategory::Health, smart, metric)
+    }
+
+    #[test]
+    fn value_completion_and_archive_changes_are_journaled() {
+        let mut before = GoalsFile::default();
+        let mut root = goal(2.0);
+        root.add_child(goal(0.0));
+        let child_id = root.children[0].id;
+        let root_id = root.id;
+        before.roots.push(root);
+
+        let mut after = before.clone();
+        let before = before.watched();
+        let r = after.find_mut(root_id).unwrap();
+        r.metric = Metric::Numeric {
+            unit: "km".into(),
+            current: 10.0,
+            target: 10.0,
+            clamp_0_100: true,
+        };
+        r.sync_completed_from_metric();
+        after.find_mut(child_id).unwrap().archived = true;
+        after.roots.push(goal(1.0));
+
+        let at = OffsetDateTime::now_utc();
+        let changes = after.changes_since(&before, at);
+        let kinds: Vec<(Uuid, JournalKind)> = changes.iter().map(|(id, e)| (*id, e.kind.clone())).collect();
+        assert_eq!(
+            kinds,
+            [
+                (root_id, JournalKind::Value { from: 2.0, to: 10.0 }),
+                (root_id, JournalKind::Completed { done: true }),
+                (child_id, JournalKind::Archived { archived: true }),
+            ]
+        );
+
+        after.record_changes(changes);
+        assert_eq!(after.roots[0].journal.len(), 2);
+        // Journaling isn't itself a change.
+        let again = after.watched();
+        assert!(after.changes_since(&again, at).is_empty());
+    }
+
+    #[test]
+    fn journaled_edits_record_only_what_they_changed() {
+        let mut file = GoalsFile::default();
+        file.roots.push(goal(2.0));
+        let id = file.roots[0].id;
+        let at = OffsetDateTime::now_utc();
+
+        file.journaled(at, |g| g.find_mut(id).unwrap().title = "Run far".into());
+        assert!(file.roots[0].journal.is_empty());
+
+        file.journaled(at, |g| g.set_archived(id, true)).unwrap();
+        let kinds: Vec<JournalKind> = file.roots[0].journal.iter().map(|e| e.kind.clone()).collect();
+        assert_eq!(kinds, [JournalKind::Archived { archived: true }]);
+    }
+
+    #[test]
+    fn blank_notes_are_dropped() {
+        let mut g = goal(0.0);
+        let at = OffsetDateTime::now_utc();
+        g.add_note("  ", at);
+        g.add_note(" Knee hurt, eased off ", at);
+        assert_eq!(g.journal.len(), 1);
+        assert_eq!(g.journal[0].kind.describe(), "Knee hurt, eased off");
+    }
+}
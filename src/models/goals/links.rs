@@ -0,0 +1,199 @@
+// src/models/goals/links.rs
+//! What a goal's `ContextLink`s point at, and whether it's still there.
+//!
+//! The value's scheme wins over `kind`: `jaxbrain:<node-id>` (e.g.
+//! `jaxbrain:term:xyz`) is a JaxBrain graph node and `time:<event-id>` a
+//! calendar event, whatever the kind says. Otherwise `kind` decides: "path"
+//! is a local file, "url" a web page, "map" a place (a URL or a search) and
+//! "note" plain text.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use uuid::Uuid;
+
+use super::goals::{ContextLink, GoalNode, GoalsFile};
+
+pub const JAXBRAIN_PREFIX: &str = "jaxbrain:";
+pub const TIME_PREFIX: &str = "time:";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkTarget {
+    /// Opened with the system handler.
+    Path(PathBuf),
+    /// Opened in the browser.
+    Url(String),
+    /// A JaxBrain graph node id, e.g. `term:xyz`.
+    JaxBrain(String),
+    /// A calendar event.
+    Event(Uuid),
+    /// Nothing to open; the value is the content.
+    Note(String),
+}
+
+impl LinkTarget {
+    /// Whether following it leaves the app (file or browser).
+    pub fn is_external(&self) -> bool {
+        matches!(self, LinkTarget::Path(_) | LinkTarget::Url(_))
+    }
+}
+
+/// What resolving checks against. Sets left `None` weren't available, so
+/// links into them aren't judged.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KnownTargets {
+    pub events: Option<HashSet<Uuid>>,
+    pub jax_nodes: Option<HashSet<String>>,
+}
+
+/// Percent-encode a map search for a query string.
+fn encode_query(q: &str) -> String {
+    let mut out = String::new();
+    for b in q.trim().bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(b as char),
+            b' ' => out.push('+'),
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+fn has_scheme(v: &str) -> bool {
+    v.starts_with("http://") || v.starts_with("https://")
+}
+
+impl ContextLink {
+    /// Where the link goes; `None` when the value can't name anything (blank,
+    /// or a `time:` link without a valid id).
+    pub fn target(&self) -> Option<LinkTarget> {
+        let value = self.value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        if let Some(node) = value.strip_prefix(JAXBRAIN_PREFIX) {
+            return (!node.is_empty()).then(|| LinkTarget::JaxBrain(node.to_string()));
+        }
+        if let Some(id) = value.strip_prefix(TIME_PREFIX) {
+            return Uuid::parse_str(id).ok().map(LinkTarget::Event);
+        }
+        Some(match self.kind.trim().to_lowercase().as_str() {
+            "path" => LinkTarget::Path(PathBuf::from(value)),
+            "url" if has_scheme(value) => LinkTarget::Url(value.to_string()),
+            "url" => LinkTarget::Url(format!("https://{value}")),
+            "map" if has_scheme(value) => LinkTarget::Url(value.to_string()),
+            "map" => LinkTarget::Url(format!(
+                "https://www.openstreetmap.org/search?query={}",
+                encode_query(value)
+            )),
+            _ => LinkTarget::Note(value.to_string()),
+        })
+    }
+
+    /// The target is gone: a missing file, a deleted event or JaxBrain node,
+    /// or a value that doesn't resolve at all. URLs aren't fetched.
+    pub fn is_broken(&self, known: &KnownTargets) -> bool {
+        match self.target() {
+            None => true,
+            Some(LinkTarget::Path(p)) => !p.exists(),
+            Some(LinkTarget::Event(id)) => known.events.as_ref().is_some_and(|e| !e.contains(&id)),
+            Some(LinkTarget::JaxBrain(node)) => {
+                known.jax_nodes.as_ref().is_some_and(|n| !n.contains(&node))
+            }
+            Some(LinkTarget::Url(_) | LinkTarget::Note(_)) => false,
+        }
+    }
+}
+
+impl GoalNode {
+    /// Indices into `context_links` whose targets are gone.
+    pub fn broken_links(&self, known: &KnownTargets) -> Vec<usize> {
+        self.context_links
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.is_broken(known))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl GoalsFile {
+    /// Broken links across the whole tree.
+    pub fn broken_link_count(&self, known: &KnownTargets) -> usize {
+        fn walk(nodes: &[GoalNode], known: &KnownTargets) -> usize {
+            nodes
+                .iter()
+                .map(|n| n.broken_links(known).len() + walk(&n.children, known))
+                .sum()
+        }
+        walk(&self.roots, known)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(kind: &str, value: &str) -> ContextLink {
+        ContextLink {
+            label: String::new(),
+            kind: kind.into(),
+            value: value.into(),
+        }
+    }
+
+    #[test]
+    fn scheme_beats_kind() {
+        let id = Uuid::new_v4();
+        assert_eq!(
+            link("note", "jaxbrain:term:habeas corpus").target(),
+            Some(LinkTarget::JaxBrain("term:habeas corpus".into()))
+        );
+        assert_eq!(
+            link("url", &format!("time:{id}")).target(),
+            Some(LinkTarget::Event(id))
+        );
+        assert_eq!(link("url", "time:not-a-uuid").target(), None);
+        assert_eq!(
+            link("url", "example.com/land").target(),
+            Some(LinkTarget::Url("https://example.com/land".into()))
+        );
+        assert_eq!(
+            link("map", "Rolla, MO").target(),
+            Some(LinkTarget::Url(
+                "https://www.openstreetmap.org/search?query=Rolla%2C+MO".into()
+            ))
+        );
+        assert_eq!(
+            link("note", "call the county").target(),
+            Some(LinkTarget::Note("call the county".into()))
+        );
+    }
+
+    #[test]
+    fn broken_links_are_flagged_only_when_checkable() {
+        let kept = Uuid::new_v4();
+        let gone = Uuid::new_v4();
+        let here = env!("CARGO_MANIFEST_DIR");
+        let links = [
+            link("path", here),
+            link("path", "/definitely/not/here.txt"),
+            link("event", &format!("time:{kept}")),
+            link("event", &format!("time:{gone}")),
+            link("jax", "jaxbrain:term:tort"),
+            link("url", "https://example.com"),
+            link("path", "  "),
+        ];
+
+        let unknown = KnownTargets::default();
+        let broken: Vec<bool> = links.iter().map(|l| l.is_broken(&unknown)).collect();
+        assert_eq!(broken, [false, true, false, false, false, false, true]);
+
+        let known = KnownTargets {
+            events: Some(HashSet::from([kept])),
+            jax_nodes: Some(HashSet::from(["term:contract".to_string()])),
+        };
+        let broken: Vec<bool> = links.iter().map(|l| l.is_broken(&known)).collect();
+        assert_eq!(broken, [false, true, false, true, true, false, true]);
+    }
+}
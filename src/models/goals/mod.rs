@@ -1 +1,21 @@
-pub mod goals;
\ No newline at end of file
+pub mod goals;
+
+pub mod forecast;
+
+pub mod progress;
+
+pub mod tree;
+
+pub mod habit;
+
+pub mod timeblock;
+
+pub mod smart;
+
+pub mod templates;
+
+pub mod links;
+
+pub mod journal;
+
+pub mod review;
@@ -0,0 +1,182 @@
+// src/models/goals/progress.rs
+//! How far along each goal is, rolled up the tree.
+//!
+//! A leaf's progress is its own metric. A parent averages its unarchived
+//! subgoals by `weight` and, if it has a numeric/finance metric of its own,
+//! counts that as one more share at weight 1. Checkbox parents are judged by
+//! their children alone until they're checked.
+
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::goals::{GoalNode, GoalsFile, Metric};
+
+impl Metric {
+    /// Fraction complete, 0..=1. `start` is where a numeric goal began (its
+    /// first logged value); without one, progress counts up from zero.
+    pub fn ratio(&self, start: Option<f64>) -> f64 {
+        if self.is_done() {
+            return 1.0;
+        }
+        let Some((current, target)) = self.values() else {
+            return 0.0;
+        };
+        let from = start
+            .filter(|s| (target - s).abs() > f64::EPSILON)
+            .unwrap_or(0.0);
+        if (target - from).abs() <= f64::EPSILON {
+            return 0.0;
+        }
+        ((current - from) / (target - from)).clamp(0.0, 1.0)
+    }
+}
+
+impl GoalNode {
+    /// The node's own metric as a fraction; `None` for an unchecked checkbox.
+    pub fn own_progress(&self) -> Option<f64> {
+        if self.is_done() {
+            return Some(1.0);
+        }
+        self.metric.values()?;
+        Some(self.metric.ratio(self.progress.first().map(|p| p.value)))
+    }
+
+    /// Weighted progress of this subtree, 0..=1.
+    pub fn progress(&self) -> f64 {
+        if self.is_done() {
+            return 1.0;
+        }
+        let own = self.own_progress();
+        let (mut sum, mut total) = match own {
+            Some(p) => (p, 1.0),
+            None => (0.0, 0.0),
+        };
+        for c in self.children.iter().filter(|c| !c.archived) {
+            let w = c.weight.unwrap_or(1.0).max(0.0);
+            sum += w * c.progress();
+            total += w;
+        }
+        if total <= 0.0 {
+            return own.unwrap_or(0.0);
+        }
+        sum / total
+    }
+
+    /// Complete this node if every unarchived child is, working bottom-up.
+    /// Returns how many nodes were completed.
+    fn auto_complete(&mut self) -> usize {
+        let mut n: usize = self.children.iter_mut().map(|c| c.auto_complete()).sum();
+        let mut live = self.children.iter().filter(|c| !c.archived).peekable();
+        if !self.is_done() && live.peek().is_some() && live.all(|c| c.is_done()) {
+            self.completed = true;
+            self.touch();
+            n += 1;
+        }
+        n
+    }
+}
+
+impl GoalsFile {
+    /// Progress of every node, by id.
+    pub fn progress(&self) -> HashMap<Uuid, f64> {
+        fn walk(n: &GoalNode, out: &mut HashMap<Uuid, f64>) {
+            out.insert(n.id, n.progress());
+            for c in &n.children {
+                walk(c, out);
+            }
+        }
+        let mut out = HashMap::new();
+        for r in &self.roots {
+            walk(r, &mut out);
+        }
+        out
+    }
+
+    /// If `auto_complete_parents` is on, complete every parent whose subgoals
+    /// are all done. Call after changing completion. Returns how many changed.
+    pub fn apply_auto_complete(&mut self) -> usize {
+        if !self.auto_complete_parents {
+            return 0;
+        }
+        self.roots.iter_mut().map(|r| r.auto_complete()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::goals::goals::{GoalCategory, Smart};
+    use time::{Date, Month};
+
+    fn check(title: &str) -> GoalNode {
+        GoalNode::new(
+            title,
+            GoalCategory::Other,
+            Smart::default(),
+            Metric::Boolean { done: false },
+        )
+    }
+
+    fn numeric(current: f64, target: f64) -> GoalNode {
+        let metric = Metric::Numeric {
+            unit: "pages".into(),
+            current,
+            target,
+            clamp_0_100: true,
+        };
+        GoalNode::new("Read", GoalCategory::Research, Smart::default(), metric)
+    }
+
+    #[test]
+    fn numeric_ratio_counts_from_the_first_logged_value() {
+        let mut g = numeric(0.0, 100.0);
+        assert_eq!(g.own_progress(), Some(0.0));
+        let day = Date::from_calendar_date(2026, Month::January, 1).unwrap();
+        g.log_progress(day, 40.0);
+        g.log_progress(day.next_day().unwrap(), 70.0);
+        // 40 → 100, now at 70: halfway.
+        assert_eq!(g.own_progress(), Some(0.5));
+        assert_eq!(check("x").own_progress(), None);
+    }
+
+    #[test]
+    fn parents_average_children_by_weight() {
+        let mut root = check("root");
+        let mut a = check("a");
+        a.set_done(true);
+        a.weight = Some(3.0);
+        root.children.push(a);
+        root.children.push(check("b"));
+        assert_eq!(root.progress(), 0.75);
+
+        // A numeric parent adds its own ratio as one more share.
+        let mut parent = numeric(50.0, 100.0);
+        parent.children.push(check("c"));
+        assert_eq!(parent.progress(), 0.25);
+
+        let mut archived = check("old");
+        archived.archived = true;
+        root.children.push(archived);
+        assert_eq!(root.progress(), 0.75);
+    }
+
+    #[test]
+    fn auto_complete_is_opt_in_and_bottom_up() {
+        let mut file = GoalsFile::default();
+        let mut root = check("root");
+        let mut mid = check("mid");
+        let mut leaf = check("leaf");
+        leaf.set_done(true);
+        mid.children.push(leaf);
+        root.children.push(mid);
+        file.roots.push(root);
+
+        assert_eq!(file.apply_auto_complete(), 0);
+        assert!(!file.roots[0].completed);
+
+        file.auto_complete_parents = true;
+        assert_eq!(file.apply_auto_complete(), 2);
+        assert!(file.roots[0].completed);
+        assert_eq!(file.progress()[&file.roots[0].id], 1.0);
+    }
+}
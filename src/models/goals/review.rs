@@ -0,0 +1,223 @@
+// src/models/goals/review.rs
+//! Review cadence: a recurring calendar slot for going over the goals.
+//!
+//! One schedule covers the whole tree. Each scheduled day opens a review
+//! period; an open goal is due until it has been reviewed (or created) on or
+//! after the latest scheduled day. The schedule is pushed to the `TimeStore`
+//! as a single recurring all-day event.
+
+use serde::{Deserialize, Serialize};
+use time::{Date, Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::models::finCalc::projection::{from_naive, to_naive};
+use crate::models::schedule::events::{expand_dates, Event, EventSource, Freq, Recurrence, When};
+
+use super::goals::{GoalNode, GoalsFile};
+use super::journal::{JournalEntry, JournalKind};
+
+/// `Event::link` of the review event; the calendar opens the review screen.
+pub const REVIEW_LINK: &str = "goals:review";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewCadence {
+    Weekly,
+    Monthly,
+}
+
+impl ReviewCadence {
+    pub const ALL: [ReviewCadence; 2] = [ReviewCadence::Weekly, ReviewCadence::Monthly];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ReviewCadence::Weekly => "Weekly",
+            ReviewCadence::Monthly => "Monthly",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReviewSchedule {
+    pub cadence: ReviewCadence,
+    /// First review day; sets the weekday or day of month.
+    pub start: Date,
+    /// Stable id of the calendar event.
+    pub event_id: Uuid,
+}
+
+impl ReviewSchedule {
+    pub fn new(cadence: ReviewCadence, start: Date) -> Self {
+        Self { cadence, start, event_id: Uuid::new_v4() }
+    }
+
+    pub fn recurrence(&self) -> Recurrence {
+        Recurrence {
+            freq: match self.cadence {
+                ReviewCadence::Weekly => Freq::Weekly,
+                ReviewCadence::Monthly => Freq::Monthly,
+            },
+            interval: 1,
+            by_weekday: vec![],
+            count: None,
+            until: None,
+        }
+    }
+
+    /// The latest review day on or before `today`, if one has come yet.
+    pub fn last_due(&self, today: Date) -> Option<Date> {
+        let base = to_naive(self.start)?;
+        let to = to_naive(today)?;
+        // Wide enough to hold a monthly day that some months skip (the 31st).
+        let from = to_naive(today - Duration::days(62))?;
+        expand_dates(base, &self.recurrence(), from, to).last().and_then(|d| from_naive(*d))
+    }
+
+    pub fn event(&self) -> Option<Event> {
+        Some(Event {
+            id: self.event_id,
+            title: "Goal review".into(),
+            notes: String::new(),
+            when: When::AllDay { date: to_naive(self.start)? },
+            source: EventSource::Goals,
+            recurrence: Some(self.recurrence()),
+            link: Some(REVIEW_LINK.into()),
+        })
+    }
+}
+
+/// The local calendar day of `at`.
+fn local_date(at: OffsetDateTime) -> Date {
+    chrono::DateTime::from_timestamp(at.unix_timestamp(), 0)
+        .map(|t| t.with_timezone(&chrono::Local).date_naive())
+        .and_then(from_naive)
+        .unwrap_or(at.date())
+}
+
+/// Whether `e` is the calendar copy of the review schedule.
+pub fn is_review_event(e: &Event) -> bool {
+    e.source == EventSource::Goals && e.link.as_deref() == Some(REVIEW_LINK)
+}
+
+impl GoalNode {
+    /// Local day of the last review; creation counts as one. Timestamps are
+    /// UTC but review days are local, so compare in local time.
+    pub fn last_reviewed(&self) -> Date {
+        let at = self
+            .journal
+            .iter()
+            .rev()
+            .find(|e| matches!(e.kind, JournalKind::Reviewed { .. }))
+            .map_or(self.created_at, |e| e.at);
+        local_date(at)
+    }
+
+    pub fn mark_reviewed(&mut self, note: &str, at: OffsetDateTime) {
+        self.journal.push(JournalEntry {
+            at,
+            kind: JournalKind::Reviewed { note: note.trim().to_string() },
+        });
+        self.touch();
+    }
+}
+
+impl GoalsFile {
+    /// Open goals not reviewed since the latest review day, in tree order.
+    /// Archived subtrees and finished goals are skipped.
+    pub fn due_for_review(&self, today: Date) -> Vec<Uuid> {
+        fn walk(nodes: &[GoalNode], due: Date, out: &mut Vec<Uuid>) {
+            for n in nodes.iter().filter(|n| !n.archived) {
+                if !n.is_done() && n.last_reviewed() < due {
+                    out.push(n.id);
+                }
+                walk(&n.children, due, out);
+            }
+        }
+        let mut out = vec![];
+        if let Some(due) = self.review.as_ref().and_then(|r| r.last_due(today)) {
+            walk(&self.roots, due, &mut out);
+        }
+        out
+    }
+
+    pub fn review_event(&self) -> Option<Event> {
+        self.review.as_ref()?.event()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::goals::goals::{GoalCategory, Metric, Smart};
+    use time::{Month, Time};
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    /// Midday UTC, so the local day is the same in any test time zone.
+    fn at(date: Date) -> OffsetDateTime {
+        date.with_time(Time::from_hms(12, 0, 0).unwrap()).assume_utc()
+    }
+
+    fn goal(title: &str, created: Date) -> GoalNode {
+        let smart = Smart::default();
+        let mut g = GoalNode::new(title, GoalCategory::Other, smart, Metric::Boolean { done: false });
+        g.created_at = at(created);
+        g
+    }
+
+    #[test]
+    fn last_due_follows_the_cadence() {
+        // A Sunday.
+        let weekly = ReviewSchedule::new(ReviewCadence::Weekly, d(2026, 3, 1));
+        assert_eq!(weekly.last_due(d(2026, 2, 28)), None);
+        assert_eq!(weekly.last_due(d(2026, 3, 1)), Some(d(2026, 3, 1)));
+        assert_eq!(weekly.last_due(d(2026, 3, 14)), Some(d(2026, 3, 8)));
+
+        let monthly = ReviewSchedule::new(ReviewCadence::Monthly, d(2026, 1, 15));
+        assert_eq!(monthly.last_due(d(2026, 4, 2)), Some(d(2026, 3, 15)));
+    }
+
+    #[test]
+    fn due_goals_are_open_and_unreviewed_since_the_last_review_day() {
+        let mut file = GoalsFile::default();
+        let mut parent = goal("Parent", d(2026, 1, 1));
+        parent.add_child(goal("Child", d(2026, 1, 1)));
+        parent.add_child(goal("New", d(2026, 3, 10)));
+        let mut done = goal("Done", d(2026, 1, 1));
+        done.set_done(true);
+        let mut shelved = goal("Shelved", d(2026, 1, 1));
+        shelved.archived = true;
+        shelved.add_child(goal("Under shelved", d(2026, 1, 1)));
+        file.roots = vec![parent, done, shelved];
+
+        let today = d(2026, 3, 12);
+        assert!(file.due_for_review(today).is_empty(), "no schedule, nothing due");
+
+        file.review = Some(ReviewSchedule::new(ReviewCadence::Weekly, d(2026, 3, 1)));
+        let titles = |f: &GoalsFile| -> Vec<String> {
+            f.due_for_review(today)
+                .into_iter()
+                .map(|id| f.find(id).unwrap().title.clone())
+                .collect()
+        };
+        assert_eq!(titles(&file), ["Parent", "Child"]);
+
+        let child = file.roots[0].children[0].id;
+        file.find_mut(child).unwrap().mark_reviewed("on track", at(d(2026, 3, 9)));
+        assert_eq!(titles(&file), ["Parent"]);
+    }
+
+    #[test]
+    fn last_reviewed_is_the_local_day() {
+        let mut g = goal("Late", d(2026, 1, 1));
+        let late = d(2026, 3, 8).with_time(Time::from_hms(23, 30, 0).unwrap()).assume_utc();
+        g.mark_reviewed("", late);
+        let local = chrono::DateTime::from_timestamp(late.unix_timestamp(), 0)
+            .unwrap()
+            .with_timezone(&chrono::Local)
+            .date_naive();
+        assert_eq!(to_naive(g.last_reviewed()), Some(local));
+    }
+}
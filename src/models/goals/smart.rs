@@ -0,0 +1,258 @@
+// src/models/goals/smart.rs
+//! A light SMART linter.
+//!
+//! `Smart` stays five free strings; this only flags the common gaps: a
+//! `measurable` with no quantity in it, a `time_bound` no date can be read
+//! from, and a numeric target of zero. Warnings never block saving.
+
+use time::{Date, Duration, Month};
+
+use crate::models::finCalc::projection::add_months;
+
+use super::goals::{GoalNode, Smart};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmartIssue {
+    /// `measurable` has no number with a unit next to it.
+    Unmeasured,
+    /// `time_bound` doesn't contain a date or a span we can read.
+    NoDate,
+    /// A numeric or finance target of zero is done before it starts.
+    ZeroTarget,
+}
+
+impl SmartIssue {
+    pub fn message(&self) -> &'static str {
+        match self {
+            SmartIssue::Unmeasured => "Measurable has no number and unit (e.g. \"10 lb\", \"$5,000\", \"3 sessions\").",
+            SmartIssue::NoDate => "Time-bound has no date (e.g. \"2026-04-29\", \"April 29th\", \"within 12 weeks\").",
+            SmartIssue::ZeroTarget => "Target is zero.",
+        }
+    }
+}
+
+/// Spans longer than this ("within 99999999 days") aren't read as deadlines.
+const MAX_SPAN_YEARS: i64 = 100;
+
+const MONTHS: [&str; 12] = [
+    "january", "february", "march", "april", "may", "june", "july", "august", "september",
+    "october", "november", "december",
+];
+
+/// "apr", "april" or "sept" -> April/September; at least three letters.
+fn month_from_name(word: &str) -> Option<Month> {
+    if word.len() < 3 {
+        return None;
+    }
+    let i = MONTHS.iter().position(|m| m.starts_with(word))?;
+    Month::try_from(i as u8 + 1).ok()
+}
+
+/// "29", "29th", "1st".
+fn day_of_month(word: &str) -> Option<u8> {
+    let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let suffix = &word[digits.len()..];
+    if !matches!(suffix, "" | "st" | "nd" | "rd" | "th") {
+        return None;
+    }
+    digits.parse().ok().filter(|d| (1..=31).contains(d))
+}
+
+/// "2026-04-29", "4/29/2026" or "12-29-25" (US order when the year is last).
+fn numeric_date(word: &str) -> Option<Date> {
+    let parts: Vec<&str> = word.split(['-', '/']).collect();
+    let [a, b, c] = parts.as_slice() else {
+        return None;
+    };
+    let nums: Vec<i32> = [a, b, c]
+        .iter()
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    let (year, month, day) = if a.len() == 4 {
+        (nums[0], nums[1], nums[2])
+    } else {
+        let year = if c.len() == 2 { 2000 + nums[2] } else { nums[2] };
+        (year, nums[0], nums[1])
+    };
+    let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+    Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+}
+
+/// The date a free-text time bound points at. Tries, in order: a numeric date,
+/// a month name with a day (next such day if there's no year), and a span
+/// like "12 weeks" counted from `today` (the last number wins in "12–18
+/// months").
+pub fn parse_time_bound(text: &str, today: Date) -> Option<Date> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| c.is_whitespace() || ",;:()[]–—".contains(c))
+        .map(|w| w.trim_matches('.'))
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    if let Some(d) = words.iter().find_map(|w| numeric_date(w)) {
+        return Some(d);
+    }
+
+    for (i, w) in words.iter().enumerate() {
+        let Some(month) = month_from_name(w) else {
+            continue;
+        };
+        let Some(day) = words.get(i + 1).and_then(|w| day_of_month(w)) else {
+            continue;
+        };
+        let year = words
+            .get(i + 2)
+            .and_then(|w| w.parse::<i32>().ok())
+            .filter(|y| (1900..=2999).contains(y));
+        if let Some(y) = year {
+            return Date::from_calendar_date(y, month, day).ok();
+        }
+        let this_year = Date::from_calendar_date(today.year(), month, day).ok()?;
+        if this_year >= today {
+            return Some(this_year);
+        }
+        return Date::from_calendar_date(today.year() + 1, month, day).ok();
+    }
+
+    words.windows(2).find_map(|pair| {
+        let n: i64 = pair[0].parse().ok()?;
+        let (days, months) = match pair[1].trim_end_matches('s') {
+            "day" => (n, 0),
+            "week" => (n.checked_mul(7)?, 0),
+            "month" => (0, n),
+            "year" => (0, n.checked_mul(12)?),
+            _ => return None,
+        };
+        if !(0..=MAX_SPAN_YEARS * 366).contains(&days) || !(0..=MAX_SPAN_YEARS * 12).contains(&months) {
+            return None;
+        }
+        if months > 0 {
+            Some(add_months(today, months as i32))
+        } else {
+            today.checked_add(Duration::days(days))
+        }
+    })
+}
+
+/// Whether `text` has a number with a unit beside it: a currency sign before,
+/// `%` or letters right after ("5km"), or a word after ("3 clients").
+pub fn is_quantified(text: &str) -> bool {
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == ',' || chars[i] == '.') {
+            i += 1;
+        }
+        let before = start.checked_sub(1).map(|j| chars[j]);
+        if matches!(before, Some('$' | '€' | '£' | '¥')) {
+            return true;
+        }
+        let mut j = i;
+        if j < chars.len() && (chars[j] == '%' || chars[j].is_alphabetic()) {
+            return true;
+        }
+        while j < chars.len() && chars[j] == ' ' {
+            j += 1;
+        }
+        if j > i && j < chars.len() && chars[j].is_alphabetic() {
+            return true;
+        }
+    }
+    false
+}
+
+impl Smart {
+    pub fn lint(&self, today: Date) -> Vec<SmartIssue> {
+        let mut out = vec![];
+        if !is_quantified(&self.measurable) {
+            out.push(SmartIssue::Unmeasured);
+        }
+        if parse_time_bound(&self.time_bound, today).is_none() {
+            out.push(SmartIssue::NoDate);
+        }
+        out
+    }
+}
+
+impl GoalNode {
+    /// SMART warnings for this goal alone (not its subgoals).
+    pub fn lint(&self, today: Date) -> Vec<SmartIssue> {
+        let mut out = self.smart.lint(today);
+        if let Some((_, target)) = self.metric.values() {
+            if target == 0.0 {
+                out.push(SmartIssue::ZeroTarget);
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::goals::goals::{GoalCategory, Metric};
+
+    fn d(y: i32, m: u8, day: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), day).unwrap()
+    }
+
+    #[test]
+    fn time_bounds_parse_dates_names_and_spans() {
+        let today = d(2026, 1, 10);
+        let cases = [
+            ("by 2026-04-29", Some(d(2026, 4, 29))),
+            ("done on 4/29/2027.", Some(d(2027, 4, 29))),
+            ("I am 214.6 [12-29-25]", Some(d(2025, 12, 29))),
+            ("I want to achieve this by april 29th", Some(d(2026, 4, 29))),
+            // Already past this year, so next January.
+            ("Jan 5", Some(d(2027, 1, 5))),
+            ("Sept 1st, 2028", Some(d(2028, 9, 1))),
+            ("Within 90 days: first clients", Some(d(2026, 4, 10))),
+            ("12–18 months", Some(d(2027, 7, 10))),
+            ("within 99999999 days", None),
+            ("in 9999999999999 years", None),
+            ("soon-ish", None),
+            ("", None),
+        ];
+        for (text, want) in cases {
+            assert_eq!(parse_time_bound(text, today), want, "{text}");
+        }
+    }
+
+    #[test]
+    fn measurable_needs_a_number_with_a_unit() {
+        assert!(is_quantified("10,000 steps per day"));
+        assert!(is_quantified("save $5,000"));
+        assert!(is_quantified("body fat under 15%"));
+        assert!(is_quantified("run 5km"));
+        assert!(!is_quantified("feel better"));
+        assert!(!is_quantified("score: 42"));
+    }
+
+    #[test]
+    fn goal_lint_flags_zero_targets() {
+        let smart = Smart {
+            specific: "Save".into(),
+            measurable: "feel richer".into(),
+            time_bound: "someday".into(),
+            ..Default::default()
+        };
+        let metric = Metric::Numeric {
+            unit: "usd".into(),
+            current: 0.0,
+            target: 0.0,
+            clamp_0_100: true,
+        };
+        let g = GoalNode::new("Fund", GoalCategory::Wealth, smart, metric);
+        assert_eq!(
+            g.lint(d(2026, 1, 1)),
+            [SmartIssue::Unmeasured, SmartIssue::NoDate, SmartIssue::ZeroTarget]
+        );
+    }
+}
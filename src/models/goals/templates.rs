@@ -0,0 +1,393 @@
+// src/models/goals/templates.rs
+//! Starter goal trees, a few per category.
+//!
+//! A template builds a whole subtree with SMART text, metrics, deadlines and
+//! habit cadences already filled in, dated from the day it's used. Numbers
+//! are sensible defaults meant to be edited afterwards.
+
+use time::{Date, Duration};
+
+use crate::models::finCalc::projection::add_months;
+use crate::models::schedule::events::{Freq, Recurrence};
+
+use super::goals::{GoalCategory, GoalNode, Metric, Smart};
+use super::timeblock::TimeBudget;
+
+pub struct GoalTemplate {
+    /// Stable id for pickers.
+    pub key: &'static str,
+    pub name: &'static str,
+    pub category: GoalCategory,
+    build: fn(Date) -> GoalNode,
+}
+
+impl GoalTemplate {
+    /// A fresh subtree (new ids) dated from `today`.
+    pub fn instantiate(&self, today: Date) -> GoalNode {
+        (self.build)(today)
+    }
+}
+
+pub const TEMPLATES: &[GoalTemplate] = &[
+    GoalTemplate {
+        key: "weight_target",
+        name: "Reach a target weight",
+        category: GoalCategory::Health,
+        build: weight_target,
+    },
+    GoalTemplate {
+        key: "strength_routine",
+        name: "Lift three times a week",
+        category: GoalCategory::Health,
+        build: strength_routine,
+    },
+    GoalTemplate {
+        key: "emergency_fund",
+        name: "Build an emergency fund",
+        category: GoalCategory::Wealth,
+        build: emergency_fund,
+    },
+    GoalTemplate {
+        key: "down_payment",
+        name: "Save for a down payment",
+        category: GoalCategory::Wealth,
+        build: down_payment,
+    },
+    GoalTemplate {
+        key: "paper_reading",
+        name: "Paper reading plan",
+        category: GoalCategory::Research,
+        build: paper_reading,
+    },
+    GoalTemplate {
+        key: "deep_work",
+        name: "Protect deep-work hours",
+        category: GoalCategory::Time,
+        build: deep_work,
+    },
+    GoalTemplate {
+        key: "reading_books",
+        name: "Read a book a month",
+        category: GoalCategory::Other,
+        build: reading_books,
+    },
+];
+
+pub fn for_category(category: GoalCategory) -> impl Iterator<Item = &'static GoalTemplate> {
+    TEMPLATES.iter().filter(move |t| t.category == category)
+}
+
+pub fn find(key: &str) -> Option<&'static GoalTemplate> {
+    TEMPLATES.iter().find(|t| t.key == key)
+}
+
+fn smart(s: &str, m: &str, a: &str, r: &str, t: &str) -> Smart {
+    Smart {
+        specific: s.into(),
+        measurable: m.into(),
+        achievable: a.into(),
+        relevant: r.into(),
+        time_bound: t.into(),
+    }
+}
+
+fn numeric(unit: &str, target: f64) -> Metric {
+    Metric::Numeric {
+        unit: unit.into(),
+        current: 0.0,
+        target,
+        clamp_0_100: true,
+    }
+}
+
+fn habit(freq: Freq, by_weekday: &[u8], today: Date) -> Metric {
+    Metric::Habit {
+        cadence: Recurrence {
+            freq,
+            interval: 1,
+            by_weekday: by_weekday.to_vec(),
+            count: None,
+            until: None,
+        },
+        start: today,
+        checkins: vec![],
+    }
+}
+
+fn checkbox() -> Metric {
+    Metric::Boolean { done: false }
+}
+
+fn weight_target(today: Date) -> GoalNode {
+    let mut root = GoalNode::new(
+        "Reach target weight",
+        GoalCategory::Health,
+        smart(
+            "Get down to a healthy target weight and keep it",
+            "Lose 20 lb, weighing in weekly",
+            "About 1.25 lb a week is a sustainable cut",
+            "More energy and lower health risks",
+            "16 weeks from today",
+        ),
+        // Counted as pounds lost: numeric goals are done once current
+        // reaches target, so a falling weight can't be the metric itself.
+        numeric("lb lost", 20.0),
+    );
+    root.deadline = Some(today + Duration::weeks(16));
+    root.add_child(GoalNode::new(
+        "Set a calorie target",
+        GoalCategory::Health,
+        smart(
+            "Work out maintenance calories and set a deficit",
+            "A 500 kcal daily deficit",
+            "One evening with a TDEE calculator",
+            "Everything else is planned around it",
+            "Within 7 days",
+        ),
+        checkbox(),
+    ));
+    root.add_child(GoalNode::new(
+        "Walk every day",
+        GoalCategory::Health,
+        smart(
+            "Walk daily to raise baseline burn",
+            "8,000 steps per day",
+            "Can be split into short walks",
+            "Supports the cut without extra fatigue",
+            "Every day for 16 weeks",
+        ),
+        habit(Freq::Daily, &[], today),
+    ));
+    root
+}
+
+fn strength_routine(today: Date) -> GoalNode {
+    let mut root = GoalNode::new(
+        "Lift three times a week",
+        GoalCategory::Health,
+        smart(
+            "Full-body strength sessions on Monday, Wednesday and Friday",
+            "3 sessions per week",
+            "Under an hour each, at the gym on the way home",
+            "Strength and muscle support every other goal",
+            "For the next 12 weeks",
+        ),
+        habit(Freq::Weekly, &[0, 2, 4], today),
+    );
+    root.deadline = Some(today + Duration::weeks(12));
+    root
+}
+
+fn emergency_fund(today: Date) -> GoalNode {
+    let mut root = GoalNode::new(
+        "Emergency fund",
+        GoalCategory::Wealth,
+        smart(
+            "Keep 6 months of essential expenses in cash",
+            "$10,000 in a separate savings account",
+            "Automatic transfers every payday",
+            "A job loss or repair never becomes debt",
+            "Within 12 months",
+        ),
+        numeric("usd", 10_000.0),
+    );
+    root.deadline = Some(add_months(today, 12));
+    root.add_child(GoalNode::new(
+        "Open a high-yield savings account",
+        GoalCategory::Wealth,
+        smart(
+            "Separate account so the fund isn't spent by accident",
+            "1 account opened and linked",
+            "Online banks take minutes",
+            "Keeps the fund out of sight",
+            "Within 14 days",
+        ),
+        checkbox(),
+    ));
+    root.add_child(GoalNode::new(
+        "Transfer on payday",
+        GoalCategory::Wealth,
+        smart(
+            "Move a fixed amount to savings each payday",
+            "$400 every 2 weeks",
+            "Set up as an automatic transfer",
+            "Steady contributions reach the target on time",
+            "Every payday for 12 months",
+        ),
+        Metric::Habit {
+            cadence: Recurrence {
+                freq: Freq::Weekly,
+                interval: 2,
+                by_weekday: vec![4],
+                count: None,
+                until: None,
+            },
+            start: today,
+            checkins: vec![],
+        },
+    ));
+    root
+}
+
+fn down_payment(today: Date) -> GoalNode {
+    let mut root = GoalNode::new(
+        "House down payment",
+        GoalCategory::Wealth,
+        smart(
+            "Save a 20% down payment",
+            "$60,000 saved",
+            "Budget review and a fixed monthly contribution",
+            "Avoids mortgage insurance and a bigger loan",
+            "Within 36 months",
+        ),
+        numeric("usd", 60_000.0),
+    );
+    root.deadline = Some(add_months(today, 36));
+    root.add_child(GoalNode::new(
+        "Monthly budget review",
+        GoalCategory::Wealth,
+        smart(
+            "Review spending and adjust the contribution",
+            "1 review per month",
+            "30 minutes with FinCalc",
+            "Keeps the plan honest",
+            "Monthly for 36 months",
+        ),
+        habit(Freq::Monthly, &[], today),
+    ));
+    root
+}
+
+fn paper_reading(today: Date) -> GoalNode {
+    let mut root = GoalNode::new(
+        "Paper reading plan",
+        GoalCategory::Research,
+        smart(
+            "Read and summarize the key papers in my field",
+            "24 papers read with notes",
+            "One paper a week with a 3-hour block",
+            "Keeps research current and feeds new ideas",
+            "Within 6 months",
+        ),
+        numeric("papers", 24.0),
+    );
+    root.deadline = Some(add_months(today, 6));
+    root.time_budget = Some(TimeBudget {
+        hours_per_week: 3.0,
+        block_minutes: 90,
+    });
+    root.add_child(GoalNode::new(
+        "Build the reading list",
+        GoalCategory::Research,
+        smart(
+            "Collect the papers worth reading, in order",
+            "24 papers listed",
+            "Start from survey papers and their citations",
+            "No time lost deciding what's next",
+            "Within 7 days",
+        ),
+        checkbox(),
+    ));
+    root.add_child(GoalNode::new(
+        "Weekly paper",
+        GoalCategory::Research,
+        smart(
+            "Read one paper each Saturday and write a one-page summary",
+            "1 paper per week",
+            "Saturday mornings are free",
+            "The summaries are the actual output",
+            "Weekly for 6 months",
+        ),
+        habit(Freq::Weekly, &[5], today),
+    ));
+    root
+}
+
+fn deep_work(today: Date) -> GoalNode {
+    let mut root = GoalNode::new(
+        "Deep-work hours",
+        GoalCategory::Time,
+        smart(
+            "Protect focused blocks for the most important project",
+            "100 hours logged from calendar blocks",
+            "10 hours a week in 2-hour blocks",
+            "Hard problems need uninterrupted time",
+            "Within 12 weeks",
+        ),
+        numeric("hours", 100.0),
+    );
+    root.deadline = Some(today + Duration::weeks(12));
+    root.time_budget = Some(TimeBudget {
+        hours_per_week: 10.0,
+        block_minutes: 120,
+    });
+    root.add_child(GoalNode::new(
+        "Plan tomorrow",
+        GoalCategory::Time,
+        smart(
+            "Pick tomorrow's focus task before finishing work",
+            "1 task written down each day",
+            "Takes 5 minutes",
+            "Blocks start without warm-up",
+            "Every day for 12 weeks",
+        ),
+        habit(Freq::Daily, &[], today),
+    ));
+    root
+}
+
+fn reading_books(today: Date) -> GoalNode {
+    let mut root = GoalNode::new(
+        "Read a book a month",
+        GoalCategory::Other,
+        smart(
+            "Read 12 books this year, fiction and non-fiction",
+            "12 books finished",
+            "30 minutes a day is about a book a month",
+            "Reading beats the phone before bed",
+            "Within 12 months",
+        ),
+        numeric("books", 12.0),
+    );
+    root.deadline = Some(add_months(today, 12));
+    root.add_child(GoalNode::new(
+        "Read 30 minutes",
+        GoalCategory::Other,
+        smart(
+            "Read before bed",
+            "30 minutes per day",
+            "Replaces phone time",
+            "Builds the habit that finishes the books",
+            "Every day for 12 months",
+        ),
+        habit(Freq::Daily, &[], today),
+    ));
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Month;
+
+    #[test]
+    fn every_template_builds_a_clean_dated_tree() {
+        let today = Date::from_calendar_date(2026, Month::March, 2).unwrap();
+        for t in TEMPLATES {
+            let a = t.instantiate(today);
+            let b = t.instantiate(today);
+            assert_ne!(a.id, b.id, "{}", t.key);
+            assert_eq!(a.category, t.category, "{}", t.key);
+            assert!(a.deadline.is_some_and(|d| d > today), "{}", t.key);
+
+            fn check(n: &GoalNode, today: Date, key: &str) {
+                assert!(n.lint(today).is_empty(), "{key}/{}: {:?}", n.title, n.lint(today));
+                assert!(!n.is_done(), "{key}/{} starts out done", n.title);
+                n.children.iter().for_each(|c| check(c, today, key));
+            }
+            check(&a, today, t.key);
+        }
+        assert!(find("paper_reading").is_some());
+        assert_eq!(for_category(GoalCategory::Wealth).count(), 2);
+    }
+}
@@ -0,0 +1,359 @@
+// src/models/goals/timeblock.rs
+//! Time-blocking: a weekly hours budget per goal, planned into free calendar
+//! time.
+//!
+//! The planner works on one Monday-based week. Timed occurrences from every
+//! source are busy; all-day markers aren't. Free time is the
+//! `PLAN_START_HOUR`..`PLAN_END_HOUR` window minus busy blocks, and blocks are
+//! handed out one day at a time, round-robin, so the budget spreads over the
+//! week instead of piling onto Monday. Blocks already linked to the goal count
+//! toward the budget, so re-planning only tops up.
+//!
+//! Time spent goes into `time_log`, either from finished blocks or by hand.
+//! When the goal's metric is numeric in hours, each entry adds to `current`.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+use crate::models::finCalc::projection::from_naive;
+use crate::models::schedule::events::{
+    Event, EventSource, Occurrence, When, PLAN_END_HOUR, PLAN_START_HOUR,
+};
+
+use super::goals::{GoalNode, Metric};
+use super::habit::{goal_link, parse_goal_link};
+
+/// Planned blocks start on these boundaries, matching the planner grid.
+pub const SLOT_MINUTES: i64 = 30;
+
+fn default_block_minutes() -> u32 {
+    60
+}
+
+/// How much calendar time a goal should get.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TimeBudget {
+    pub hours_per_week: f64,
+    /// Length of each planned block; the last one of a week may be shorter.
+    #[serde(default = "default_block_minutes")]
+    pub block_minutes: u32,
+}
+
+/// Time spent on a goal.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TimeEntry {
+    pub date: Date,
+    pub hours: f64,
+    /// The calendar block this came from, so it's only logged once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<Uuid>,
+}
+
+/// Monday of the week holding `d`.
+pub fn week_of(d: NaiveDate) -> NaiveDate {
+    d - Duration::days(d.weekday().num_days_from_monday() as i64)
+}
+
+/// `t` rounded up to the next slot boundary.
+fn ceil_to_slot(t: NaiveDateTime) -> NaiveDateTime {
+    let mins = (t.hour() * 60 + t.minute()) as i64;
+    let on_boundary = mins % SLOT_MINUTES == 0 && t.second() == 0 && t.nanosecond() == 0;
+    let rounded = if on_boundary {
+        mins
+    } else {
+        (mins / SLOT_MINUTES + 1) * SLOT_MINUTES
+    };
+    t.date().and_time(NaiveTime::MIN) + Duration::minutes(rounded)
+}
+
+/// Free `[start, end)` ranges on `day` inside the planning window, starting
+/// no earlier than `not_before`. `busy` may hold ranges from any day.
+pub fn free_ranges(
+    day: NaiveDate,
+    busy: &[(NaiveDateTime, NaiveDateTime)],
+    not_before: NaiveDateTime,
+) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let open = day.and_time(NaiveTime::MIN) + Duration::hours(PLAN_START_HOUR as i64);
+    let close = day.and_time(NaiveTime::MIN) + Duration::hours(PLAN_END_HOUR as i64);
+    let mut taken: Vec<(NaiveDateTime, NaiveDateTime)> = busy
+        .iter()
+        .filter(|(s, e)| *s < close && *e > open)
+        .copied()
+        .collect();
+    taken.sort();
+
+    let mut out = vec![];
+    let mut cursor = ceil_to_slot(open.max(not_before));
+    for (s, e) in taken {
+        if s > cursor {
+            out.push((cursor, s.min(close)));
+        }
+        cursor = cursor.max(ceil_to_slot(e));
+        if cursor >= close {
+            break;
+        }
+    }
+    if cursor < close {
+        out.push((cursor, close));
+    }
+    out.retain(|(s, e)| e > s);
+    out
+}
+
+/// `[start, end)` of every timed occurrence.
+fn busy_ranges(occurrences: &[Occurrence]) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    occurrences
+        .iter()
+        .filter(|o| !o.all_day)
+        .map(|o| (o.start, o.end))
+        .collect()
+}
+
+fn is_block_for(o: &Occurrence, id: Uuid) -> bool {
+    !o.all_day && o.link.as_deref().and_then(parse_goal_link) == Some(id)
+}
+
+fn hours_between(start: NaiveDateTime, end: NaiveDateTime) -> f64 {
+    (end - start).num_minutes() as f64 / 60.0
+}
+
+impl GoalNode {
+    /// Hours of blocks linked to this goal among `occurrences`.
+    pub fn scheduled_hours(&self, occurrences: &[Occurrence]) -> f64 {
+        occurrences
+            .iter()
+            .filter(|o| is_block_for(o, self.id))
+            .map(|o| hours_between(o.start, o.end))
+            .sum()
+    }
+
+    /// New blocks that fill what's left of the budget in the week starting
+    /// `week_start`. `occurrences` must cover that week; nothing is placed
+    /// before `now`. Ids are nil, for `TimeStore::add_event` to assign.
+    pub fn plan_blocks(
+        &self,
+        occurrences: &[Occurrence],
+        week_start: NaiveDate,
+        now: NaiveDateTime,
+    ) -> Vec<Event> {
+        let Some(budget) = self.time_budget else {
+            return vec![];
+        };
+        if self.archived || self.is_done() {
+            return vec![];
+        }
+        let week_end = week_start + Duration::days(6);
+        let in_week: Vec<Occurrence> = occurrences
+            .iter()
+            .filter(|o| o.date() >= week_start && o.date() <= week_end)
+            .cloned()
+            .collect();
+        let budget_min = (budget.hours_per_week * 60.0).round() as i64;
+        let mut remaining = budget_min - (self.scheduled_hours(&in_week) * 60.0).round() as i64;
+        let block = (budget.block_minutes as i64).max(SLOT_MINUTES);
+
+        let mut busy = busy_ranges(&in_week);
+        let mut out = vec![];
+        loop {
+            let mut placed = false;
+            for day in (0..7).map(|i| week_start + Duration::days(i)) {
+                if remaining <= 0 {
+                    break;
+                }
+                // Whole slots only, so short remainders still land on the grid.
+                let len = block.min(remaining);
+                let len = (len + SLOT_MINUTES - 1) / SLOT_MINUTES * SLOT_MINUTES;
+                let fits = free_ranges(day, &busy, now)
+                    .into_iter()
+                    .find(|(s, e)| (*e - *s).num_minutes() >= len);
+                let Some((start, _)) = fits else {
+                    continue;
+                };
+                let end = start + Duration::minutes(len);
+                busy.push((start, end));
+                remaining -= len;
+                placed = true;
+                out.push(Event {
+                    id: Uuid::nil(),
+                    title: format!("Goal: {}", self.title),
+                    notes: String::new(),
+                    when: When::Timed { start, end },
+                    source: EventSource::Goals,
+                    recurrence: None,
+                    link: Some(goal_link(self.id)),
+                });
+            }
+            if remaining <= 0 || !placed {
+                break;
+            }
+        }
+        out
+    }
+
+    /// Whether logged time feeds the metric: a numeric goal counted in hours.
+    pub fn tracks_hours(&self) -> bool {
+        match &self.metric {
+            Metric::Numeric { unit, .. } => {
+                matches!(unit.trim().to_lowercase().as_str(), "h" | "hr" | "hrs" | "hour" | "hours")
+            }
+            _ => false,
+        }
+    }
+
+    pub fn hours_logged(&self) -> f64 {
+        self.time_log.iter().map(|t| t.hours).sum()
+    }
+
+    /// Record time spent. Returns false (and changes nothing) if `event` was
+    /// already logged. Hour-tracking goals add it to `current`.
+    pub fn log_time(&mut self, date: Date, hours: f64, event: Option<Uuid>) -> bool {
+        if event.is_some() && self.time_log.iter().any(|t| t.event == event) {
+            return false;
+        }
+        let i = self.time_log.partition_point(|t| t.date <= date);
+        self.time_log.insert(i, TimeEntry { date, hours, event });
+        if self.tracks_hours() {
+            if let Some(current) = self.metric.current_mut() {
+                *current += hours;
+                let value = *current;
+                self.record_progress(date, value);
+            }
+            self.sync_completed_from_metric();
+        } else {
+            self.touch();
+        }
+        true
+    }
+
+    /// Log every block of this goal that ended by `now` and isn't logged yet.
+    /// Returns how many were logged.
+    pub fn log_finished_blocks(&mut self, occurrences: &[Occurrence], now: NaiveDateTime) -> usize {
+        let done: Vec<(Uuid, NaiveDate, f64)> = occurrences
+            .iter()
+            .filter(|o| is_block_for(o, self.id) && o.end <= now)
+            .map(|o| (o.event_id, o.date(), hours_between(o.start, o.end)))
+            .collect();
+        let mut n = 0;
+        for (event, day, hours) in done {
+            let Some(date) = from_naive(day) else {
+                continue;
+            };
+            if self.log_time(date, hours, Some(event)) {
+                n += 1;
+            }
+        }
+        n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::goals::goals::{GoalCategory, Smart};
+
+    fn at(d: u32, h: u32, m: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    }
+
+    fn occ(start: NaiveDateTime, end: NaiveDateTime, link: Option<String>) -> Occurrence {
+        Occurrence {
+            event_id: Uuid::new_v4(),
+            title: String::new(),
+            source: EventSource::Manual,
+            start,
+            end,
+            all_day: false,
+            link,
+        }
+    }
+
+    fn study(hours_per_week: f64) -> GoalNode {
+        let smart = Smart::default();
+        let metric = Metric::Numeric {
+            unit: "hours".into(),
+            current: 0.0,
+            target: 20.0,
+            clamp_0_100: true,
+        };
+        let mut g = GoalNode::new("Study", GoalCategory::Research, smart, metric);
+        g.time_budget = Some(TimeBudget {
+            hours_per_week,
+            block_minutes: 120,
+        });
+        g
+    }
+
+    #[test]
+    fn free_ranges_skip_busy_time_and_the_past() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let busy = [(at(2, 9, 0), at(2, 17, 10)), (at(2, 5, 0), at(2, 7, 0))];
+        let free = free_ranges(day, &busy, at(2, 6, 40));
+        // Starts on the next slot after both the early block and `now`, and
+        // resumes on the slot after the day job.
+        assert_eq!(
+            free,
+            [(at(2, 7, 0), at(2, 9, 0)), (at(2, 17, 30), at(2, 23, 0))]
+        );
+    }
+
+    #[test]
+    fn plan_spreads_blocks_and_tops_up_existing_ones() {
+        // Mon 2026-03-02; busy 06:00–21:00 every day but Wednesday.
+        let monday = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let mut occs: Vec<Occurrence> = (0..7)
+            .filter(|&i| i != 2)
+            .map(|i| occ(at(2 + i, 6, 0), at(2 + i, 21, 0), None))
+            .collect();
+        let mut g = study(5.0);
+
+        let events = g.plan_blocks(&occs, monday, at(1, 12, 0));
+        let slots: Vec<(NaiveDateTime, NaiveDateTime)> = events
+            .iter()
+            .map(|e| match e.when {
+                When::Timed { start, end } => (start, end),
+                _ => unreachable!(),
+            })
+            .collect();
+        // Round-robin: Mon 21–23, Tue 21–23, Wed 06–07 (the last hour).
+        assert_eq!(slots[0], (at(2, 21, 0), at(2, 23, 0)));
+        assert_eq!(slots[1], (at(3, 21, 0), at(3, 23, 0)));
+        assert_eq!(slots[2], (at(4, 6, 0), at(4, 7, 0)));
+        assert_eq!(slots.len(), 3);
+        assert!(events
+            .iter()
+            .all(|e| e.link.as_deref().and_then(parse_goal_link) == Some(g.id)));
+
+        // With Monday's block already on the calendar, only 3h remain.
+        occs.push(occ(at(2, 21, 0), at(2, 23, 0), Some(goal_link(g.id))));
+        let events = g.plan_blocks(&occs, monday, at(1, 12, 0));
+        assert_eq!(events.len(), 2);
+
+        // Nothing placed in the past or when the goal has no budget.
+        assert!(g.plan_blocks(&occs, monday, at(9, 0, 0)).is_empty());
+        g.time_budget = None;
+        assert!(g.plan_blocks(&occs, monday, at(1, 12, 0)).is_empty());
+    }
+
+    #[test]
+    fn finished_blocks_log_once_into_an_hours_metric() {
+        let mut g = study(5.0);
+        let block = occ(at(2, 21, 0), at(2, 22, 30), Some(goal_link(g.id)));
+        let later = occ(at(3, 21, 0), at(3, 23, 0), Some(goal_link(g.id)));
+        let other = occ(at(2, 9, 0), at(2, 10, 0), None);
+        let occs = [block, later, other];
+
+        assert_eq!(g.log_finished_blocks(&occs, at(3, 8, 0)), 1);
+        assert_eq!(g.log_finished_blocks(&occs, at(3, 8, 0)), 0);
+        assert_eq!(g.metric.values(), Some((1.5, 20.0)));
+
+        assert!(g.log_time(from_naive(at(4, 0, 0).date()).unwrap(), 0.5, None));
+        assert_eq!(g.hours_logged(), 2.0);
+        assert_eq!(g.metric.values(), Some((2.0, 20.0)));
+        assert_eq!(g.progress.len(), 2);
+    }
+}
@@ -0,0 +1,282 @@
+// src/models/goals/tree.rs
+//! Structural edits on the goal tree: delete, re-parent, reorder, duplicate
+//! and archive.
+//!
+//! Everything is addressed by id. Moves refuse to put a node under itself or
+//! one of its own descendants, which would detach that subtree from the file.
+
+use std::fmt;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::goals::{GoalNode, GoalsFile};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeError {
+    NotFound(Uuid),
+    /// The destination is the node itself or one of its descendants.
+    IntoOwnSubtree,
+}
+
+impl fmt::Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeError::NotFound(id) => write!(f, "No goal with id {id}"),
+            TreeError::IntoOwnSubtree => {
+                write!(f, "A goal can't be moved under itself or its own subgoals")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TreeError {}
+
+/// The sibling list holding `id`, and its index there.
+fn siblings_of(nodes: &mut Vec<GoalNode>, id: Uuid) -> Option<(&mut Vec<GoalNode>, usize)> {
+    if let Some(i) = nodes.iter().position(|n| n.id == id) {
+        return Some((nodes, i));
+    }
+    for n in nodes.iter_mut() {
+        if let Some(found) = siblings_of(&mut n.children, id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+impl GoalNode {
+    pub fn find(&self, id: Uuid) -> Option<&GoalNode> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|c| c.find(id))
+    }
+
+    /// True if `id` is this node or anywhere below it.
+    pub fn contains(&self, id: Uuid) -> bool {
+        self.find(id).is_some()
+    }
+
+    /// Deep copy with fresh ids and timestamps. Progress logs come along so
+    /// forecasts keep working on the copy.
+    pub fn duplicate(&self) -> GoalNode {
+        let now = OffsetDateTime::now_utc();
+        GoalNode {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            children: self.children.iter().map(GoalNode::duplicate).collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl GoalsFile {
+    pub fn find(&self, id: Uuid) -> Option<&GoalNode> {
+        self.roots.iter().find_map(|r| r.find(id))
+    }
+
+    /// Parent of `id`; `None` for roots and unknown ids.
+    pub fn parent_of(&self, id: Uuid) -> Option<Uuid> {
+        fn walk(nodes: &[GoalNode], id: Uuid) -> Option<Uuid> {
+            for n in nodes {
+                if n.children.iter().any(|c| c.id == id) {
+                    return Some(n.id);
+                }
+                if let Some(p) = walk(&n.children, id) {
+                    return Some(p);
+                }
+            }
+            None
+        }
+        walk(&self.roots, id)
+    }
+
+    /// Detach `id` (with its subtree) and hand it back.
+    pub fn remove(&mut self, id: Uuid) -> Option<GoalNode> {
+        let (siblings, i) = siblings_of(&mut self.roots, id)?;
+        Some(siblings.remove(i))
+    }
+
+    /// Re-parent `id` under `parent` (`None` = top level) at `index` among
+    /// its new siblings, clamped to the end. Within the same parent, `index`
+    /// counts the siblings without the moved node.
+    pub fn move_node(
+        &mut self,
+        id: Uuid,
+        parent: Option<Uuid>,
+        index: usize,
+    ) -> Result<(), TreeError> {
+        let node = self.find(id).ok_or(TreeError::NotFound(id))?;
+        if let Some(p) = parent {
+            if node.contains(p) {
+                return Err(TreeError::IntoOwnSubtree);
+            }
+            if self.find(p).is_none() {
+                return Err(TreeError::NotFound(p));
+            }
+        }
+
+        let mut node = self.remove(id).ok_or(TreeError::NotFound(id))?;
+        node.touch();
+        let siblings = match parent {
+            None => &mut self.roots,
+            Some(p) => {
+                let parent = self.find_mut(p).ok_or(TreeError::NotFound(p))?;
+                parent.touch();
+                &mut parent.children
+            }
+        };
+        siblings.insert(index.min(siblings.len()), node);
+        Ok(())
+    }
+
+    /// Move `id` up (`delta < 0`) or down among its siblings. Returns false at
+    /// either end or for unknown ids.
+    pub fn shift(&mut self, id: Uuid, delta: isize) -> bool {
+        let Some((siblings, i)) = siblings_of(&mut self.roots, id) else {
+            return false;
+        };
+        let Some(j) = i.checked_add_signed(delta).filter(|j| *j < siblings.len()) else {
+            return false;
+        };
+        let node = siblings.remove(i);
+        siblings.insert(j, node);
+        true
+    }
+
+    /// Copy the subtree at `id` and place it right after the original.
+    /// Returns the copy's id.
+    pub fn duplicate(&mut self, id: Uuid) -> Result<Uuid, TreeError> {
+        let (siblings, i) = siblings_of(&mut self.roots, id).ok_or(TreeError::NotFound(id))?;
+        let mut copy = siblings[i].duplicate();
+        copy.title = format!("{} (copy)", copy.title);
+        let copy_id = copy.id;
+        siblings.insert(i + 1, copy);
+        Ok(copy_id)
+    }
+
+    pub fn set_archived(&mut self, id: Uuid, archived: bool) -> Result<(), TreeError> {
+        let node = self.find_mut(id).ok_or(TreeError::NotFound(id))?;
+        node.archived = archived;
+        node.touch();
+        Ok(())
+    }
+
+    /// Topmost archived nodes: archived goals whose ancestors aren't, so a
+    /// shelved subtree shows up once.
+    pub fn archived(&self) -> Vec<&GoalNode> {
+        fn walk<'a>(nodes: &'a [GoalNode], out: &mut Vec<&'a GoalNode>) {
+            for n in nodes {
+                if n.archived {
+                    out.push(n);
+                } else {
+                    walk(&n.children, out);
+                }
+            }
+        }
+        let mut out = vec![];
+        walk(&self.roots, &mut out);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::goals::goals::{GoalCategory, Metric, Smart};
+
+    fn goal(title: &str) -> GoalNode {
+        let smart = Smart::default();
+        GoalNode::new(
+            title,
+            GoalCategory::Other,
+            smart,
+            Metric::Boolean { done: false },
+        )
+    }
+
+    fn titles(nodes: &[GoalNode]) -> Vec<&str> {
+        nodes.iter().map(|n| n.title.as_str()).collect()
+    }
+
+    /// a > (a1 > a1x), a2 ; b
+    fn sample() -> (GoalsFile, [Uuid; 5]) {
+        let mut a = goal("a");
+        let mut a1 = goal("a1");
+        let a1x = goal("a1x");
+        let a2 = goal("a2");
+        let b = goal("b");
+        let ids = [a.id, a1.id, a1x.id, a2.id, b.id];
+        a1.children.push(a1x);
+        a.children.push(a1);
+        a.children.push(a2);
+        let mut file = GoalsFile::default();
+        file.roots.push(a);
+        file.roots.push(b);
+        (file, ids)
+    }
+
+    #[test]
+    fn move_reparents_and_refuses_own_subtree() {
+        let (mut f, [a, a1, a1x, _, b]) = sample();
+
+        assert_eq!(f.move_node(a, Some(a1x), 0), Err(TreeError::IntoOwnSubtree));
+        assert_eq!(f.move_node(a1, Some(a1), 0), Err(TreeError::IntoOwnSubtree));
+        let stranger = Uuid::new_v4();
+        assert_eq!(
+            f.move_node(a1, Some(stranger), 0),
+            Err(TreeError::NotFound(stranger))
+        );
+        // Refused moves leave the tree alone.
+        assert_eq!(f.parent_of(a1x), Some(a1));
+
+        f.move_node(a1, Some(b), 5).unwrap();
+        assert_eq!(f.parent_of(a1), Some(b));
+        assert_eq!(f.parent_of(a1x), Some(a1));
+        assert_eq!(titles(&f.find(a).unwrap().children), ["a2"]);
+
+        f.move_node(a1x, None, 0).unwrap();
+        assert_eq!(titles(&f.roots), ["a1x", "a", "b"]);
+        assert_eq!(f.parent_of(a1x), None);
+    }
+
+    #[test]
+    fn shift_reorders_siblings_within_bounds() {
+        let (mut f, [a, a1, _, a2, b]) = sample();
+        assert!(f.shift(a2, -1));
+        assert_eq!(titles(&f.find(a).unwrap().children), ["a2", "a1"]);
+        assert!(!f.shift(a2, -1));
+        assert!(!f.shift(a1, 1));
+        assert!(f.shift(b, -1));
+        assert_eq!(titles(&f.roots), ["b", "a"]);
+    }
+
+    #[test]
+    fn duplicate_and_remove_subtrees() {
+        let (mut f, [a, a1, a1x, _, _]) = sample();
+        let copy = f.duplicate(a1).unwrap();
+        let parent = f.find(a).unwrap();
+        assert_eq!(titles(&parent.children), ["a1", "a1 (copy)", "a2"]);
+        let copied = f.find(copy).unwrap();
+        assert_ne!(copied.children[0].id, a1x);
+        assert_eq!(copied.children[0].title, "a1x");
+
+        let removed = f.remove(a1).unwrap();
+        assert!(removed.contains(a1x));
+        assert!(f.find(a1x).is_none());
+        assert!(f.remove(a1).is_none());
+    }
+
+    #[test]
+    fn archived_lists_topmost_archived_nodes() {
+        let (mut f, [a, a1, a1x, _, b]) = sample();
+        f.set_archived(a1x, true).unwrap();
+        f.set_archived(a1, true).unwrap();
+        f.set_archived(b, true).unwrap();
+        let shelved: Vec<Uuid> = f.archived().iter().map(|n| n.id).collect();
+        assert_eq!(shelved, [a1, b]);
+        assert!(f.set_archived(Uuid::new_v4(), true).is_err());
+        assert!(!f.find(a).unwrap().archived);
+    }
+}
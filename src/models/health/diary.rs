@@ -0,0 +1,332 @@
+// src/models/health/diary.rs
+//! The food diary: what was eaten each day, by reference into the
+//! `NutritionConstants` food database.
+//!
+//! Lives in `diary.json` beside the open `health.json`. Quantities are in the
+//! food group's `unit` and nutrients are per unit, so totals are a multiply
+//! and sum. Entries whose food has since been removed from the database are
+//! kept and counted as unknown rather than dropped.
+
+use std::collections::BTreeMap;
+use std::ops::AddAssign;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::health::{FoodVariant, HealthFile, NutritionalInfo};
+use crate::utils::json_store::{load_json, save_json, StoreResult};
+
+pub const DIARY_FILE_NAME: &str = "diary.json";
+
+/// `diary.json` in the same folder as `health_path`.
+pub fn diary_path_for(health_path: impl AsRef<Path>) -> PathBuf {
+    let dir = health_path.as_ref().parent().unwrap_or(Path::new(""));
+    dir.join(DIARY_FILE_NAME)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiaryFile {
+    pub version: u32,
+    #[serde(default)]
+    pub days: BTreeMap<NaiveDate, Vec<DiaryEntry>>,
+}
+
+impl Default for DiaryFile {
+    fn default() -> Self {
+        Self { version: 1, days: BTreeMap::new() }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Meal {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+impl Meal {
+    pub const ALL: [Meal; 4] = [Meal::Breakfast, Meal::Lunch, Meal::Dinner, Meal::Snack];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Meal::Breakfast => "Breakfast",
+            Meal::Lunch => "Lunch",
+            Meal::Dinner => "Dinner",
+            Meal::Snack => "Snack",
+        }
+    }
+}
+
+/// A food in the database: group, variant and, for nested variants, the
+/// sub-variant ("grass fed beef" / "Ground" / "90/10").
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FoodRef {
+    pub group: String,
+    pub variant: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+}
+
+impl FoodRef {
+    pub fn label(&self) -> String {
+        match &self.sub {
+            Some(sub) => format!("{} · {} · {}", self.group, self.variant, sub),
+            None => format!("{} · {}", self.group, self.variant),
+        }
+    }
+}
+
+/// A typed quantity: positive and finite, since NaN and infinity can't be
+/// saved to `diary.json`.
+pub fn parse_quantity(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter(|q| q.is_finite() && *q > 0.0)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiaryEntry {
+    pub id: Uuid,
+    pub meal: Meal,
+    pub food: FoodRef,
+    /// In the food group's unit.
+    pub quantity: f64,
+}
+
+/// Nutrient totals. Grams except calories (kcal) and cholesterol and
+/// sodium, which are in whatever unit the database uses for them (mg).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Nutrients {
+    pub calories: f64,
+    pub protein: f64,
+    pub carbs: f64,
+    pub fat: f64,
+    pub saturated_fat: f64,
+    pub monounsaturated_fat: f64,
+    pub polyunsaturated_fat: f64,
+    pub cholesterol: f64,
+    pub sodium: f64,
+}
+
+impl Nutrients {
+    pub fn of(info: &NutritionalInfo, quantity: f64) -> Self {
+        Self {
+            calories: info.calories * quantity,
+            protein: info.protein * quantity,
+            carbs: info.carbohydrates * quantity,
+            fat: info.total_fat * quantity,
+            saturated_fat: info.saturated_fat * quantity,
+            monounsaturated_fat: info.monounsaturated_fat * quantity,
+            polyunsaturated_fat: info.polyunsaturated_fat * quantity,
+            cholesterol: info.cholesterol * quantity,
+            sodium: info.sodium * quantity,
+        }
+    }
+}
+
+impl AddAssign for Nutrients {
+    fn add_assign(&mut self, o: Nutrients) {
+        self.calories += o.calories;
+        self.protein += o.protein;
+        self.carbs += o.carbs;
+        self.fat += o.fat;
+        self.saturated_fat += o.saturated_fat;
+        self.monounsaturated_fat += o.monounsaturated_fat;
+        self.polyunsaturated_fat += o.polyunsaturated_fat;
+        self.cholesterol += o.cholesterol;
+        self.sodium += o.sodium;
+    }
+}
+
+/// What a day should add up to: the simulation row's calories and macros.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayTarget {
+    pub calories: f64,
+    pub protein: f64,
+    pub carbs: f64,
+    pub fat: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DayTotals {
+    pub total: Nutrients,
+    pub by_meal: BTreeMap<Meal, Nutrients>,
+    /// Entries whose food is no longer in the database.
+    pub unknown: usize,
+}
+
+impl HealthFile {
+    /// Per-unit nutrition and the unit for `food`.
+    pub fn food_info(&self, food: &FoodRef) -> Option<(&str, &NutritionalInfo)> {
+        let group = self.nutrition_constants.get(&food.group)?;
+        let info = match (group.variants.get(&food.variant)?, &food.sub) {
+            (FoodVariant::Direct(info), None) => info,
+            (FoodVariant::Nested(map), Some(sub)) => map.get(sub)?,
+            _ => return None,
+        };
+        Some((group.unit.as_str(), info))
+    }
+
+    /// Every loggable food, sorted.
+    pub fn foods(&self) -> Vec<FoodRef> {
+        let mut out = vec![];
+        for (group, g) in &self.nutrition_constants {
+            for (variant, v) in &g.variants {
+                let food = |sub: Option<&String>| FoodRef {
+                    group: group.clone(),
+                    variant: variant.clone(),
+                    sub: sub.cloned(),
+                };
+                match v {
+                    FoodVariant::Direct(_) => out.push(food(None)),
+                    FoodVariant::Nested(map) => out.extend(map.keys().map(|s| food(Some(s)))),
+                    FoodVariant::Metadata(_) => {}
+                }
+            }
+        }
+        out.sort();
+        out
+    }
+}
+
+impl DiaryFile {
+    /// A missing file is an empty diary.
+    pub fn load(path: impl AsRef<Path>) -> StoreResult<DiaryFile> {
+        if !path.as_ref().exists() {
+            return Ok(DiaryFile::default());
+        }
+        load_json(path)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> StoreResult<()> {
+        save_json(path, self)
+    }
+
+    pub fn entries(&self, date: NaiveDate) -> &[DiaryEntry] {
+        self.days.get(&date).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn add(&mut self, date: NaiveDate, entry: DiaryEntry) {
+        self.days.entry(date).or_default().push(entry);
+    }
+
+    /// Empty days are dropped so they don't read as "ate nothing".
+    pub fn remove(&mut self, date: NaiveDate, id: Uuid) -> bool {
+        let Some(entries) = self.days.get_mut(&date) else {
+            return false;
+        };
+        let before = entries.len();
+        entries.retain(|e| e.id != id);
+        let removed = entries.len() != before;
+        if entries.is_empty() {
+            self.days.remove(&date);
+        }
+        removed
+    }
+
+    /// Calories per logged day, for the maintenance estimate. Days with an
+    /// unknown food are left out rather than undercounted.
+    pub fn daily_calories(&self, health: &HealthFile) -> BTreeMap<NaiveDate, f64> {
+        self.days
+            .keys()
+            .map(|d| (*d, self.day_totals(*d, health)))
+            .filter(|(_, t)| t.unknown == 0)
+            .map(|(d, t)| (d, t.total.calories))
+            .collect()
+    }
+
+    pub fn day_totals(&self, date: NaiveDate, health: &HealthFile) -> DayTotals {
+        let mut out = DayTotals::default();
+        for e in self.entries(date) {
+            let Some((_, info)) = health.food_info(&e.food) else {
+                out.unknown += 1;
+                continue;
+            };
+            let n = Nutrients::of(info, e.quantity);
+            out.total += n;
+            *out.by_meal.entry(e.meal).or_default() += n;
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn health() -> HealthFile {
+        let json = r#"{
+            "NutritionConstants": {
+                "beef": { "type": "weight", "unit": "lb",
+                    "Ground": {
+                        "90/10": { "calories": 800, "protein": 91, "Total Fat": 45, "Sodium": 300 }
+                    }
+                },
+                "eggs": { "type": "quanitity", "unit": "egg",
+                    "whole": { "calories": 70, "protein": 6, "Total Fat": 5, "Carbohydrates": 0.5 }
+                }
+            }
+        }"#;
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn entry(meal: Meal, group: &str, variant: &str, sub: Option<&str>, quantity: f64) -> DiaryEntry {
+        DiaryEntry {
+            id: Uuid::new_v4(),
+            meal,
+            food: FoodRef { group: group.into(), variant: variant.into(), sub: sub.map(Into::into) },
+            quantity,
+        }
+    }
+
+    #[test]
+    fn quantities_must_be_finite_and_positive() {
+        assert_eq!(parse_quantity(" 1.5 "), Some(1.5));
+        for bad in ["0", "-1", "nan", "inf", "1e400", "", "two"] {
+            assert_eq!(parse_quantity(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn database_foods_are_listed_and_resolved() {
+        let h = health();
+        let labels: Vec<String> = h.foods().iter().map(FoodRef::label).collect();
+        assert_eq!(labels, ["beef · Ground · 90/10", "eggs · whole"]);
+        let beef = &h.foods()[0];
+        assert_eq!(h.food_info(beef).map(|(unit, i)| (unit, i.calories)), Some(("lb", 800.0)));
+        // A nested variant needs its sub-variant.
+        let bare = FoodRef { sub: None, ..beef.clone() };
+        assert!(h.food_info(&bare).is_none());
+    }
+
+    #[test]
+    fn day_totals_sum_by_meal_and_count_unknown_foods() {
+        let h = health();
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let mut diary = DiaryFile::default();
+        diary.add(day, entry(Meal::Breakfast, "eggs", "whole", None, 3.0));
+        diary.add(day, entry(Meal::Dinner, "beef", "Ground", Some("90/10"), 0.5));
+        diary.add(day, entry(Meal::Dinner, "tofu", "firm", None, 1.0));
+
+        let totals = diary.day_totals(day, &h);
+        assert_eq!(totals.total.calories, 610.0);
+        assert_eq!(totals.total.protein, 63.5);
+        assert_eq!(totals.total.sodium, 150.0);
+        assert_eq!(totals.by_meal[&Meal::Breakfast].carbs, 1.5);
+        assert_eq!(totals.unknown, 1);
+
+        // A day with an unknown food would be undercounted, so it's left out.
+        let next = day.succ_opt().unwrap();
+        diary.add(next, entry(Meal::Lunch, "eggs", "whole", None, 2.0));
+        assert_eq!(diary.daily_calories(&h).into_iter().collect::<Vec<_>>(), [(next, 140.0)]);
+        assert!(diary.remove(next, diary.entries(next)[0].id));
+
+        let ids: Vec<Uuid> = diary.entries(day).iter().map(|e| e.id).collect();
+        for id in ids {
+            assert!(diary.remove(day, id));
+        }
+        assert!(diary.days.is_empty());
+    }
+}
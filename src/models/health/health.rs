@@ -1,20 +1,37 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::workouts::{de_workouts, validate_workouts, Workout};
+use crate::utils::json_store::{load_json, StoreError, StoreResult};
+
 pub const DEFAULT_HEALTH_PATH: &str = "assets/data/health/health.json";
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct HealthFile {
     #[serde(default)]
     pub schedule: HashMap<String, DaySchedule>,
-    #[serde(default)]
-    pub workouts: HashMap<String, serde_json::Value>, 
+    #[serde(default, deserialize_with = "de_workouts")]
+    pub workouts: HashMap<String, Workout>,
     #[serde(rename = "NutritionConstants", default)]
     pub nutrition_constants: HashMap<String, FoodGroup>,
     #[serde(default)]
     pub diet: DietConfig,
 }
 
+impl HealthFile {
+    /// Load and check the workout library; any problem fails the load with
+    /// every issue listed.
+    pub fn load(path: impl AsRef<std::path::Path>) -> StoreResult<HealthFile> {
+        let file: HealthFile = load_json(path)?;
+        let issues = validate_workouts(&file.workouts);
+        if !issues.is_empty() {
+            let list: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+            return Err(StoreError::Invalid(list.join("; ")));
+        }
+        Ok(file)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DaySchedule {
     #[serde(default)]
@@ -0,0 +1,319 @@
+// src/models/health/history.rs
+//! Body measurements over time: weight, body fat and tape measures.
+//!
+//! Lives in `history.json` beside the open `health.json`. Older files store
+//! one map per measure keyed by `"M-D-YY_HHMM"` (`{"weight": {"12-25-25_1200":
+//! 220.2}}`); they are migrated on load and written back in the typed shape
+//! on the next save. Weights are in pounds, tape measures in inches.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::json_store::{load_json, save_json, StoreError, StoreResult};
+
+pub const HISTORY_FILE_NAME: &str = "history.json";
+
+/// Daily smoothing for the trend weight: a reading a day after the last one
+/// moves the trend a tenth of the way towards it, which irons out day-to-day
+/// water swings. Readings further apart move it further, as if the days in
+/// between had been weighed at the same value.
+pub const TREND_ALPHA: f64 = 0.1;
+
+/// `history.json` in the same folder as `health_path`.
+pub fn history_path_for(health_path: impl AsRef<Path>) -> PathBuf {
+    let dir = health_path.as_ref().parent().unwrap_or(Path::new(""));
+    dir.join(HISTORY_FILE_NAME)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HistoryFile {
+    pub version: u32,
+    /// Oldest first.
+    #[serde(default)]
+    pub measurements: Vec<Measurement>,
+    /// Calories eaten per day.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub intake: BTreeMap<NaiveDate, f64>,
+}
+
+impl Default for HistoryFile {
+    fn default() -> Self {
+        Self { version: 1, measurements: vec![], intake: BTreeMap::new() }
+    }
+}
+
+/// One sitting on the scale and/or with the tape. Every measure is optional.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Measurement {
+    pub id: Uuid,
+    pub at: NaiveDateTime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_fat_pct: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waist: Option<f64>,
+    /// Other tape measures ("hips", "chest", "neck").
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tape: BTreeMap<String, f64>,
+}
+
+/// A weight reading with the smoothed trend up to and including it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrendPoint {
+    pub at: NaiveDateTime,
+    pub weight: f64,
+    pub trend: f64,
+}
+
+/// `"12-25-25_1200"` → 2025-12-25 12:00. The time part is optional.
+pub fn parse_legacy_key(key: &str) -> Option<NaiveDateTime> {
+    let (date, time) = key.split_once('_').unwrap_or((key, ""));
+    let mut parts = date.split('-').map(|p| p.trim().parse::<u32>().ok());
+    let (m, d, y) = (parts.next()??, parts.next()??, parts.next()??);
+    if parts.next().is_some() {
+        return None;
+    }
+    let year = if y < 100 { 2000 + y as i32 } else { y as i32 };
+    let date = NaiveDate::from_ymd_opt(year, m, d)?;
+    let time = match time.trim() {
+        "" => NaiveTime::MIN,
+        t if t.len() == 4 => NaiveTime::from_hms_opt(t[..2].parse().ok()?, t[2..].parse().ok()?, 0)?,
+        _ => return None,
+    };
+    Some(date.and_time(time))
+}
+
+/// A weight, body-fat or waist field: blank is "not measured", anything else
+/// must be a positive number. NaN and infinity are refused since they can't
+/// be saved.
+pub fn parse_measure(label: &str, text: &str) -> Result<Option<f64>, String> {
+    match text.trim() {
+        "" => Ok(None),
+        t => match t.parse::<f64>() {
+            Ok(v) if v.is_finite() && v > 0.0 => Ok(Some(v)),
+            _ => Err(format!("{label} must be a positive number.")),
+        },
+    }
+}
+
+/// `"hips=38, neck 15.5"` → `{hips: 38, neck: 15.5}`.
+pub fn parse_tape(text: &str) -> Result<BTreeMap<String, f64>, String> {
+    let mut out = BTreeMap::new();
+    for part in text.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, value) = part
+            .rsplit_once(['=', ' '])
+            .ok_or_else(|| format!("\"{part}\" needs a name and a number"))?;
+        let name = name.trim().trim_end_matches('=').trim().to_lowercase();
+        let value: f64 = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("\"{part}\": {} isn't a number", value.trim()))?;
+        if name.is_empty() || !(value.is_finite() && value > 0.0) {
+            return Err(format!("\"{part}\" needs a name and a positive number"));
+        }
+        out.insert(name, value);
+    }
+    Ok(out)
+}
+
+impl HistoryFile {
+    /// A missing file is an empty log. The legacy keyed shape is migrated.
+    pub fn load(path: impl AsRef<Path>) -> StoreResult<HistoryFile> {
+        if !path.as_ref().exists() {
+            return Ok(HistoryFile::default());
+        }
+        let raw: serde_json::Value = load_json(path)?;
+        if raw.get("version").is_some() {
+            return serde_json::from_value(raw).map_err(StoreError::SerdeJson);
+        }
+        HistoryFile::from_legacy(&raw).map_err(StoreError::Invalid)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> StoreResult<()> {
+        save_json(path, self)
+    }
+
+    /// Build from `{measure: {"M-D-YY_HHMM": value}}`. Readings taken at the
+    /// same moment become one measurement. `weight`, `bodyFat` and `waist`
+    /// map to their fields, `intake` to that day's calories; any other
+    /// measure is a tape measure.
+    pub fn from_legacy(raw: &serde_json::Value) -> Result<HistoryFile, String> {
+        let measures = raw.as_object().ok_or("history.json isn't an object")?;
+        let mut by_time: BTreeMap<NaiveDateTime, Measurement> = BTreeMap::new();
+        let mut intake = BTreeMap::new();
+        for (measure, readings) in measures {
+            let readings = readings
+                .as_object()
+                .ok_or_else(|| format!("\"{measure}\" isn't a map of readings"))?;
+            for (key, value) in readings {
+                let at = parse_legacy_key(key)
+                    .ok_or_else(|| format!("{measure}: can't read \"{key}\" as M-D-YY_HHMM"))?;
+                let value = value
+                    .as_f64()
+                    .ok_or_else(|| format!("{measure} at {key}: {value} isn't a number"))?;
+                if measure == "intake" {
+                    intake.insert(at.date(), value);
+                    continue;
+                }
+                let m = by_time.entry(at).or_insert_with(|| Measurement {
+                    id: Uuid::new_v4(),
+                    at,
+                    ..Default::default()
+                });
+                match measure.as_str() {
+                    "weight" => m.weight = Some(value),
+                    "bodyFat" | "body_fat" | "body_fat_pct" => m.body_fat_pct = Some(value),
+                    "waist" => m.waist = Some(value),
+                    other => {
+                        m.tape.insert(other.to_string(), value);
+                    }
+                }
+            }
+        }
+        Ok(HistoryFile { version: 1, measurements: by_time.into_values().collect(), intake })
+    }
+
+    /// Insert keeping time order.
+    pub fn add(&mut self, m: Measurement) {
+        let at = self.measurements.partition_point(|x| x.at <= m.at);
+        self.measurements.insert(at, m);
+    }
+
+    pub fn remove(&mut self, id: Uuid) -> bool {
+        let before = self.measurements.len();
+        self.measurements.retain(|m| m.id != id);
+        self.measurements.len() != before
+    }
+
+    /// Record a day's calories; zero or less clears the day.
+    pub fn set_intake(&mut self, date: NaiveDate, kcal: f64) {
+        if kcal > 0.0 {
+            self.intake.insert(date, kcal);
+        } else {
+            self.intake.remove(&date);
+        }
+    }
+
+    /// Weight readings with a time-aware exponential moving average, seeded
+    /// with the first reading: `n` days after the last reading the step is
+    /// `1 - (1 - α)^n`, so sparse weigh-ins still track the scale.
+    pub fn weight_trend(&self) -> Vec<TrendPoint> {
+        let mut out: Vec<TrendPoint> = vec![];
+        for m in &self.measurements {
+            let Some(weight) = m.weight.filter(|w| *w > 0.0) else {
+                continue;
+            };
+            let trend = match out.last() {
+                Some(prev) => {
+                    let days = (m.at - prev.at).num_minutes().max(0) as f64 / (24.0 * 60.0);
+                    let alpha = 1.0 - (1.0 - TREND_ALPHA).powf(days);
+                    prev.trend + alpha * (weight - prev.trend)
+                }
+                None => weight,
+            };
+            out.push(TrendPoint { at: m.at, weight, trend });
+        }
+        out
+    }
+
+    /// Latest trend weight; the simulation's starting point.
+    pub fn trend_weight(&self) -> Option<f64> {
+        self.weight_trend().last().map(|p| p.trend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn legacy_keys_parse() {
+        assert_eq!(parse_legacy_key("12-25-25_1200"), Some(at(2025, 12, 25, 12)));
+        assert_eq!(
+            parse_legacy_key("1-10-26_2030"),
+            Some(NaiveDate::from_ymd_opt(2026, 1, 10).unwrap().and_hms_opt(20, 30, 0).unwrap())
+        );
+        assert_eq!(parse_legacy_key("3-1-26"), Some(at(2026, 3, 1, 0)));
+        assert_eq!(parse_legacy_key("13-1-26_1200"), None);
+        assert_eq!(parse_legacy_key("1-1-26_930"), None);
+        assert_eq!(parse_legacy_key("yesterday"), None);
+    }
+
+    #[test]
+    fn legacy_file_migrates_and_merges_same_time_readings() {
+        let raw = serde_json::json!({
+            "weight": { "12-25-25_1200": 220.2, "1-10-26_2030": 211.4 },
+            "waist": { "1-10-26_2030": 40.5 },
+            "hips": { "1-10-26_2030": 44.0 },
+            "intake": { "1-10-26": 2100 }
+        });
+        let file = HistoryFile::from_legacy(&raw).unwrap();
+        assert_eq!(file.measurements.len(), 2);
+        assert_eq!(file.measurements[0].weight, Some(220.2));
+        let jan = &file.measurements[1];
+        assert_eq!((jan.weight, jan.waist), (Some(211.4), Some(40.5)));
+        assert_eq!(jan.tape.get("hips"), Some(&44.0));
+        assert_eq!(file.intake.get(&NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()), Some(&2100.0));
+
+        let bad = serde_json::json!({ "weight": { "Christmas": 220.2 } });
+        assert!(HistoryFile::from_legacy(&bad).unwrap_err().contains("Christmas"));
+    }
+
+    #[test]
+    fn trend_smooths_towards_readings() {
+        let mut file = HistoryFile::default();
+        for (day, w) in [(2, 200.0), (1, 210.0), (3, 0.0)] {
+            file.add(Measurement { id: Uuid::new_v4(), at: at(2026, 3, day, 7), weight: Some(w), ..Default::default() });
+        }
+        let trend = file.weight_trend();
+        // The zero reading is skipped; readings come back in time order.
+        assert_eq!(trend.iter().map(|p| p.weight).collect::<Vec<_>>(), [210.0, 200.0]);
+        // A day apart: a tenth of the way.
+        assert!((file.trend_weight().unwrap() - 209.0).abs() < 1e-9);
+        assert_eq!(HistoryFile::default().trend_weight(), None);
+    }
+
+    #[test]
+    fn sparse_readings_move_the_trend_further() {
+        // The shipped history: 220.2 lb, then 211.4 lb 16.35 days later.
+        let raw = serde_json::json!({ "weight": { "12-25-25_1200": 220.2, "1-10-26_2030": 211.4 } });
+        let file = HistoryFile::from_legacy(&raw).unwrap();
+        let trend = file.trend_weight().unwrap();
+        let days = 16.0 + 8.5 / 24.0;
+        let want = 220.2 - (1.0 - 0.9_f64.powf(days)) * 8.8;
+        assert!((trend - want).abs() < 1e-9);
+        // Within a couple of pounds of the latest reading, not 8 lb above it.
+        assert!(trend < 213.5, "{trend}");
+    }
+
+    #[test]
+    fn tape_text_parses() {
+        let tape = parse_tape("hips=38, Neck 15.5, chest = 42").unwrap();
+        assert_eq!(tape.get("chest"), Some(&42.0));
+        assert_eq!(tape.get("hips"), Some(&38.0));
+        assert_eq!(tape.get("neck"), Some(&15.5));
+        assert!(parse_tape("").unwrap().is_empty());
+        assert!(parse_tape("hips").is_err());
+        assert!(parse_tape("hips=big").is_err());
+        assert!(parse_tape("hips=nan").is_err());
+        assert!(parse_tape("hips=inf").is_err());
+    }
+
+    #[test]
+    fn measures_must_be_finite_and_positive() {
+        assert_eq!(parse_measure("Weight", " 182.5 "), Ok(Some(182.5)));
+        assert_eq!(parse_measure("Weight", ""), Ok(None));
+        for bad in ["0", "-3", "nan", "inf", "1e400", "heavy"] {
+            assert!(parse_measure("Weight", bad).is_err(), "{bad}");
+        }
+    }
+}
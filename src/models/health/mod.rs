@@ -1,2 +1,7 @@
+pub mod diary;
 pub mod equations;
-pub mod health;
\ No newline at end of file
+pub mod health;
+pub mod history;
+pub mod sessions;
+pub mod tdee;
+pub mod workouts;
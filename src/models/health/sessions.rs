@@ -0,0 +1,378 @@
+// src/models/health/sessions.rs
+//! The workout session log and progressive-overload numbers built on it.
+//!
+//! Sessions live in `sessions.json` beside whichever `health.json` is open.
+//! Weights are in pounds; a weight of 0 is a bodyweight set. Estimated 1RM
+//! uses the Epley formula, which is only trustworthy up to ~10 reps.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::workouts::Workout;
+use crate::utils::json_store::{load_json, save_json, StoreResult};
+
+pub const SESSIONS_FILE_NAME: &str = "sessions.json";
+/// Most sets one `NxR` entry may expand to; a typo like "3000x10" is refused
+/// rather than logged.
+pub const MAX_SETS_PER_ENTRY: u32 = 50;
+
+/// `sessions.json` in the same folder as `health_path`.
+pub fn sessions_path_for(health_path: impl AsRef<Path>) -> PathBuf {
+    let dir = health_path.as_ref().parent().unwrap_or(Path::new(""));
+    dir.join(SESSIONS_FILE_NAME)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SessionsFile {
+    pub version: u32,
+    /// Oldest first.
+    #[serde(default)]
+    pub sessions: Vec<WorkoutSession>,
+}
+
+impl Default for SessionsFile {
+    fn default() -> Self {
+        Self { version: 1, sessions: vec![] }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkoutSession {
+    pub id: Uuid,
+    pub date: NaiveDate,
+    /// Library workout name ("Push").
+    pub workout: String,
+    pub duration_minutes: u32,
+    /// Perceived effort, 1 (easy) to 10 (max).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<u8>,
+    /// Calories burned; estimated from the library when logged.
+    #[serde(default)]
+    pub kcal: f64,
+    #[serde(default)]
+    pub exercises: Vec<ExerciseLog>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExerciseLog {
+    pub name: String,
+    /// The library focus group it counts toward ("chest").
+    pub focus: String,
+    pub sets: Vec<SetLog>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct SetLog {
+    pub reps: u32,
+    #[serde(default)]
+    pub weight: f64,
+}
+
+/// Epley: `weight × (1 + reps / 30)`; a single is its own max.
+pub fn epley_1rm(weight: f64, reps: u32) -> f64 {
+    match reps {
+        0 => 0.0,
+        1 => weight,
+        r => weight * (1.0 + r as f64 / 30.0),
+    }
+}
+
+/// Parse "3x10@135, 8@145, 12": comma-separated `[sets x] reps [@ weight]`.
+/// No weight means bodyweight.
+pub fn parse_sets(text: &str) -> Result<Vec<SetLog>, String> {
+    let mut out = vec![];
+    for part in text.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (count_reps, weight) = match part.split_once('@') {
+            Some((cr, w)) => {
+                let w: f64 = w.trim().parse().map_err(|_| format!("bad weight in \"{part}\""))?;
+                (cr.trim(), w)
+            }
+            None => (part, 0.0),
+        };
+        let (count, reps) = match count_reps.split_once(['x', 'X', '×']) {
+            Some((c, r)) => (c.trim().parse::<u32>().ok(), r.trim().parse::<u32>().ok()),
+            None => (Some(1), count_reps.parse::<u32>().ok()),
+        };
+        let (Some(count), Some(reps)) = (count, reps) else {
+            return Err(format!("expected [sets x] reps [@ weight] in \"{part}\""));
+        };
+        // NaN and infinity would save as `null` and make the log unloadable.
+        if reps == 0 || !weight.is_finite() || weight < 0.0 {
+            return Err(format!("reps must be positive and weight not negative in \"{part}\""));
+        }
+        if !(1..=MAX_SETS_PER_ENTRY).contains(&count) {
+            return Err(format!("sets must be 1 to {MAX_SETS_PER_ENTRY} in \"{part}\""));
+        }
+        out.extend(std::iter::repeat_n(SetLog { reps, weight }, count as usize));
+    }
+    Ok(out)
+}
+
+impl ExerciseLog {
+    /// Total load: reps × weight over every set.
+    pub fn volume(&self) -> f64 {
+        self.sets.iter().map(|s| s.reps as f64 * s.weight).sum()
+    }
+
+    pub fn best_1rm(&self) -> f64 {
+        self.sets.iter().map(|s| epley_1rm(s.weight, s.reps)).fold(0.0, f64::max)
+    }
+}
+
+impl WorkoutSession {
+    pub fn volume(&self) -> f64 {
+        self.exercises.iter().map(ExerciseLog::volume).sum()
+    }
+
+    /// Burn estimate from the library: the average rate of the logged
+    /// exercises it knows, else the workout's planned session rate, times
+    /// the duration.
+    pub fn estimate_kcal(&self, workouts: &HashMap<String, Workout>, weight_kg: Option<f64>) -> f64 {
+        let Some(workout) = workouts.get(&self.workout) else {
+            return 0.0;
+        };
+        let rates: Vec<f64> = self
+            .exercises
+            .iter()
+            .filter_map(|e| workout.focus.get(&e.focus)?.exercises.get(&e.name))
+            .map(|ex| ex.kcal_per_hour(weight_kg))
+            .filter(|r| *r > 0.0)
+            .collect();
+        let rate = if rates.is_empty() {
+            workout.session(0, weight_kg).1
+        } else {
+            rates.iter().sum::<f64>() / rates.len() as f64
+        };
+        rate * self.duration_minutes as f64 / 60.0
+    }
+}
+
+/// Sets, reps and load for one focus group over a week.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FocusVolume {
+    pub sets: u32,
+    pub reps: u32,
+    pub load: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalRecord {
+    pub exercise: String,
+    /// Best estimated 1RM and the day it was set.
+    pub best_1rm: f64,
+    pub best_1rm_date: NaiveDate,
+    pub heaviest: f64,
+    pub most_reps: u32,
+}
+
+impl SessionsFile {
+    /// A missing file is an empty log; anything else unreadable is an error.
+    pub fn load(path: impl AsRef<Path>) -> StoreResult<SessionsFile> {
+        if !path.as_ref().exists() {
+            return Ok(SessionsFile::default());
+        }
+        load_json(path)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> StoreResult<()> {
+        save_json(path, self)
+    }
+
+    /// Insert keeping date order (same-day sessions stay in logging order).
+    pub fn add(&mut self, session: WorkoutSession) {
+        let at = self.sessions.partition_point(|s| s.date <= session.date);
+        self.sessions.insert(at, session);
+    }
+
+    pub fn remove(&mut self, id: Uuid) -> bool {
+        let before = self.sessions.len();
+        self.sessions.retain(|s| s.id != id);
+        self.sessions.len() != before
+    }
+
+    /// Every exercise name that has been logged, sorted.
+    pub fn exercise_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .sessions
+            .iter()
+            .flat_map(|s| s.exercises.iter().map(|e| e.name.clone()))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Best estimated 1RM per day for `exercise`, oldest first.
+    pub fn one_rm_history(&self, exercise: &str) -> Vec<(NaiveDate, f64)> {
+        let mut by_day: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+        for s in &self.sessions {
+            for e in s.exercises.iter().filter(|e| e.name == exercise) {
+                let best = e.best_1rm();
+                if best > 0.0 {
+                    let day = by_day.entry(s.date).or_insert(0.0);
+                    *day = day.max(best);
+                }
+            }
+        }
+        by_day.into_iter().collect()
+    }
+
+    /// Per-focus totals for sessions in `[week_start, week_start + 7 days)`.
+    pub fn weekly_volume(&self, week_start: NaiveDate) -> BTreeMap<String, FocusVolume> {
+        let end = week_start + Duration::days(7);
+        let mut out: BTreeMap<String, FocusVolume> = BTreeMap::new();
+        for s in self.sessions.iter().filter(|s| s.date >= week_start && s.date < end) {
+            for e in &s.exercises {
+                let v = out.entry(e.focus.clone()).or_default();
+                v.sets += e.sets.len() as u32;
+                v.reps += e.sets.iter().map(|s| s.reps).sum::<u32>();
+                v.load += e.volume();
+            }
+        }
+        out
+    }
+
+    /// Records per exercise, by name. The first day a best was reached
+    /// keeps it.
+    pub fn personal_records(&self) -> Vec<PersonalRecord> {
+        let mut out: BTreeMap<String, PersonalRecord> = BTreeMap::new();
+        for s in &self.sessions {
+            for e in &s.exercises {
+                for set in &e.sets {
+                    let e1rm = epley_1rm(set.weight, set.reps);
+                    let pr = out.entry(e.name.clone()).or_insert(PersonalRecord {
+                        exercise: e.name.clone(),
+                        best_1rm: e1rm,
+                        best_1rm_date: s.date,
+                        heaviest: set.weight,
+                        most_reps: set.reps,
+                    });
+                    if e1rm > pr.best_1rm {
+                        pr.best_1rm = e1rm;
+                        pr.best_1rm_date = s.date;
+                    }
+                    pr.heaviest = pr.heaviest.max(set.weight);
+                    pr.most_reps = pr.most_reps.max(set.reps);
+                }
+            }
+        }
+        out.into_values().collect()
+    }
+
+    /// Logged calories for sessions in `[from, to)`, and how many there were.
+    pub fn kcal_between(&self, from: NaiveDate, to: NaiveDate) -> (f64, usize) {
+        self.sessions
+            .iter()
+            .filter(|s| s.date >= from && s.date < to)
+            .fold((0.0, 0), |(kcal, n), s| (kcal + s.kcal, n + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::health::workouts::Exercise;
+
+    fn d(m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, day).unwrap()
+    }
+
+    fn session(date: NaiveDate, exercises: Vec<ExerciseLog>) -> WorkoutSession {
+        WorkoutSession {
+            id: Uuid::new_v4(),
+            date,
+            workout: "Push".into(),
+            duration_minutes: 60,
+            effort: Some(7),
+            kcal: 300.0,
+            exercises,
+            notes: String::new(),
+        }
+    }
+
+    fn bench(sets: &str) -> ExerciseLog {
+        ExerciseLog { name: "Bench".into(), focus: "chest".into(), sets: parse_sets(sets).unwrap() }
+    }
+
+    #[test]
+    fn set_notation_parses() {
+        assert_eq!(
+            parse_sets("2x10@135, 8 @ 145, 12").unwrap(),
+            [
+                SetLog { reps: 10, weight: 135.0 },
+                SetLog { reps: 10, weight: 135.0 },
+                SetLog { reps: 8, weight: 145.0 },
+                SetLog { reps: 12, weight: 0.0 },
+            ]
+        );
+        assert!(parse_sets("ten@135").is_err());
+        assert!(parse_sets("0@135").is_err());
+        assert_eq!(parse_sets("50x5").unwrap().len(), 50);
+        assert!(parse_sets("51x5").is_err());
+        for bad in ["5@nan", "5@inf", "5@1e400", "5@-inf"] {
+            assert!(parse_sets(bad).is_err(), "{bad}");
+        }
+        assert!(parse_sets("4294967295x5").is_err());
+        assert_eq!(parse_sets(" ").unwrap(), []);
+    }
+
+    #[test]
+    fn overload_analytics() {
+        let mut log = SessionsFile::default();
+        log.add(session(d(3, 9), vec![bench("3x5@200")]));
+        log.add(session(d(3, 2), vec![bench("3x5@180, 1@215")]));
+        log.add(session(
+            d(3, 11),
+            vec![
+                bench("5@205"),
+                ExerciseLog { name: "Dips".into(), focus: "triceps".into(), sets: parse_sets("2x12").unwrap() },
+            ],
+        ));
+        assert_eq!(log.sessions[0].date, d(3, 2));
+
+        let history = log.one_rm_history("Bench");
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0], (d(3, 2), 215.0));
+        assert!((history[1].1 - 233.333).abs() < 0.01);
+
+        let week = log.weekly_volume(d(3, 9));
+        assert_eq!(week["chest"], FocusVolume { sets: 4, reps: 20, load: 3000.0 + 1025.0 });
+        assert_eq!(week["triceps"], FocusVolume { sets: 2, reps: 24, load: 0.0 });
+
+        let prs = log.personal_records();
+        let bench_pr = prs.iter().find(|p| p.exercise == "Bench").unwrap();
+        assert_eq!(bench_pr.best_1rm_date, d(3, 11));
+        assert_eq!(bench_pr.heaviest, 215.0);
+        assert_eq!(bench_pr.most_reps, 5);
+
+        assert_eq!(log.kcal_between(d(3, 9), d(3, 16)), (600.0, 2));
+    }
+
+    #[test]
+    fn kcal_estimate_uses_logged_exercises() {
+        let mut push = Workout::default();
+        push.add_exercise("chest", "Bench", Exercise { calories_per_hour: 400.0, ..Default::default() });
+        push.add_exercise("triceps", "Dips", Exercise { calories_per_hour: 200.0, ..Default::default() });
+        let workouts = HashMap::from([("Push".to_string(), push)]);
+
+        let mut s = session(d(3, 2), vec![bench("5@200")]);
+        s.duration_minutes = 30;
+        assert_eq!(s.estimate_kcal(&workouts, None), 200.0);
+        s.exercises.clear();
+        assert_eq!(s.estimate_kcal(&workouts, None), 150.0);
+    }
+
+    #[test]
+    fn sessions_sit_next_to_health_json() {
+        assert_eq!(
+            sessions_path_for("assets/data/health/health.json"),
+            PathBuf::from("assets/data/health/sessions.json")
+        );
+    }
+}
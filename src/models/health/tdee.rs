@@ -0,0 +1,194 @@
+// src/models/health/tdee.rs
+//! Maintenance calories learned from what was eaten and what the scale did.
+//!
+//! Over a window, energy balance says `intake − maintenance = 3500 kcal ×
+//! weight change (lb)`, so maintenance is the mean daily intake minus 3500
+//! times the weight slope in lb/day. The slope is a least-squares line
+//! through every reading in the window, so one salty dinner doesn't swing
+//! it and sparse weigh-ins still count their full change.
+//!
+//! Intake comes from two places: calories typed into the weight log and
+//! food diary totals. A typed value wins for its day.
+
+use std::collections::BTreeMap;
+
+use chrono::{Duration, NaiveDate};
+
+use super::history::HistoryFile;
+
+pub const TDEE_WINDOW_DAYS: i64 = 28;
+/// Days of logged intake needed inside the window.
+pub const MIN_INTAKE_DAYS: usize = 10;
+/// Weight readings must span at least this many days inside the window.
+pub const MIN_WEIGHT_SPAN_DAYS: i64 = 7;
+const CALS_PER_LB: f64 = 3500.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TdeeEstimate {
+    /// Observed maintenance, kcal/day.
+    pub tdee: f64,
+    pub avg_intake: f64,
+    /// Fitted weight change; negative is loss.
+    pub lbs_per_week: f64,
+    pub intake_days: usize,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+/// Least-squares slope of `y` against `x`; the caller guarantees a spread
+/// in `x`.
+fn slope(points: &[(f64, f64)]) -> f64 {
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let (mut sxy, mut sxx) = (0.0, 0.0);
+    for (x, y) in points {
+        sxy += (x - mean_x) * (y - mean_y);
+        sxx += (x - mean_x) * (x - mean_x);
+    }
+    sxy / sxx
+}
+
+impl HistoryFile {
+    /// Back-solve maintenance over the `window_days` ending on `today`, with
+    /// `diary` filling days that have no typed intake. `None` until there's
+    /// enough intake and a long enough weight span.
+    pub fn estimate_tdee(
+        &self,
+        diary: &BTreeMap<NaiveDate, f64>,
+        today: NaiveDate,
+        window_days: i64,
+    ) -> Option<TdeeEstimate> {
+        let from = today - Duration::days(window_days.max(1) - 1);
+        let mut by_day: BTreeMap<NaiveDate, f64> = diary.range(from..=today).map(|(d, k)| (*d, *k)).collect();
+        by_day.extend(self.intake.range(from..=today).map(|(d, k)| (*d, *k)));
+        let eaten: Vec<f64> = by_day.into_values().collect();
+        if eaten.len() < MIN_INTAKE_DAYS {
+            return None;
+        }
+        // (days since the first reading, weight)
+        let readings: Vec<(f64, f64)> = {
+            let in_window: Vec<_> = self
+                .measurements
+                .iter()
+                .filter(|m| (from..=today).contains(&m.at.date()))
+                .filter_map(|m| Some((m.at, m.weight.filter(|w| *w > 0.0)?)))
+                .collect();
+            let origin = in_window.first()?.0;
+            in_window
+                .iter()
+                .map(|(at, w)| ((*at - origin).num_minutes() as f64 / (24.0 * 60.0), *w))
+                .collect()
+        };
+        let span_days = readings.last()?.0;
+        if span_days < MIN_WEIGHT_SPAN_DAYS as f64 {
+            return None;
+        }
+        let lbs_per_day = slope(&readings);
+        let avg_intake = eaten.iter().sum::<f64>() / eaten.len() as f64;
+        Some(TdeeEstimate {
+            tdee: avg_intake - lbs_per_day * CALS_PER_LB,
+            avg_intake,
+            lbs_per_week: lbs_per_day * 7.0,
+            intake_days: eaten.len(),
+            from,
+            to: today,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::health::history::Measurement;
+    use uuid::Uuid;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    fn weigh(file: &mut HistoryFile, day: u32, weight: f64) {
+        file.add(Measurement {
+            id: Uuid::new_v4(),
+            at: d(day).and_hms_opt(7, 0, 0).unwrap(),
+            weight: Some(weight),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn steady_weight_means_intake_is_maintenance() {
+        let mut file = HistoryFile::default();
+        for day in 1..=28 {
+            file.set_intake(d(day), 2400.0);
+            weigh(&mut file, day, 200.0);
+        }
+        let est = file.estimate_tdee(&BTreeMap::new(), d(28), TDEE_WINDOW_DAYS).unwrap();
+        assert_eq!(est.tdee, 2400.0);
+        assert_eq!(est.intake_days, 28);
+        assert_eq!(est.from, d(1));
+    }
+
+    #[test]
+    fn losing_weight_puts_maintenance_above_intake() {
+        let mut file = HistoryFile::default();
+        for day in 1..=28 {
+            file.set_intake(d(day), 2000.0);
+        }
+        weigh(&mut file, 1, 200.0);
+        weigh(&mut file, 8, 199.0);
+        weigh(&mut file, 15, 198.0);
+        let est = file.estimate_tdee(&BTreeMap::new(), d(28), TDEE_WINDOW_DAYS).unwrap();
+        // 2 lb over 14 days: 1 lb/week, 500 kcal/day.
+        assert!((est.lbs_per_week + 1.0).abs() < 1e-9);
+        assert!((est.tdee - 2500.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn sparse_weigh_ins_count_their_full_change() {
+        let mut file = HistoryFile::default();
+        for day in 1..=28 {
+            file.set_intake(d(day), 2000.0);
+        }
+        weigh(&mut file, 1, 200.0);
+        weigh(&mut file, 15, 180.0);
+        let est = file.estimate_tdee(&BTreeMap::new(), d(28), TDEE_WINDOW_DAYS).unwrap();
+        // 20 lb in 14 days is 10 lb/week, or 5000 kcal/day of deficit.
+        assert!((est.lbs_per_week + 10.0).abs() < 1e-9);
+        assert!((est.tdee - 7000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn diary_fills_days_without_typed_intake() {
+        let mut file = HistoryFile::default();
+        let mut diary = BTreeMap::new();
+        for day in 1..=28 {
+            weigh(&mut file, day, 200.0);
+            diary.insert(d(day), 2000.0);
+        }
+        for day in 1..=14 {
+            file.set_intake(d(day), 3000.0);
+        }
+        let est = file.estimate_tdee(&diary, d(28), TDEE_WINDOW_DAYS).unwrap();
+        assert_eq!(est.intake_days, 28);
+        assert_eq!(est.avg_intake, 2500.0);
+    }
+
+    #[test]
+    fn too_little_data_gives_nothing() {
+        let mut file = HistoryFile::default();
+        for day in 1..=5 {
+            file.set_intake(d(day), 2000.0);
+        }
+        weigh(&mut file, 1, 200.0);
+        weigh(&mut file, 20, 199.0);
+        assert_eq!(file.estimate_tdee(&BTreeMap::new(), d(28), TDEE_WINDOW_DAYS), None);
+
+        for day in 6..=28 {
+            file.set_intake(d(day), 2000.0);
+        }
+        // Enough intake, but only one reading inside a two-week window.
+        assert_eq!(file.estimate_tdee(&BTreeMap::new(), d(26), 14), None);
+        assert!(file.estimate_tdee(&BTreeMap::new(), d(28), TDEE_WINDOW_DAYS).is_some());
+    }
+}
@@ -0,0 +1,268 @@
+// src/models/health/workouts.rs
+//! The workout library in `health.json`.
+//!
+//! Shape on disk (unchanged from the untyped version, new fields optional):
+//! `workouts → name → focus → group → exercise → { caloriesPerHour, met,
+//! sets, reps, restSeconds, equipment, examples }`. Wrong types fail to load
+//! with the workout's name in the message; values that parse but make no
+//! sense (negative burn, zero sets) are reported by [`validate_workouts`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Workout {
+    /// Muscle group or focus ("chest", "endurance") to its exercises.
+    #[serde(default)]
+    pub focus: BTreeMap<String, FocusGroup>,
+}
+
+/// Exercises that train the same thing; a session picks one per group.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(transparent)]
+pub struct FocusGroup {
+    pub exercises: BTreeMap<String, Exercise>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Exercise {
+    /// Flat burn rate; used when there's no `met` or no body weight.
+    #[serde(default)]
+    pub calories_per_hour: f64,
+    /// Metabolic equivalent: kcal per kg of body weight per hour.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub met: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sets: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reps: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rest_seconds: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub equipment: Vec<String>,
+    /// Demo media keyed by an id.
+    #[serde(default)]
+    pub examples: BTreeMap<String, ExampleMedia>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExampleMedia {
+    /// `true` when `file_location` is a URL rather than a local file.
+    #[serde(default)]
+    pub link: bool,
+    pub file_location: String,
+}
+
+/// One exercise picked for a session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionExercise {
+    pub focus: String,
+    pub name: String,
+    pub image: Option<String>,
+    /// "3 × 10 · rest 90s · bench", empty when nothing is prescribed.
+    pub prescription: String,
+}
+
+impl Exercise {
+    /// Burn rate for someone weighing `weight_kg`, preferring MET.
+    pub fn kcal_per_hour(&self, weight_kg: Option<f64>) -> f64 {
+        match (self.met, weight_kg) {
+            (Some(met), Some(kg)) if kg > 0.0 => met * kg,
+            _ => self.calories_per_hour,
+        }
+    }
+
+    pub fn prescription(&self) -> String {
+        let mut parts = vec![];
+        match (self.sets, self.reps) {
+            (Some(s), Some(r)) => parts.push(format!("{s} × {r}")),
+            (Some(s), None) => parts.push(format!("{s} sets")),
+            (None, Some(r)) => parts.push(format!("{r} reps")),
+            (None, None) => {}
+        }
+        if let Some(rest) = self.rest_seconds {
+            parts.push(format!("rest {rest}s"));
+        }
+        if !self.equipment.is_empty() {
+            parts.push(self.equipment.join(", "));
+        }
+        parts.join(" · ")
+    }
+}
+
+impl Workout {
+    /// Insert or replace `exercise` under `focus`, creating the group.
+    pub fn add_exercise(&mut self, focus: &str, name: &str, exercise: Exercise) {
+        self.focus
+            .entry(focus.to_string())
+            .or_default()
+            .exercises
+            .insert(name.to_string(), exercise);
+    }
+
+    /// One exercise from every focus group, rotating through each group's
+    /// variations by `week_index`, and the session's burn rate: the average
+    /// over the picks that have one.
+    pub fn session(&self, week_index: usize, weight_kg: Option<f64>) -> (Vec<SessionExercise>, f64) {
+        let mut picks = vec![];
+        let mut burn_sum = 0.0;
+        let mut burn_count = 0.0;
+        for (focus, group) in &self.focus {
+            if group.exercises.is_empty() {
+                continue;
+            }
+            let idx = week_index % group.exercises.len();
+            let Some((name, ex)) = group.exercises.iter().nth(idx) else {
+                continue;
+            };
+            let rate = ex.kcal_per_hour(weight_kg);
+            if rate > 0.0 {
+                burn_sum += rate;
+                burn_count += 1.0;
+            }
+            picks.push(SessionExercise {
+                focus: focus.clone(),
+                name: name.clone(),
+                image: ex.examples.values().next().map(|m| m.file_location.clone()),
+                prescription: ex.prescription(),
+            });
+        }
+        let avg = if burn_count > 0.0 { burn_sum / burn_count } else { 0.0 };
+        (picks, avg)
+    }
+}
+
+/// A library entry that loaded but doesn't make sense.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkoutIssue {
+    /// "Push / chest / Archer Push Ups"
+    pub path: String,
+    pub problem: String,
+}
+
+impl fmt::Display for WorkoutIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.problem)
+    }
+}
+
+/// Every problem in the library, sorted by workout name.
+pub fn validate_workouts(workouts: &HashMap<String, Workout>) -> Vec<WorkoutIssue> {
+    let mut names: Vec<&String> = workouts.keys().collect();
+    names.sort();
+    let mut out = vec![];
+    for name in names {
+        let mut issue = |path: String, problem: &str| {
+            out.push(WorkoutIssue { path, problem: problem.to_string() })
+        };
+        if name.trim().is_empty() {
+            issue(name.clone(), "workout name is blank");
+        }
+        for (focus, group) in &workouts[name].focus {
+            if focus.trim().is_empty() {
+                issue(format!("{name} / {focus}"), "focus name is blank");
+            }
+            for (ex_name, ex) in &group.exercises {
+                let path = format!("{name} / {focus} / {ex_name}");
+                if ex_name.trim().is_empty() {
+                    issue(path.clone(), "exercise name is blank");
+                }
+                if !ex.calories_per_hour.is_finite() || ex.calories_per_hour < 0.0 {
+                    issue(path.clone(), "caloriesPerHour must be zero or more");
+                }
+                if ex.met.is_some_and(|m| !m.is_finite() || m <= 0.0) {
+                    issue(path.clone(), "met must be above zero");
+                }
+                if ex.sets == Some(0) {
+                    issue(path.clone(), "sets must be at least 1");
+                }
+                if ex.reps == Some(0) {
+                    issue(path.clone(), "reps must be at least 1");
+                }
+                if ex.examples.values().any(|m| m.file_location.trim().is_empty()) {
+                    issue(path, "an example has no fileLocation");
+                }
+            }
+        }
+    }
+    out
+}
+
+/// `deserialize_with` for `HealthFile::workouts`: a workout of the wrong
+/// shape fails the load and names itself, rather than a bare serde position.
+pub fn de_workouts<'de, D>(d: D) -> Result<HashMap<String, Workout>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = HashMap::<String, serde_json::Value>::deserialize(d)?;
+    raw.into_iter()
+        .map(|(name, value)| {
+            Workout::deserialize(value)
+                .map(|w| (name.clone(), w))
+                .map_err(|e| D::Error::custom(format!("workout \"{name}\": {e}")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::health::health::HealthFile;
+
+    #[test]
+    fn existing_library_shape_loads() {
+        let json = r#"{
+            "workouts": {
+                "Push": { "focus": {
+                    "chest": {
+                        "Standard Push Ups": { "caloriesPerHour": 300, "examples": {
+                            "uuid-6": { "link": true, "fileLocation": "https://media.tenor.com/pushups.gif" }
+                        } },
+                        "Archer Push Ups": { "caloriesPerHour": 400, "examples": {} }
+                    },
+                    "shoulders": {
+                        "Pike Push Ups": { "caloriesPerHour": 350, "met": 5.0, "sets": 3, "reps": 8, "restSeconds": 90 }
+                    }
+                } }
+            }
+        }"#;
+        let file: HealthFile = serde_json::from_str(json).unwrap();
+        assert!(validate_workouts(&file.workouts).is_empty());
+
+        let push = &file.workouts["Push"];
+        let (week0, rate) = push.session(0, None);
+        assert_eq!(week0[0].name, "Archer Push Ups");
+        assert_eq!(week0[1].prescription, "3 × 8 · rest 90s");
+        assert_eq!(rate, 375.0);
+        let (week1, _) = push.session(1, None);
+        assert_eq!(week1[0].image.as_deref(), Some("https://media.tenor.com/pushups.gif"));
+        // With a body weight, MET wins where it's set.
+        let (_, rate) = push.session(0, Some(100.0));
+        assert_eq!(rate, 450.0);
+    }
+
+    #[test]
+    fn bad_shapes_are_errors_not_empty_workouts() {
+        let json = r#"{ "workouts": { "Legs": { "focus": { "quads": { "Squats": "lots" } } } } }"#;
+        let err = serde_json::from_str::<HealthFile>(json).unwrap_err().to_string();
+        assert!(err.contains("workout \"Legs\""), "{err}");
+
+        let mut workouts = HashMap::new();
+        let mut legs = Workout::default();
+        legs.add_exercise("quads", "Squats", Exercise { calories_per_hour: -5.0, sets: Some(0), ..Default::default() });
+        workouts.insert("Legs".to_string(), legs);
+        let issues: Vec<String> = validate_workouts(&workouts).iter().map(|i| i.to_string()).collect();
+        assert_eq!(
+            issues,
+            [
+                "Legs / quads / Squats: caloriesPerHour must be zero or more",
+                "Legs / quads / Squats: sets must be at least 1",
+            ]
+        );
+    }
+}
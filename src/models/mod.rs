@@ -5,4 +5,6 @@ pub mod finCalc;
 
 pub mod health;
 
-pub mod goals;
\ No newline at end of file
+pub mod goals;
+
+pub mod schedule;
\ No newline at end of file
@@ -1,4 +1,5 @@
-//! Scheduler data model (the `time` module's event + recurrence engine).
+//! Scheduler data model: the event + recurrence engine behind the `time`
+//! component, kept here so other models can expand recurrences too.
 //!
 //! This module is deliberately independent of `dioxus-primitives` so that the
 //! persisted JSON format never breaks when the (pre-1.0) primitives crate
@@ -8,6 +9,11 @@ use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Planning day window: the weekly planner grid and the goal time-blocker
+/// only place blocks between these hours.
+pub const PLAN_START_HOUR: u32 = 6;
+pub const PLAN_END_HOUR: u32 = 23; // exclusive end of the last start slot
+
 /// Stable identity for an event. v4 UUID — generated in the store on insert, so
 /// there's no counter to persist or keep in sync.
 pub type EventId = Uuid;
@@ -20,6 +26,7 @@ pub enum EventSource {
     Health,
     JaxBrain,
     FinCalc,
+    Goals,
 }
 
 impl Default for EventSource {
@@ -36,6 +43,7 @@ impl EventSource {
             EventSource::Health => "var(--sched-health, #16a34a)",
             EventSource::JaxBrain => "var(--sched-jax, #7c3aed)",
             EventSource::FinCalc => "var(--sched-fin, #2563eb)",
+            EventSource::Goals => "var(--sched-goals, #d97706)",
         }
     }
 
@@ -45,14 +53,16 @@ impl EventSource {
             EventSource::Health => "Health",
             EventSource::JaxBrain => "Jax Brain",
             EventSource::FinCalc => "Finance",
+            EventSource::Goals => "Goals",
         }
     }
 
-    pub const ALL: [EventSource; 4] = [
+    pub const ALL: [EventSource; 5] = [
         EventSource::Manual,
         EventSource::Health,
         EventSource::JaxBrain,
         EventSource::FinCalc,
+        EventSource::Goals,
     ];
 }
 
@@ -255,7 +265,10 @@ const GUARD: u32 = 200_000;
 
 /// Produce the set of dates a recurrence lands on within `[win_from, win_to]`.
 /// `count` is honored against the *whole* series (from `base`), not the window.
-fn expand_dates(
+///
+/// Shared with FinCalc's cash-flow projection so the chart and the calendar
+/// agree on when a recurring flow lands.
+pub fn expand_dates(
     base: NaiveDate,
     rec: &Recurrence,
     win_from: NaiveDate,
@@ -10,6 +10,8 @@ pub enum StoreError {
     Io(io::Error),
     SerdeJson(serde_json::Error),
     InvalidPath(String),
+    /// Parsed, but the contents don't make sense.
+    Invalid(String),
 }
 
 impl From<io::Error> for StoreError {
@@ -75,5 +77,6 @@ pub fn err_to_string(e: StoreError) -> String {
         StoreError::Io(ioe) => format!("IO error: {ioe}"),
         StoreError::SerdeJson(se) => format!("JSON error: {se}"),
         StoreError::InvalidPath(s) => format!("Invalid path: {s}"),
+        StoreError::Invalid(s) => format!("Invalid data: {s}"),
     }
 }
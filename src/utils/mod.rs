@@ -1 +1,2 @@
-pub mod json_store;
\ No newline at end of file
+pub mod json_store;
+pub mod open;
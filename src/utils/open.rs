@@ -0,0 +1,27 @@
+use std::{
+    io,
+    process::{Command, Stdio},
+};
+
+/// Hand a file path or URL to the OS default handler (`xdg-open`, `open` or
+/// the Windows URL handler). Returns once the handler is launched, not when
+/// it exits.
+pub fn open_external(target: &str) -> io::Result<()> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        // Not `cmd /C start`: cmd.exe would read `&`, `|` and `^` in the
+        // target as command syntax. The shell handler takes it verbatim.
+        let mut c = Command::new("rundll32");
+        c.arg("url.dll,FileProtocolHandler");
+        c
+    } else if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else {
+        Command::new("xdg-open")
+    };
+    cmd.arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
@@ -1,9 +1,19 @@
 use dioxus::prelude::*;
-//use crate::components::{Goals};
-/// The Home page component that will be rendered when the current route is `[Route::Home]`
+use uuid::Uuid;
+use crate::components::{GoalReview, Goals};
+/// Rendered for `[Route::GoalView]` (`/Goals`, or `/Goals?focus=<id>` to open
+/// on one goal).
 #[component]
-pub fn GoalView() -> Element {
+pub fn GoalView(focus: String) -> Element {
     rsx! {
-        // Goals { overview:false }
+        Goals { overview:false, focus: Uuid::parse_str(&focus).ok() }
+    }
+}
+
+/// Rendered for `[Route::GoalReviewView]`; the calendar's review event links here.
+#[component]
+pub fn GoalReviewView() -> Element {
+    rsx! {
+        GoalReview {}
     }
 }
@@ -2,8 +2,9 @@ use dioxus::prelude::*;
 use crate::components::{JaxBrain};
 /// The Home page component that will be rendered when the current route is `[Route::Home]`
 #[component]
-pub fn JaxBrainView() -> Element {
+pub fn JaxBrainView(focus: String) -> Element {
+    let focus = (!focus.is_empty()).then_some(focus);
     rsx! {
-		JaxBrain { overview:false }
+		JaxBrain { overview:false, focus }
     }
 }
@@ -21,7 +21,7 @@ mod health;
 pub use health::HealthView;
 
 mod goals;
-pub use goals::GoalView;
+pub use goals::{GoalReviewView, GoalView};
 
 mod jax_brain;
 pub use jax_brain::JaxBrainView;
@@ -22,14 +22,14 @@ pub fn NavbarLayout() -> Element {
                                 "Overview"
                             }
                         }
-                        // NavbarItem {
-                        // 	index: 1usize,
-                        // 	value: "goals".to_string(),
-                        // 	to: Route::GoalView {  },
-                        // 	div{class:"text-center",
-                        // 		"Goals 🎯"
-                        // 	}
-                        // }
+                        NavbarItem {
+                            index: 1usize,
+                            value: "goals".to_string(),
+                            to: Route::GoalView { focus: String::new() },
+                            div{class:"text-center",
+                                "Goals 🎯"
+                            }
+                        }
                         NavbarItem {
                             index: 2usize,
                             value: "health".to_string(),
@@ -49,7 +49,7 @@ pub fn NavbarLayout() -> Element {
                         NavbarItem {
                             index: 4usize,
                             value: "jaxbrain".to_string(),
-                            to: Route::JaxBrainView {  },
+                            to: Route::JaxBrainView { focus: String::new() },
                             div{ class:"flex justify-center gap-2 items-center",
                                 "Jax Brain"
                                 img {class:"h-[30px]", src: asset! { "assets/images/digital_brain.png"}}
@@ -58,7 +58,7 @@ pub fn NavbarLayout() -> Element {
                         NavbarItem {
                             index: 5usize,
                             value: "time".to_string(),
-                            to: Route::TimeView {  },
+                            to: Route::TimeView { event: String::new() },
                             div{class:"text-center",
                                 "Time 🗓️"
                             }
@@ -1,4 +1,4 @@
-use crate::components::{FinCalc, Health, JaxBrain, Time};
+use crate::components::{FinCalc, Goals, Health, JaxBrain, Time};
 use dioxus::prelude::*;
 /// The Home page component that will be rendered when the current route is `[Route::Home]`
 #[component]
@@ -9,7 +9,7 @@ pub fn Overview() -> Element {
 
             Time { overview: true }
 
-            // Goals { overview:true }
+            Goals { overview:true }
 
             FinCalc { overview:true }
 
@@ -1,10 +1,12 @@
 use crate::components::time::Time;
 use dioxus::prelude::*;
+use uuid::Uuid;
 
-/// Full-page scheduler. Rendered for `Route::TimeView` (`/Time`).
+/// Full-page scheduler. Rendered for `Route::TimeView` (`/Time`, or
+/// `/Time?event=<id>` to open on one event).
 #[component]
-pub fn TimeView() -> Element {
+pub fn TimeView(event: String) -> Element {
     rsx! {
-        Time {}
+        Time { focus_event: Uuid::parse_str(&event).ok() }
     }
 }